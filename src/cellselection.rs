@@ -1,15 +1,22 @@
+use crate::command::{TableCommand, TableCommands};
 use crate::event::Outcome;
+#[cfg(feature = "crossterm")]
+use crate::event::Vim;
 use crate::{TableSelection, TableState};
+#[cfg(feature = "crossterm")]
 use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Regular};
+#[cfg(feature = "crossterm")]
 use rat_focus::HasFocus;
+#[cfg(feature = "crossterm")]
 use rat_scrolled::event::ScrollOutcome;
+#[cfg(feature = "crossterm")]
 use rat_scrolled::ScrollAreaState;
-use std::cmp::{max, min};
+use std::cmp::min;
 
 /// Select a single cell in the table.
 ///
 /// This one supports cell + column + row selection.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct CellSelection {
     /// Selected cell.
     pub lead_cell: Option<(usize, usize)>,
@@ -31,6 +38,10 @@ impl TableSelection for CellSelection {
     fn lead_selection(&self) -> Option<(usize, usize)> {
         self.lead_cell
     }
+
+    fn move_lead(&mut self, column: usize, row: usize) -> bool {
+        self.select_cell(Some((column, row)))
+    }
 }
 
 impl CellSelection {
@@ -114,7 +125,7 @@ impl CellSelection {
         let old_cell = self.lead_cell;
         self.lead_cell = match self.lead_cell {
             None => Some((0, 0)),
-            Some((scol, srow)) => Some((scol, min(srow + n, maximum))),
+            Some((scol, srow)) => Some((scol, min(srow.saturating_add(n), maximum))),
         };
         old_cell != self.lead_cell
     }
@@ -134,7 +145,7 @@ impl CellSelection {
         let old_cell = self.lead_cell;
         self.lead_cell = match self.lead_cell {
             None => Some((0, 0)),
-            Some((scol, srow)) => Some((min(scol + n, maximum), srow)),
+            Some((scol, srow)) => Some((min(scol.saturating_add(n), maximum), srow)),
         };
         old_cell != self.lead_cell
     }
@@ -150,35 +161,180 @@ impl CellSelection {
     }
 }
 
+impl TableState<CellSelection> {
+    /// Execute a command. This has the same semantics as the
+    /// corresponding keybindings.
+    pub fn execute(&mut self, cmd: TableCommand) -> Outcome {
+        match cmd {
+            TableCommand::MoveUp(n) => self.move_up(n).into(),
+            TableCommand::MoveDown(n) => self.move_down(n).into(),
+            TableCommand::MoveLeft(n) => self.move_left(n).into(),
+            TableCommand::MoveRight(n) => self.move_right(n).into(),
+            TableCommand::MoveToRow(row) => self.move_to_row(row).into(),
+            TableCommand::MoveToColumn(col) => self.move_to_col(col).into(),
+            TableCommand::MoveToCell(col, row) => self.move_to((col, row)).into(),
+            TableCommand::MoveFirst => self.move_to_row(0).into(),
+            TableCommand::MoveLast => self.move_to_row(self.rows.saturating_sub(1)).into(),
+            TableCommand::PageUp if self.page_scroll_offset => {
+                self.scroll_up(self.page_up_len()).into()
+            }
+            TableCommand::PageDown if self.page_scroll_offset => {
+                self.scroll_down(self.page_down_len()).into()
+            }
+            TableCommand::PageUp => self.move_up(self.page_up_len()).into(),
+            TableCommand::PageDown => self.move_down(self.page_down_len()).into(),
+            TableCommand::ClearSelection => {
+                self.clear_selection();
+                Outcome::Changed
+            }
+            TableCommand::ScrollUp(n) => self.scroll_up(n).into(),
+            TableCommand::ScrollDown(n) => self.scroll_down(n).into(),
+            TableCommand::ScrollLeft(n) => self.scroll_left(n).into(),
+            TableCommand::ScrollRight(n) => self.scroll_right(n).into(),
+            TableCommand::ScrollToRow(row) => self.scroll_to_row(row).into(),
+            TableCommand::ScrollToColumn(col) => self.scroll_to_col(col).into(),
+            TableCommand::ScrollLeftmost => self.scroll_to_x(0).into(),
+            TableCommand::ScrollRightmost => self.scroll_to_x(self.x_max_offset()).into(),
+            TableCommand::JumpToRow(row) => self.jump_to(row).into(),
+            TableCommand::JumpBack => self.jump_back().into(),
+            TableCommand::JumpForward => self.jump_forward().into(),
+            _ => Outcome::Continue,
+        }
+    }
+
+    /// Command for a key event with the regular keybindings.
+    /// The Regular event-handler uses the same mapping.
+    #[cfg(feature = "crossterm")]
+    fn key_command(&self, event: &crossterm::event::Event) -> Option<TableCommand> {
+        match event {
+            ct_event!(keycode press Up) => Some(TableCommand::MoveUp(1)),
+            ct_event!(keycode press Down) => Some(TableCommand::MoveDown(1)),
+            ct_event!(keycode press CONTROL-Up) | ct_event!(keycode press CONTROL-Home) => {
+                Some(TableCommand::MoveFirst)
+            }
+            ct_event!(keycode press CONTROL-Down) | ct_event!(keycode press CONTROL-End) => {
+                Some(TableCommand::MoveLast)
+            }
+            ct_event!(keycode press PageUp) => Some(TableCommand::PageUp),
+            ct_event!(keycode press PageDown) => Some(TableCommand::PageDown),
+            ct_event!(keycode press Left) => Some(TableCommand::MoveLeft(1)),
+            ct_event!(keycode press Right) => Some(TableCommand::MoveRight(1)),
+            ct_event!(keycode press CONTROL-Left) | ct_event!(keycode press Home) => {
+                Some(TableCommand::MoveToColumn(0))
+            }
+            ct_event!(keycode press CONTROL-Right) | ct_event!(keycode press End) => {
+                Some(TableCommand::MoveToColumn(self.columns.saturating_sub(1)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TableCommands for TableState<CellSelection> {
+    #[cfg(feature = "crossterm")]
+    fn command_for(&self, event: &crossterm::event::Event) -> Option<TableCommand> {
+        if self.is_focused() {
+            if let Some(cmd) = self.key_command(event) {
+                return Some(cmd);
+            }
+        }
+        match event {
+            ct_event!(mouse down Left for column, row)
+                if self.area.contains((*column, *row).into()) =>
+            {
+                self.cell_at_clicked((*column, *row))
+                    .map(|(c, r)| TableCommand::MoveToCell(c, r))
+            }
+            ct_event!(scroll up for column, row) if self.inner.contains((*column, *row).into()) => {
+                Some(TableCommand::ScrollUp(self.row_scroll_by()))
+            }
+            ct_event!(scroll down for column, row)
+                if self.inner.contains((*column, *row).into()) =>
+            {
+                Some(TableCommand::ScrollDown(self.row_scroll_by()))
+            }
+            _ => None,
+        }
+    }
+
+    fn execute_command(&mut self, cmd: TableCommand) -> Outcome {
+        self.execute(cmd)
+    }
+}
+
+#[cfg(feature = "crossterm")]
 impl HandleEvent<crossterm::event::Event, Regular, Outcome> for TableState<CellSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> Outcome {
+        self.handle_focus_gained();
+        flow!(self.handle_peek(event));
+        flow!(self.handle_annotation(event));
+        flow!(self.handle_page_scroll(event));
+
+        let res = if self.is_focused() {
+            match self.key_command(event) {
+                Some(cmd) => self.execute(cmd),
+                None => Outcome::Continue,
+            }
+        } else {
+            Outcome::Continue
+        };
+
+        if res == Outcome::Continue {
+            self.handle(event, MouseOnly)
+        } else {
+            res
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandleEvent<crossterm::event::Event, Vim, Outcome> for TableState<CellSelection> {
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: Vim) -> Outcome {
         let res = if self.is_focused() {
+            flow!(self.handle_page_scroll(event));
+            flow!(self.handle_count_prefix(event));
             match event {
-                ct_event!(keycode press Up) => self.move_up(1).into(),
-                ct_event!(keycode press Down) => self.move_down(1).into(),
-                ct_event!(keycode press CONTROL-Up) | ct_event!(keycode press CONTROL-Home) => {
-                    self.move_to_row(0).into()
+                ct_event!(key press 'j') | ct_event!(keycode press Down) => {
+                    let n = self.count_prefix.take(self.rows);
+                    self.move_down(n).into()
                 }
-                ct_event!(keycode press CONTROL-Down) | ct_event!(keycode press CONTROL-End) => {
-                    self.move_to_row(self.rows.saturating_sub(1)).into()
+                ct_event!(key press 'k') | ct_event!(keycode press Up) => {
+                    let n = self.count_prefix.take(self.rows);
+                    self.move_up(n).into()
                 }
-
-                ct_event!(keycode press PageUp) => self
-                    .move_up(max(1, self.page_len().saturating_sub(1)))
-                    .into(),
-                ct_event!(keycode press PageDown) => self
-                    .move_down(max(1, self.page_len().saturating_sub(1)))
-                    .into(),
-
-                ct_event!(keycode press Left) => self.move_left(1).into(),
-                ct_event!(keycode press Right) => self.move_right(1).into(),
-                ct_event!(keycode press CONTROL-Left) | ct_event!(keycode press Home) => {
-                    self.move_to_col(0).into()
+                ct_event!(key press 'h') | ct_event!(keycode press Left) => {
+                    let n = self.count_prefix.take(self.columns);
+                    self.move_left(n).into()
                 }
-                ct_event!(keycode press CONTROL-Right) | ct_event!(keycode press End) => {
-                    self.move_to_col(self.columns.saturating_sub(1)).into()
+                ct_event!(key press 'l') | ct_event!(keycode press Right) => {
+                    let n = self.count_prefix.take(self.columns);
+                    self.move_right(n).into()
+                }
+                ct_event!(key press SHIFT-'G') => match self.count_prefix.pending() {
+                    Some(_) => {
+                        let n = self.count_prefix.take(self.rows);
+                        self.jump_to(n - 1).into()
+                    }
+                    None => self.move_to_row(self.rows.saturating_sub(1)).into(),
+                },
+                ct_event!(key press CONTROL-'o') => self.jump_back().into(),
+                ct_event!(key press CONTROL-'i') => self.jump_forward().into(),
+                ct_event!(keycode press PageUp) => {
+                    let n = self.count_prefix.take(self.rows);
+                    self.move_up(n.saturating_mul(self.page_up_len())).into()
+                }
+                ct_event!(keycode press PageDown) => {
+                    let n = self.count_prefix.take(self.rows);
+                    self.move_down(n.saturating_mul(self.page_down_len()))
+                        .into()
+                }
+                // other keys drop the count. mouse and focus events don't.
+                crossterm::event::Event::Key(key)
+                    if key.kind != crossterm::event::KeyEventKind::Release =>
+                {
+                    self.count_prefix.clear();
+                    Outcome::Continue
                 }
-
                 _ => Outcome::Continue,
             }
         } else {
@@ -186,15 +342,26 @@ impl HandleEvent<crossterm::event::Event, Regular, Outcome> for TableState<CellS
         };
 
         if res == Outcome::Continue {
-            self.handle(event, MouseOnly)
+            self.handle(event, Regular)
         } else {
             res
         }
     }
 }
 
+#[cfg(feature = "crossterm")]
 impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for TableState<CellSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> Outcome {
+        let touch = self.touch_event(event);
+        let event = touch.as_ref().unwrap_or(event);
+
+        flow!(self.handle_overflow(event));
+        flow!(self.handle_resize(event));
+        flow!(self.handle_minimap(event));
+        flow!(self.handle_swipe(event));
+        self.track_drag(event);
+        flow!(self.handle_drag_range(event, false));
+
         flow!(match event {
             ct_event!(mouse any for m) if self.mouse.drag(self.table_area, m) => {
                 self.move_to(self.cell_at_drag((m.column, m.row))).into()
@@ -213,14 +380,27 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for TableState<Cel
             _ => Outcome::Continue,
         });
 
-        let mut sas = ScrollAreaState::new()
-            .area(self.inner)
-            .h_scroll(&mut self.hscroll)
-            .v_scroll(&mut self.vscroll);
-        let r = match sas.handle(event, MouseOnly) {
+        let hwheel = self.handle_hwheel(event);
+        let scroll = if hwheel != ScrollOutcome::Continue {
+            hwheel
+        } else {
+            // wheel_scroll_by only applies for this event.
+            let scroll_by = self.vscroll.scroll_by;
+            self.vscroll.scroll_by = self.wheel_scroll_by.or(scroll_by);
+            let mut sas = ScrollAreaState::new()
+                .area(self.inner)
+                .h_scroll(&mut self.hscroll)
+                .v_scroll(&mut self.vscroll);
+            let r = sas.handle(event, MouseOnly);
+            self.vscroll.scroll_by = scroll_by;
+            r
+        };
+        flow!(self.handle_coalesce(scroll));
+
+        let r = match scroll {
             ScrollOutcome::Up(v) => self.scroll_up(v),
             ScrollOutcome::Down(v) => self.scroll_down(v),
-            ScrollOutcome::VPos(v) => self.set_row_offset(v),
+            ScrollOutcome::VPos(v) => self.set_row_offset(self.remap_vpos(v)),
             ScrollOutcome::Left(v) => self.scroll_left(v),
             ScrollOutcome::Right(v) => self.scroll_right(v),
             ScrollOutcome::HPos(v) => self.set_x_offset(v),
@@ -240,6 +420,7 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for TableState<Cel
 /// Handle all events.
 /// Table events are only processed if focus is true.
 /// Mouse events are processed if they are in range.
+#[cfg(feature = "crossterm")]
 pub fn handle_events(
     state: &mut TableState<CellSelection>,
     focus: bool,
@@ -249,7 +430,21 @@ pub fn handle_events(
     state.handle(event, Regular)
 }
 
+/// Handle all events with the vim-style keybindings.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+#[cfg(feature = "crossterm")]
+pub fn handle_vim_events(
+    state: &mut TableState<CellSelection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    state.focus.set(focus);
+    state.handle(event, Vim)
+}
+
 /// Handle only mouse-events.
+#[cfg(feature = "crossterm")]
 pub fn handle_mouse_events(
     state: &mut TableState<CellSelection>,
     event: &crossterm::event::Event,
@@ -0,0 +1,331 @@
+//!
+//! Popup for hiding and showing columns.
+//!
+//! [ColumnChooserState::open] takes the column names, usually from
+//! the header row, and the current [TableState::collapsed_columns].
+//! The popup lists all columns with a checkbox. When it's closed
+//! with Enter the new visibility is written back to the TableState.
+//!
+//! ```rust
+//! use rat_ftable::chooser::{column_names, ColumnChooserState};
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::textdata::Row;
+//! use rat_ftable::TableState;
+//!
+//! let header = Row::new(["Name", "Size", "Date"]);
+//! let mut table = TableState::<RowSelection>::default();
+//! let mut chooser = ColumnChooserState::default();
+//!
+//! chooser.open(column_names(&header), &table);
+//! chooser.select(1);
+//! chooser.toggle_selected();
+//! chooser.apply(&mut table);
+//!
+//! assert!(table.is_column_collapsed(1));
+//! assert!(!chooser.is_active());
+//! ```
+//!
+//! The popup is opened with Alt-C or with a right-click on the
+//! header, see [ColumnChooserState::handle]. Up/Down move, Space
+//! toggles, Enter applies and Esc cancels.
+//!
+
+use crate::_private::NonExhaustive;
+use crate::textdata::Row;
+#[cfg(feature = "crossterm")]
+use crate::TableSelection;
+use crate::TableState;
+#[cfg(feature = "crossterm")]
+use rat_event::{ct_event, Outcome};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::{Block, Clear, StatefulWidget, Widget};
+use std::cmp::min;
+use unicode_width::UnicodeWidthStr;
+
+/// Column names from a header row.
+///
+/// Multi-line titles are joined with a space.
+pub fn column_names(header: &Row<'_>) -> Vec<String> {
+    header
+        .cells
+        .iter()
+        .map(|cell| {
+            cell.content
+                .lines
+                .iter()
+                .map(|line| {
+                    line.spans
+                        .iter()
+                        .map(|span| span.content.as_ref())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+/// Popup with a checkbox for each column.
+///
+/// The popup is placed in the top-left corner of the area
+/// given to render, which is usually the area of the table.
+#[derive(Debug, Default, Clone)]
+pub struct ColumnChooser<'a> {
+    style: Style,
+    select_style: Option<Style>,
+    block: Option<Block<'a>>,
+}
+
+/// State of the column chooser.
+#[derive(Debug, Clone)]
+pub struct ColumnChooserState {
+    /// Popup is open.
+    pub active: bool,
+    /// Column names.
+    pub names: Vec<String>,
+    /// Visibility per column, as edited in the popup.
+    pub visible: Vec<bool>,
+    /// Selected entry.
+    pub selected: usize,
+    /// First visible entry.
+    pub offset: usize,
+
+    /// Area of the popup.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Area of the list inside the block.
+    /// __readonly__. renewed for each render.
+    pub inner: Rect,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl<'a> ColumnChooser<'a> {
+    /// New popup.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style for the selected entry.
+    pub fn select_style(mut self, style: Option<Style>) -> Self {
+        self.select_style = style;
+        self
+    }
+
+    /// Block. Defaults to a bordered block.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl StatefulWidget for ColumnChooser<'_> {
+    type State = ColumnChooserState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if !state.active {
+            state.area = Rect::default();
+            state.inner = Rect::default();
+            return;
+        }
+
+        let block = self.block.unwrap_or_else(Block::bordered);
+        let text_width = state.names.iter().map(|v| v.width()).max().unwrap_or(0);
+        // size of the borders+padding of the block.
+        let probe = block.inner(Rect::new(0, 0, 100, 100));
+        let border_w = 100 - probe.width;
+        let border_h = 100 - probe.height;
+        // "[x] " + name
+        let width = min(area.width, (text_width as u16 + 4).saturating_add(border_w));
+        let height = min(
+            area.height,
+            (state.names.len() as u16).saturating_add(border_h),
+        );
+        state.area = Rect::new(area.x, area.y, width, height);
+        state.inner = block.inner(state.area);
+
+        let page = state.inner.height as usize;
+        if state.selected < state.offset {
+            state.offset = state.selected;
+        } else if page > 0 && state.selected >= state.offset + page {
+            state.offset = state.selected + 1 - page;
+        }
+
+        Clear.render(state.area, buf);
+        buf.set_style(state.area, self.style);
+        block.render(state.area, buf);
+
+        for (i, name) in state.names.iter().enumerate().skip(state.offset).take(page) {
+            let y = state.inner.y + (i - state.offset) as u16;
+            let check = if state.visible[i] { "[x] " } else { "[ ] " };
+            let line = format!("{}{}", check, name);
+            buf.set_stringn(
+                state.inner.x,
+                y,
+                line,
+                state.inner.width as usize,
+                self.style,
+            );
+            if i == state.selected {
+                let select_style = self.select_style.unwrap_or(self.style.reversed());
+                buf.set_style(
+                    Rect::new(state.inner.x, y, state.inner.width, 1),
+                    select_style,
+                );
+            }
+        }
+    }
+}
+
+impl Default for ColumnChooserState {
+    fn default() -> Self {
+        Self {
+            active: false,
+            names: Default::default(),
+            visible: Default::default(),
+            selected: 0,
+            offset: 0,
+            area: Default::default(),
+            inner: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl ColumnChooserState {
+    /// New state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Popup is open.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Open the popup with the current visibility of the table.
+    pub fn open<Selection>(&mut self, names: Vec<String>, table: &TableState<Selection>) {
+        self.visible = (0..names.len())
+            .map(|col| !table.collapsed_columns.contains(&col))
+            .collect();
+        self.names = names;
+        self.selected = 0;
+        self.offset = 0;
+        self.active = true;
+    }
+
+    /// Close the popup without changes.
+    pub fn cancel(&mut self) {
+        self.active = false;
+    }
+
+    /// Close the popup and write the visibility back to the table.
+    pub fn apply<Selection>(&mut self, table: &mut TableState<Selection>) {
+        for (col, visible) in self.visible.iter().enumerate() {
+            if *visible {
+                table.collapsed_columns.remove(&col);
+            } else {
+                table.collapsed_columns.insert(col);
+            }
+        }
+        self.active = false;
+    }
+
+    /// Select an entry.
+    pub fn select(&mut self, idx: usize) -> bool {
+        let old = self.selected;
+        self.selected = min(idx, self.names.len().saturating_sub(1));
+        old != self.selected
+    }
+
+    /// Toggle the selected entry.
+    pub fn toggle_selected(&mut self) -> bool {
+        if let Some(v) = self.visible.get_mut(self.selected) {
+            *v = !*v;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Key and mouse actions for the column chooser.
+    ///
+    /// Alt-C opens the popup if the table is focused, a right-click
+    /// on the header of the table opens it too. The column names are
+    /// only needed for opening the popup.
+    ///
+    /// While the popup is open it consumes all key events.
+    #[cfg(feature = "crossterm")]
+    pub fn handle<Selection: TableSelection>(
+        &mut self,
+        event: &crossterm::event::Event,
+        names: impl FnOnce() -> Vec<String>,
+        table: &mut TableState<Selection>,
+    ) -> Outcome {
+        use rat_focus::HasFocus;
+
+        if !self.active {
+            return match event {
+                ct_event!(key press ALT-'c') if table.is_focused() => {
+                    self.open(names(), table);
+                    Outcome::Changed
+                }
+                ct_event!(mouse down Right for column, row)
+                    if table.header_area.contains((*column, *row).into()) =>
+                {
+                    self.open(names(), table);
+                    Outcome::Changed
+                }
+                _ => Outcome::Continue,
+            };
+        }
+
+        match event {
+            ct_event!(keycode press Up) => self.select(self.selected.saturating_sub(1)).into(),
+            ct_event!(keycode press Down) => self.select(self.selected + 1).into(),
+            ct_event!(keycode press Home) => self.select(0).into(),
+            ct_event!(keycode press End) => self.select(usize::MAX).into(),
+            ct_event!(key press ' ') => self.toggle_selected().into(),
+            ct_event!(keycode press Enter) => {
+                self.apply(table);
+                Outcome::Changed
+            }
+            ct_event!(keycode press Esc) => {
+                self.cancel();
+                Outcome::Changed
+            }
+            ct_event!(mouse down Left for column, row)
+                if self.inner.contains((*column, *row).into()) =>
+            {
+                let idx = self.offset + (*row - self.inner.y) as usize;
+                if idx < self.names.len() {
+                    self.select(idx);
+                    self.toggle_selected();
+                }
+                Outcome::Changed
+            }
+            ct_event!(scroll up for column, row) if self.area.contains((*column, *row).into()) => {
+                self.select(self.selected.saturating_sub(1)).into()
+            }
+            ct_event!(scroll down for column, row)
+                if self.area.contains((*column, *row).into()) =>
+            {
+                self.select(self.selected + 1).into()
+            }
+            ct_event!(mouse down Left for _column, _row) => {
+                self.cancel();
+                Outcome::Changed
+            }
+            crossterm::event::Event::Key(_) => Outcome::Unchanged,
+            _ => Outcome::Continue,
+        }
+    }
+}
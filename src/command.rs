@@ -0,0 +1,195 @@
+//!
+//! Commands for driving the table without key events.
+//!
+//! Use TableState::execute() to run a command. The commands have
+//! the same semantics as the corresponding keybindings.
+//!
+//! [Recorder] converts incoming events to commands, and [replay]
+//! runs them again. This way scroll/selection behaviour can be
+//! reproduced in tests.
+//!
+//! ```rust
+//! use rat_ftable::command::{replay, Recorder, TableCommand};
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::TableState;
+//!
+//! let mut state = TableState::<RowSelection>::default();
+//! state.rows = 100;
+//!
+//! let commands = [
+//!     TableCommand::MoveToRow(10),
+//!     TableCommand::MoveDown(5),
+//!     TableCommand::MoveUp(2),
+//! ];
+//! replay(&mut state, &commands);
+//! assert_eq!(state.selected(), Some(13));
+//!
+//! let mut recorder = Recorder::new();
+//! recorder.push(TableCommand::MoveLast);
+//! assert_eq!(recorder.commands(), &[TableCommand::MoveLast]);
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::event::Outcome;
+
+/// Scroll and selection commands.
+///
+/// Commands that don't make sense for a selection model
+/// return [Outcome::Continue](crate::event::Outcome::Continue).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableCommand {
+    /// Move the selection up.
+    MoveUp(usize),
+    /// Move the selection down.
+    MoveDown(usize),
+    /// Move the selection left.
+    MoveLeft(usize),
+    /// Move the selection right.
+    MoveRight(usize),
+    /// Move the selection to the row.
+    MoveToRow(usize),
+    /// Move the selection to the column.
+    MoveToColumn(usize),
+    /// Move the selection to the cell (column, row).
+    MoveToCell(usize, usize),
+    /// Move the selection to the first row.
+    MoveFirst,
+    /// Move the selection to the last row.
+    MoveLast,
+    /// Move the selection one page up.
+    PageUp,
+    /// Move the selection one page down.
+    PageDown,
+    /// Extend the selection up.
+    ExtendUp(usize),
+    /// Extend the selection down.
+    ExtendDown(usize),
+    /// Extend the selection to the row.
+    ExtendToRow(usize),
+    /// Select all rows.
+    SelectAll,
+    /// Clear the selection.
+    ClearSelection,
+    /// Scroll up.
+    ScrollUp(usize),
+    /// Scroll down.
+    ScrollDown(usize),
+    /// Scroll left.
+    ScrollLeft(usize),
+    /// Scroll right.
+    ScrollRight(usize),
+    /// Scroll the row into view.
+    ScrollToRow(usize),
+    /// Scroll the column into view.
+    ScrollToColumn(usize),
+    /// Scroll all the way to the left.
+    ScrollLeftmost,
+    /// Scroll all the way to the right.
+    ScrollRightmost,
+    /// Move the selection to the row and record the jump.
+    JumpToRow(usize),
+    /// Go back in the jump list.
+    JumpBack,
+    /// Go forward in the jump list.
+    JumpForward,
+}
+
+/// Maps events to commands and executes them.
+///
+/// Implemented for TableState with all the selection models.
+pub trait TableCommands {
+    /// Command for the event, using the regular keybindings.
+    ///
+    /// Key events are only mapped if the table is focused.
+    /// Mouse clicks and the mouse wheel are mapped if they are in
+    /// range. Other events, for example mouse drags or double-clicks,
+    /// have no command.
+    #[cfg(feature = "crossterm")]
+    fn command_for(&self, event: &crossterm::event::Event) -> Option<TableCommand>;
+
+    /// Execute the command.
+    fn execute_command(&mut self, cmd: TableCommand) -> Outcome;
+}
+
+/// Records the commands for incoming events.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    /// Recorded commands.
+    pub commands: Vec<TableCommand>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self {
+            commands: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Recorder {
+    /// New recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map the event to a command, record and execute it.
+    ///
+    /// Returns Outcome::Continue if the event has no command.
+    /// Such events can still be handled the usual way, but they
+    /// will be missing when replaying.
+    #[cfg(feature = "crossterm")]
+    pub fn handle<S: TableCommands>(
+        &mut self,
+        state: &mut S,
+        event: &crossterm::event::Event,
+    ) -> Outcome {
+        if let Some(cmd) = state.command_for(event) {
+            self.commands.push(cmd);
+            state.execute_command(cmd)
+        } else {
+            Outcome::Continue
+        }
+    }
+
+    /// Map the event to a command and record it, but don't execute it.
+    #[cfg(feature = "crossterm")]
+    pub fn record<S: TableCommands>(
+        &mut self,
+        state: &S,
+        event: &crossterm::event::Event,
+    ) -> Option<TableCommand> {
+        let cmd = state.command_for(event)?;
+        self.commands.push(cmd);
+        Some(cmd)
+    }
+
+    /// Record a command.
+    pub fn push(&mut self, cmd: TableCommand) {
+        self.commands.push(cmd);
+    }
+
+    /// Recorded commands.
+    pub fn commands(&self) -> &[TableCommand] {
+        &self.commands
+    }
+
+    /// Clear the recording.
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+}
+
+/// Executes all the commands.
+///
+/// Returns the maximum of all outcomes.
+pub fn replay<S: TableCommands>(state: &mut S, commands: &[TableCommand]) -> Outcome {
+    commands
+        .iter()
+        .map(|cmd| state.execute_command(*cmd))
+        .max()
+        .unwrap_or(Outcome::Continue)
+}
@@ -0,0 +1,62 @@
+//!
+//! Aliases for the names used before the crate was renamed
+//! from FTable to Table.
+//!
+//! Needs the feature `compat`. Everything here is deprecated
+//! and only meant to ease the upgrade.
+//!
+//! | old | new |
+//! |---|---|
+//! | `FTable` | [Table] |
+//! | `FTableState` | [TableState] |
+//! | `FTableStyle` | [TableStyle] |
+//! | `FTableContext` | [TableContext] |
+//! | `FocusKeys` | [Regular] |
+//! | `state.row_offset` | [TableState::row_offset] / [TableState::set_row_offset] |
+//! | `state.max_row_offset` | [FTableStateCompat::max_row_offset] |
+//! | `Row::style(Option<Style>)` | [Row::style](crate::textdata::Row::style), unchanged |
+//!
+
+use crate::{Table, TableContext, TableState, TableStyle};
+use rat_event::Regular;
+
+/// Old name of [Table].
+#[deprecated(since = "0.29.1", note = "use Table")]
+pub type FTable<'a, Selection> = Table<'a, Selection>;
+
+/// Old name of [TableState].
+#[deprecated(since = "0.29.1", note = "use TableState")]
+pub type FTableState<Selection> = TableState<Selection>;
+
+/// Old name of [TableStyle].
+#[deprecated(since = "0.29.1", note = "use TableStyle")]
+pub type FTableStyle = TableStyle;
+
+/// Old name of [TableContext].
+#[deprecated(since = "0.29.1", note = "use TableContext")]
+pub type FTableContext = TableContext;
+
+/// Old name of the [Regular] event-handler qualifier.
+#[deprecated(since = "0.29.1", note = "use rat_event::Regular")]
+pub type FocusKeys = Regular;
+
+/// Accessors for the former fields of FTableState.
+#[deprecated(since = "0.29.1", note = "use TableState::vscroll")]
+pub trait FTableStateCompat {
+    /// Maximum row offset.
+    fn max_row_offset(&self) -> usize;
+
+    /// Set the maximum row offset.
+    fn set_max_row_offset(&mut self, offset: usize);
+}
+
+#[allow(deprecated)]
+impl<Selection> FTableStateCompat for TableState<Selection> {
+    fn max_row_offset(&self) -> usize {
+        self.vscroll.max_offset()
+    }
+
+    fn set_max_row_offset(&mut self, offset: usize) {
+        self.vscroll.set_max_offset(offset);
+    }
+}
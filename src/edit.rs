@@ -2,10 +2,18 @@
 //!
 //! Extra keys while viewing are
 //! * Insert - Insert a row and start the editor widget.
-//! * Delete - Delete row.
+//! * Delete - Delete row, or mark it for deletion with soft-delete.
 //! * Enter - Start editor widget.
 //! * Double-Click - Start editor widget.
 //! * Down - Append after the last row and start the editor widget.
+//! * Space - Toggle the value of the first toggle column.
+//! * Click on a toggle column - Toggle the value.
+//! * +/- - Step the value of the first adjustable column.
+//! * Mouse-wheel over an adjustable column - Step the value.
+//!
+//! Toggle columns are declared with `set_toggle_columns()` and
+//! flip a boolean without entering the editor. Adjustable columns
+//! are declared with `set_adjust_columns()` and step a number.
 //!
 //! Keys while editing are
 //! * Esc - Cancel editing.
@@ -53,11 +61,123 @@ pub trait EditorState: FocusContainer {
         ctx: &Self::Context<'_>,
     ) -> Result<(), Self::Err>;
 
+    /// Set editing data for a row.
+    ///
+    /// This gets the neighboring rows too, for editors that
+    /// validate against them. The default calls set_edit_data.
+    #[allow(unused_variables)]
+    fn set_edit_row(
+        &mut self,
+        data: &Self::Data,
+        row: &EditRow<'_, Self::Data>,
+        ctx: &Self::Context<'_>,
+    ) -> Result<(), Self::Err> {
+        self.set_edit_data(data, ctx)
+    }
+
+    /// Copy the editor state back to the data for a row.
+    ///
+    /// This gets the neighboring rows too, for editors that
+    /// validate against them. The default calls get_edit_data.
+    #[allow(unused_variables)]
+    fn get_edit_row(
+        &mut self,
+        data: &mut Self::Data,
+        row: &EditRow<'_, Self::Data>,
+        ctx: &Self::Context<'_>,
+    ) -> Result<(), Self::Err> {
+        self.get_edit_data(data, ctx)
+    }
+
     /// Is this some empty state?
     fn is_empty(&self) -> bool;
 
     /// Returns the currently focused column.
     fn focused_col(&self) -> Option<usize>;
+
+    /// Flip the boolean value of a toggle column directly
+    /// in the data.
+    ///
+    /// Returns true if the data has been changed.
+    /// The default does nothing.
+    #[allow(unused_variables)]
+    fn toggle_value(
+        &mut self,
+        data: &mut Self::Data,
+        column: usize,
+        ctx: &Self::Context<'_>,
+    ) -> Result<bool, Self::Err> {
+        Ok(false)
+    }
+
+    /// Step the numeric value of an adjustable column directly
+    /// in the data.
+    ///
+    /// Returns true if the data has been changed.
+    /// The default does nothing.
+    #[allow(unused_variables)]
+    fn adjust_value(
+        &mut self,
+        data: &mut Self::Data,
+        column: usize,
+        delta: i32,
+        ctx: &Self::Context<'_>,
+    ) -> Result<bool, Self::Err> {
+        Ok(false)
+    }
+}
+
+/// The edited row and its neighbors.
+///
+/// The edited row itself is not part of this, the
+/// rows before and after are.
+#[derive(Debug)]
+pub struct EditRow<'a, D> {
+    row: usize,
+    before: &'a [D],
+    after: &'a [D],
+}
+
+impl<'a, D> EditRow<'a, D> {
+    /// New context for the given row.
+    pub fn new(row: usize, before: &'a [D], after: &'a [D]) -> Self {
+        Self { row, before, after }
+    }
+
+    /// Index of the edited row.
+    #[inline]
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// All rows before the edited row.
+    #[inline]
+    pub fn before(&self) -> &'a [D] {
+        self.before
+    }
+
+    /// All rows after the edited row.
+    #[inline]
+    pub fn after(&self) -> &'a [D] {
+        self.after
+    }
+
+    /// The row before.
+    #[inline]
+    pub fn prev(&self) -> Option<&'a D> {
+        self.before.last()
+    }
+
+    /// The row after.
+    #[inline]
+    pub fn next(&self) -> Option<&'a D> {
+        self.after.first()
+    }
+
+    /// All other rows.
+    pub fn siblings(&self) -> impl Iterator<Item = &'a D> {
+        self.before.iter().chain(self.after.iter())
+    }
 }
 
 /// Editing mode.
@@ -8,12 +8,16 @@
 //! There is `examples/table_edit1.rs`.
 
 use crate::edit::{Editor, EditorState, Mode};
+#[cfg(feature = "crossterm")]
 use crate::event::EditOutcome;
 use crate::rowselection::RowSelection;
-use crate::{Table, TableSelection, TableState};
+#[cfg(feature = "crossterm")]
+use crate::TableSelection;
+use crate::{Table, TableState};
 use log::warn;
 use rat_cursor::HasScreenCursor;
 use rat_event::util::MouseFlags;
+#[cfg(feature = "crossterm")]
 use rat_event::{ct_event, flow, HandleEvent, Outcome, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use ratatui::buffer::Buffer;
@@ -52,6 +56,12 @@ pub struct EditTableState<S> {
     pub editor: S,
     /// Focus-flag for the whole editor widget.
     pub editor_focus: FocusFlag,
+    /// Columns that toggle a boolean value instead of
+    /// starting the editor.
+    pub toggle_columns: Vec<usize>,
+    /// Numeric columns that can be stepped with +/- or
+    /// the mouse-wheel.
+    pub adjust_columns: Vec<usize>,
 
     pub mouse: MouseFlags,
 }
@@ -127,6 +137,8 @@ where
             table: Default::default(),
             editor: S::default(),
             editor_focus: Default::default(),
+            toggle_columns: Default::default(),
+            adjust_columns: Default::default(),
             mouse: Default::default(),
         }
     }
@@ -194,6 +206,8 @@ impl<S> EditTableState<S> {
             table: TableState::new(),
             editor,
             editor_focus: Default::default(),
+            toggle_columns: Default::default(),
+            adjust_columns: Default::default(),
             mouse: Default::default(),
         }
     }
@@ -206,10 +220,41 @@ impl<S> EditTableState<S> {
             editor,
             mouse: Default::default(),
             editor_focus: Default::default(),
+            toggle_columns: Default::default(),
+            adjust_columns: Default::default(),
         }
     }
 }
 
+impl<S> EditTableState<S> {
+    /// Declare the toggle columns.
+    ///
+    /// Space or a click on one of these columns results in
+    /// [EditOutcome::Toggle](crate::event::EditOutcome::Toggle)
+    /// instead of starting the editor.
+    pub fn set_toggle_columns(&mut self, columns: impl IntoIterator<Item = usize>) {
+        self.toggle_columns = columns.into_iter().collect();
+    }
+
+    /// Is this a toggle column.
+    pub fn is_toggle_column(&self, column: usize) -> bool {
+        self.toggle_columns.contains(&column)
+    }
+
+    /// Declare the adjustable columns.
+    ///
+    /// +/- or the mouse-wheel over one of these columns results in
+    /// [EditOutcome::ValueDelta](crate::event::EditOutcome::ValueDelta).
+    pub fn set_adjust_columns(&mut self, columns: impl IntoIterator<Item = usize>) {
+        self.adjust_columns = columns.into_iter().collect();
+    }
+
+    /// Is this an adjustable column.
+    pub fn is_adjust_column(&self, column: usize) -> bool {
+        self.adjust_columns.contains(&column)
+    }
+}
+
 impl<S> EditTableState<S>
 where
     S: EditorState,
@@ -336,6 +381,7 @@ where
     }
 }
 
+#[cfg(feature = "crossterm")]
 impl<'a, S> HandleEvent<crossterm::event::Event, &'a S::Context<'a>, EditOutcome>
     for EditTableState<S>
 where
@@ -378,12 +424,35 @@ where
             }
             EditOutcome::Continue
         } else {
+            flow!(match event {
+                ct_event!(mouse down Left for x, y) => {
+                    match self.table.cell_at_clicked((*x, *y)) {
+                        Some((col, row)) if self.is_toggle_column(col) => {
+                            self.table.select(Some(row));
+                            EditOutcome::Toggle(col, row)
+                        }
+                        _ => EditOutcome::Continue,
+                    }
+                }
+                ct_event!(scroll up for x, y) => match self.table.cell_at_clicked((*x, *y)) {
+                    Some((col, row)) if self.is_adjust_column(col) => {
+                        EditOutcome::ValueDelta(col, row, 1)
+                    }
+                    _ => EditOutcome::Continue,
+                },
+                ct_event!(scroll down for x, y) => match self.table.cell_at_clicked((*x, *y)) {
+                    Some((col, row)) if self.is_adjust_column(col) => {
+                        EditOutcome::ValueDelta(col, row, -1)
+                    }
+                    _ => EditOutcome::Continue,
+                },
+                _ => EditOutcome::Continue,
+            });
             flow!(match event {
                 ct_event!(mouse any for m) if self.mouse.doubleclick(self.table.table_area, m) => {
-                    if self.table.cell_at_clicked((m.column, m.row)).is_some() {
-                        EditOutcome::Edit
-                    } else {
-                        EditOutcome::Continue
+                    match self.table.cell_at_clicked((m.column, m.row)) {
+                        Some((col, _)) if !self.is_toggle_column(col) => EditOutcome::Edit,
+                        _ => EditOutcome::Continue,
                     }
                 }
                 _ => EditOutcome::Continue,
@@ -391,6 +460,24 @@ where
 
             if self.table.is_focused() {
                 flow!(match event {
+                    ct_event!(key press ' ') => {
+                        match (self.toggle_columns.first(), self.table.selected()) {
+                            (Some(col), Some(row)) => EditOutcome::Toggle(*col, row),
+                            _ => EditOutcome::Continue,
+                        }
+                    }
+                    ct_event!(key press '+') => {
+                        match (self.adjust_columns.first(), self.table.selected()) {
+                            (Some(col), Some(row)) => EditOutcome::ValueDelta(*col, row, 1),
+                            _ => EditOutcome::Continue,
+                        }
+                    }
+                    ct_event!(key press '-') => {
+                        match (self.adjust_columns.first(), self.table.selected()) {
+                            (Some(col), Some(row)) => EditOutcome::ValueDelta(*col, row, -1),
+                            _ => EditOutcome::Continue,
+                        }
+                    }
                     ct_event!(keycode press Insert) => {
                         EditOutcome::Insert
                     }
@@ -8,20 +8,28 @@
 //! For examples go to the rat-widget crate.
 //! There is `examples/table_edit2.rs`.
 
-use crate::edit::{Editor, EditorState, Mode};
+use crate::edit::{EditRow, Editor, EditorState, Mode};
 use crate::rowselection::RowSelection;
 use crate::textdata::Row;
-use crate::{Table, TableContext, TableData, TableSelection, TableState};
+#[cfg(feature = "crossterm")]
+use crate::TableSelection;
+use crate::{Badge, FormattedRow, Table, TableContext, TableData, TableState};
 use log::warn;
 use rat_cursor::HasScreenCursor;
 use rat_event::util::MouseFlags;
+#[cfg(feature = "crossterm")]
 use rat_event::{ct_event, try_flow, HandleEvent, Outcome, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Rect};
 use ratatui::prelude::{StatefulWidget, Style};
+use ratatui::style::Modifier;
+use std::borrow::Cow;
 use std::cell::RefCell;
+use std::cmp::min;
+use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
+use std::ops::Range;
 use std::rc::Rc;
 
 /// Extends TableData with the capability to set the actual data
@@ -49,6 +57,7 @@ where
     table: Table<'a, RowSelection>,
     table_data: Box<dyn EditorData<<<E as Editor>::State as EditorState>::Data>>,
     editor: E,
+    deleted_style: Style,
 }
 
 /// State for EditTable.
@@ -70,12 +79,39 @@ where
     pub editor: S,
     /// Focus-flag for the whole editor widget.
     pub editor_focus: FocusFlag,
+    /// Columns that toggle a boolean value instead of
+    /// starting the editor.
+    pub toggle_columns: Vec<usize>,
+    /// Numeric columns that can be stepped with +/- or
+    /// the mouse-wheel.
+    pub adjust_columns: Vec<usize>,
+    /// Delete only marks the row for deletion.
+    /// See [EditVecState::set_soft_delete].
+    pub soft_delete: bool,
+    /// Rows marked for deletion.
+    pub deleted: HashSet<usize>,
+    /// Running batch.
+    /// See [EditVecState::begin_batch].
+    pub batch: Option<EditBatch<S::Data>>,
     /// Data store
     pub editor_data: Rc<RefCell<Vec<S::Data>>>,
 
     pub mouse: MouseFlags,
 }
 
+/// Snapshot for a batch of changes.
+///
+/// See [EditVecState::begin_batch].
+#[derive(Debug)]
+pub struct EditBatch<D> {
+    /// Data before the batch.
+    pub data: Vec<D>,
+    /// Rows marked for deletion before the batch.
+    pub deleted: HashSet<usize>,
+    /// Lowest row changed by the batch.
+    pub first: usize,
+}
+
 impl<'a, E> EditVec<'a, E>
 where
     E: Editor + 'a,
@@ -89,8 +125,17 @@ where
             table,
             table_data: Box::new(table_data),
             editor,
+            deleted_style: Style::new().add_modifier(Modifier::CROSSED_OUT | Modifier::DIM),
         }
     }
+
+    /// Style for rows that are marked for deletion.
+    /// See [EditVecState::set_soft_delete].
+    #[inline]
+    pub fn deleted_style(mut self, style: Style) -> Self {
+        self.deleted_style = style;
+        self
+    }
 }
 
 impl<'a, D> TableData<'a> for Box<dyn EditorData<D> + 'a> {
@@ -118,6 +163,34 @@ impl<'a, D> TableData<'a> for Box<dyn EditorData<D> + 'a> {
         (**self).widths()
     }
 
+    fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        (**self).cell_text(column, row)
+    }
+
+    fn row_summary_style(&self, row: usize) -> Option<Style> {
+        (**self).row_summary_style(row)
+    }
+
+    fn version(&self) -> Option<u64> {
+        (**self).version()
+    }
+
+    fn row_progress(&self, row: usize) -> Option<f32> {
+        (**self).row_progress(row)
+    }
+
+    fn cell_badge(&self, column: usize, row: usize) -> Option<Badge> {
+        (**self).cell_badge(column, row)
+    }
+
+    fn render_detail(&self, row: usize, area: Rect, buf: &mut Buffer) {
+        (**self).render_detail(row, area, buf)
+    }
+
+    fn format_rows(&self, rows: Range<usize>) -> Vec<FormattedRow<'a>> {
+        (**self).format_rows(rows)
+    }
+
     fn render_cell(
         &self,
         ctx: &TableContext,
@@ -140,6 +213,7 @@ where
             .field("table", &self.table)
             .field("table_data", &"..dyn..")
             .field("editor", &self.editor)
+            .field("deleted_style", &self.deleted_style)
             .finish()
     }
 }
@@ -157,6 +231,12 @@ where
             .data(self.table_data)
             .render(area, buf, &mut state.table);
 
+        for row in state.deleted.iter() {
+            if let Some((row_area, _)) = state.table.row_cells(*row) {
+                buf.set_style(row_area.intersection(buf.area), self.deleted_style);
+            }
+        }
+
         if state.mode == Mode::Insert || state.mode == Mode::Edit {
             if let Some(row) = state.table.selected() {
                 // but it might be out of view
@@ -184,6 +264,11 @@ where
             table: Default::default(),
             editor: S::default(),
             editor_focus: Default::default(),
+            toggle_columns: Default::default(),
+            adjust_columns: Default::default(),
+            soft_delete: Default::default(),
+            deleted: Default::default(),
+            batch: Default::default(),
             editor_data: Rc::new(RefCell::new(Vec::default())),
             mouse: Default::default(),
         }
@@ -258,6 +343,11 @@ where
             table: TableState::new(),
             editor,
             editor_focus: Default::default(),
+            toggle_columns: Default::default(),
+            adjust_columns: Default::default(),
+            soft_delete: Default::default(),
+            deleted: Default::default(),
+            batch: Default::default(),
             editor_data: Rc::new(RefCell::new(vec![])),
             mouse: Default::default(),
         }
@@ -269,6 +359,11 @@ where
             table: TableState::named(name),
             editor,
             editor_focus: Default::default(),
+            toggle_columns: Default::default(),
+            adjust_columns: Default::default(),
+            soft_delete: Default::default(),
+            deleted: Default::default(),
+            batch: Default::default(),
             editor_data: Rc::new(RefCell::new(vec![])),
             mouse: Default::default(),
         }
@@ -290,12 +385,15 @@ where
     }
 
     /// Remove the item at the selected row.
+    ///
+    /// Does nothing while a batch is running.
     pub fn remove(&mut self, row: usize) {
-        if self.mode != Mode::View {
+        if self.mode != Mode::View || self.batch.is_some() {
             return;
         }
         if row < self.editor_data.borrow().len() {
             self.editor_data.borrow_mut().remove(row);
+            self.deleted_removed(row);
             self.table.items_removed(row, 1);
             if !self.table.scroll_to_row(row) {
                 self.table.scroll_to_row(row.saturating_sub(1));
@@ -304,13 +402,21 @@ where
     }
 
     /// Edit a new item inserted at the selected row.
+    ///
+    /// Does nothing while a batch is running.
     pub fn edit_new(&mut self, row: usize, ctx: &S::Context<'_>) -> Result<(), S::Err> {
-        if self.mode != Mode::View {
+        if self.mode != Mode::View || self.batch.is_some() {
             return Ok(());
         }
         let value = self.editor.new_edit_data(ctx)?;
-        self.editor.set_edit_data(&value, ctx)?;
+        {
+            let data = self.editor_data.borrow();
+            let (before, after) = data.split_at(row);
+            let edit_row = EditRow::new(row, before, after);
+            self.editor.set_edit_row(&value, &edit_row, ctx)?;
+        }
         self.editor_data.borrow_mut().insert(row, value);
+        self.deleted_added(row);
         self._start(row, Mode::Insert);
         Ok(())
     }
@@ -321,8 +427,11 @@ where
             return Ok(());
         }
         {
-            let value = &self.editor_data.borrow()[row];
-            self.editor.set_edit_data(value, ctx)?;
+            let data = self.editor_data.borrow();
+            let (before, rest) = data.split_at(row);
+            let (value, after) = rest.split_first().expect("row");
+            let edit_row = EditRow::new(row, before, after);
+            self.editor.set_edit_row(value, &edit_row, ctx)?;
         }
         self._start(row, Mode::Edit);
         Ok(())
@@ -355,6 +464,7 @@ where
         };
         if self.mode == Mode::Insert {
             self.editor_data.borrow_mut().remove(row);
+            self.deleted_removed(row);
             self.table.items_removed(row, 1);
         }
         self._stop();
@@ -369,8 +479,11 @@ where
             return Ok(());
         };
         {
-            let value = &mut self.editor_data.borrow_mut()[row];
-            self.editor.get_edit_data(value, ctx)?;
+            let mut data = self.editor_data.borrow_mut();
+            let (before, rest) = data.split_at_mut(row);
+            let (value, after) = rest.split_first_mut().expect("row");
+            let edit_row = EditRow::new(row, before, after);
+            self.editor.get_edit_row(value, &edit_row, ctx)?;
         }
         self._stop();
         Ok(())
@@ -395,6 +508,277 @@ where
         Ok(())
     }
 
+    /// Delete only marks a row for deletion, and the marked rows
+    /// are rendered with the deleted style. Delete again unmarks
+    /// the row. The rows are removed with
+    /// [purge_deleted](EditVecState::purge_deleted), e.g. when
+    /// saving the data.
+    pub fn set_soft_delete(&mut self, soft_delete: bool) {
+        self.soft_delete = soft_delete;
+    }
+
+    /// Delete only marks rows for deletion.
+    pub fn is_soft_delete(&self) -> bool {
+        self.soft_delete
+    }
+
+    /// Mark/unmark the row for deletion.
+    ///
+    /// Returns true if the row is marked now.
+    pub fn toggle_deleted(&mut self, row: usize) -> bool {
+        if self.mode != Mode::View || row >= self.editor_data.borrow().len() {
+            return false;
+        }
+        if self.deleted.remove(&row) {
+            false
+        } else {
+            self.deleted.insert(row);
+            true
+        }
+    }
+
+    /// Is the row marked for deletion.
+    pub fn is_deleted(&self, row: usize) -> bool {
+        self.deleted.contains(&row)
+    }
+
+    /// Remove all rows that are marked for deletion.
+    ///
+    /// Returns the removed rows.
+    ///
+    /// Does nothing while a batch is running.
+    pub fn purge_deleted(&mut self) -> Vec<S::Data> {
+        if self.mode != Mode::View || self.batch.is_some() {
+            return Vec::new();
+        }
+        let mut rows = self.deleted.drain().collect::<Vec<_>>();
+        rows.sort_unstable();
+
+        let mut removed = Vec::new();
+        for row in rows.into_iter().rev() {
+            if row < self.editor_data.borrow().len() {
+                removed.push(self.editor_data.borrow_mut().remove(row));
+                self.table.items_removed(row, 1);
+            }
+        }
+        removed.reverse();
+        if let Some(row) = self.table.selected() {
+            if !self.table.scroll_to_row(row) {
+                self.table.scroll_to_row(row.saturating_sub(1));
+            }
+        }
+        removed
+    }
+
+    /// Start a batch of changes.
+    ///
+    /// Use [batch_insert](EditVecState::batch_insert) and
+    /// [batch_remove](EditVecState::batch_remove) to add and remove
+    /// rows, and change existing rows via editor_data. The table
+    /// state is only updated with commit_batch(). rollback_batch()
+    /// restores the data as it was at this point.
+    ///
+    /// Returns false if a batch is already running or the
+    /// editor is active.
+    pub fn begin_batch(&mut self) -> bool
+    where
+        S::Data: Clone,
+    {
+        if self.mode != Mode::View || self.batch.is_some() {
+            return false;
+        }
+        self.batch = Some(EditBatch {
+            data: self.editor_data.borrow().clone(),
+            deleted: self.deleted.clone(),
+            first: usize::MAX,
+        });
+        true
+    }
+
+    /// A batch is running.
+    pub fn is_batch(&self) -> bool {
+        self.batch.is_some()
+    }
+
+    /// Insert a row as part of the batch.
+    ///
+    /// Without a running batch this does nothing.
+    pub fn batch_insert(&mut self, row: usize, value: S::Data) {
+        let Some(batch) = &mut self.batch else {
+            return;
+        };
+        let mut data = self.editor_data.borrow_mut();
+        let row = min(row, data.len());
+        data.insert(row, value);
+        batch.first = min(batch.first, row);
+        drop(data);
+        self.deleted_added(row);
+    }
+
+    /// Remove a row as part of the batch.
+    ///
+    /// Without a running batch this does nothing.
+    pub fn batch_remove(&mut self, row: usize) -> Option<S::Data> {
+        let Some(batch) = &mut self.batch else {
+            return None;
+        };
+        let mut data = self.editor_data.borrow_mut();
+        if row >= data.len() {
+            return None;
+        }
+        let value = data.remove(row);
+        batch.first = min(batch.first, row);
+        drop(data);
+        self.deleted_removed(row);
+        Some(value)
+    }
+
+    /// Finish the batch and update the table state.
+    ///
+    /// The change of the row count is applied as one
+    /// items_added()/items_removed() at the first changed row.
+    pub fn commit_batch(&mut self) {
+        let Some(batch) = self.batch.take() else {
+            return;
+        };
+        let old_len = batch.data.len();
+        let new_len = self.editor_data.borrow().len();
+        let first = min(batch.first, old_len);
+        if new_len > old_len {
+            self.table.items_added(first, new_len - old_len);
+        } else if new_len < old_len {
+            self.table.items_removed(first, old_len - new_len);
+            if let Some(row) = self.table.selected() {
+                if row >= new_len {
+                    self.table.select(new_len.checked_sub(1));
+                }
+            }
+        }
+    }
+
+    /// Throw away all changes since begin_batch().
+    pub fn rollback_batch(&mut self) {
+        let Some(batch) = self.batch.take() else {
+            return;
+        };
+        *self.editor_data.borrow_mut() = batch.data;
+        self.deleted = batch.deleted;
+    }
+
+    /// Runs the function as a batch.
+    ///
+    /// Commits if the function returns Ok, and rolls back all
+    /// changes otherwise. If no batch can be started, because one
+    /// is already running or the editor is active, the function
+    /// is just called.
+    pub fn apply_batch<E>(&mut self, f: impl FnOnce(&mut Self) -> Result<(), E>) -> Result<(), E>
+    where
+        S::Data: Clone,
+    {
+        if !self.begin_batch() {
+            return f(self);
+        }
+        match f(self) {
+            Ok(()) => {
+                self.commit_batch();
+                Ok(())
+            }
+            Err(e) => {
+                self.rollback_batch();
+                Err(e)
+            }
+        }
+    }
+
+    fn deleted_added(&mut self, pos: usize) {
+        if !self.deleted.is_empty() {
+            self.deleted = self
+                .deleted
+                .iter()
+                .map(|v| if *v >= pos { *v + 1 } else { *v })
+                .collect();
+        }
+    }
+
+    fn deleted_removed(&mut self, pos: usize) {
+        if !self.deleted.is_empty() {
+            self.deleted = self
+                .deleted
+                .iter()
+                .filter(|v| **v != pos)
+                .map(|v| if *v > pos { *v - 1 } else { *v })
+                .collect();
+        }
+    }
+
+    /// Declare the toggle columns.
+    ///
+    /// Space or a click on one of these columns calls
+    /// [EditorState::toggle_value] for the row instead of
+    /// starting the editor.
+    pub fn set_toggle_columns(&mut self, columns: impl IntoIterator<Item = usize>) {
+        self.toggle_columns = columns.into_iter().collect();
+    }
+
+    /// Is this a toggle column.
+    pub fn is_toggle_column(&self, column: usize) -> bool {
+        self.toggle_columns.contains(&column)
+    }
+
+    /// Declare the adjustable columns.
+    ///
+    /// +/- or the mouse-wheel over one of these columns calls
+    /// [EditorState::adjust_value] for the row.
+    pub fn set_adjust_columns(&mut self, columns: impl IntoIterator<Item = usize>) {
+        self.adjust_columns = columns.into_iter().collect();
+    }
+
+    /// Is this an adjustable column.
+    pub fn is_adjust_column(&self, column: usize) -> bool {
+        self.adjust_columns.contains(&column)
+    }
+
+    /// Step the value of an adjustable column in the given row.
+    ///
+    /// Returns true if the data has been changed.
+    pub fn adjust(
+        &mut self,
+        column: usize,
+        row: usize,
+        delta: i32,
+        ctx: &S::Context<'_>,
+    ) -> Result<bool, S::Err> {
+        if self.mode != Mode::View {
+            return Ok(false);
+        }
+        let mut data = self.editor_data.borrow_mut();
+        if let Some(value) = data.get_mut(row) {
+            self.editor.adjust_value(value, column, delta, ctx)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Flip the value of a toggle column in the given row.
+    ///
+    /// Returns true if the data has been changed.
+    pub fn toggle(
+        &mut self,
+        column: usize,
+        row: usize,
+        ctx: &S::Context<'_>,
+    ) -> Result<bool, S::Err> {
+        if self.mode != Mode::View {
+            return Ok(false);
+        }
+        let mut data = self.editor_data.borrow_mut();
+        if let Some(value) = data.get_mut(row) {
+            self.editor.toggle_value(value, column, ctx)
+        } else {
+            Ok(false)
+        }
+    }
+
     fn _stop(&mut self) {
         self.mode = Mode::View;
         if self.editor_focus.get() {
@@ -405,6 +789,7 @@ where
     }
 }
 
+#[cfg(feature = "crossterm")]
 impl<'a, S> HandleEvent<crossterm::event::Event, &'a S::Context<'a>, Result<Outcome, S::Err>>
     for EditVecState<S>
 where
@@ -455,19 +840,92 @@ where
 
             Ok(Outcome::Continue)
         } else {
+            try_flow!(match event {
+                ct_event!(mouse down Left for x, y) => {
+                    match self.table.cell_at_clicked((*x, *y)) {
+                        Some((col, row)) if self.is_toggle_column(col) => {
+                            let selected = self.table.select(Some(row));
+                            if self.toggle(col, row, ctx)? || selected {
+                                Outcome::Changed
+                            } else {
+                                Outcome::Unchanged
+                            }
+                        }
+                        _ => Outcome::Continue,
+                    }
+                }
+                ct_event!(scroll up for x, y) => match self.table.cell_at_clicked((*x, *y)) {
+                    Some((col, row)) if self.is_adjust_column(col) => {
+                        if self.adjust(col, row, 1, ctx)? {
+                            Outcome::Changed
+                        } else {
+                            Outcome::Unchanged
+                        }
+                    }
+                    _ => Outcome::Continue,
+                },
+                ct_event!(scroll down for x, y) => match self.table.cell_at_clicked((*x, *y)) {
+                    Some((col, row)) if self.is_adjust_column(col) => {
+                        if self.adjust(col, row, -1, ctx)? {
+                            Outcome::Changed
+                        } else {
+                            Outcome::Unchanged
+                        }
+                    }
+                    _ => Outcome::Continue,
+                },
+                _ => Outcome::Continue,
+            });
             try_flow!(match event {
                 ct_event!(mouse any for m) if self.mouse.doubleclick(self.table.table_area, m) => {
-                    if let Some((_col, row)) = self.table.cell_at_clicked((m.column, m.row)) {
-                        self.edit(row, ctx)?;
-                        Outcome::Changed
-                    } else {
-                        Outcome::Continue
+                    match self.table.cell_at_clicked((m.column, m.row)) {
+                        Some((col, row)) if !self.is_toggle_column(col) => {
+                            self.edit(row, ctx)?;
+                            Outcome::Changed
+                        }
+                        _ => Outcome::Continue,
                     }
                 }
                 _ => Outcome::Continue,
             });
 
             try_flow!(match event {
+                ct_event!(key press ' ') => {
+                    match (self.toggle_columns.first(), self.table.selected()) {
+                        (Some(&col), Some(row)) => {
+                            if self.toggle(col, row, ctx)? {
+                                Outcome::Changed
+                            } else {
+                                Outcome::Unchanged
+                            }
+                        }
+                        _ => Outcome::Continue,
+                    }
+                }
+                ct_event!(key press '+') => {
+                    match (self.adjust_columns.first(), self.table.selected()) {
+                        (Some(&col), Some(row)) => {
+                            if self.adjust(col, row, 1, ctx)? {
+                                Outcome::Changed
+                            } else {
+                                Outcome::Unchanged
+                            }
+                        }
+                        _ => Outcome::Continue,
+                    }
+                }
+                ct_event!(key press '-') => {
+                    match (self.adjust_columns.first(), self.table.selected()) {
+                        (Some(&col), Some(row)) => {
+                            if self.adjust(col, row, -1, ctx)? {
+                                Outcome::Changed
+                            } else {
+                                Outcome::Unchanged
+                            }
+                        }
+                        _ => Outcome::Continue,
+                    }
+                }
                 ct_event!(keycode press Insert) => {
                     if let Some(row) = self.table.selected() {
                         self.edit_new(row, ctx)?;
@@ -476,7 +934,11 @@ where
                 }
                 ct_event!(keycode press Delete) => {
                     if let Some(row) = self.table.selected() {
-                        self.remove(row);
+                        if self.soft_delete {
+                            self.toggle_deleted(row);
+                        } else {
+                            self.remove(row);
+                        }
                     }
                     Outcome::Changed
                 }
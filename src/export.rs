@@ -0,0 +1,403 @@
+//!
+//! Export the table outside of a terminal UI.
+//!
+//! [Table::to_ansi](crate::Table::to_ansi) renders the table to a
+//! string with ANSI escape codes. This can be printed to stdout
+//! after the application exits or written to a log.
+//!
+//! ```rust
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::textdata::{Cell, Row};
+//! use rat_ftable::{Table, TableState};
+//! use ratatui::layout::Constraint;
+//! use ratatui::style::{Style, Stylize};
+//!
+//! let table = Table::<RowSelection>::new_ratatui(
+//!     [
+//!         Row::new([Cell::from("1"), Cell::from("alpha")]),
+//!         Row::new([Cell::from("2"), Cell::from("beta")]).style(Some(Style::new().red())),
+//!     ],
+//!     [Constraint::Length(3), Constraint::Length(8)],
+//! );
+//! let state = TableState::default();
+//!
+//! let text = table.to_ansi(11, None, &state);
+//! assert_eq!(text.lines().count(), 2);
+//! assert!(text.contains("\x1b[0;31m"));
+//! ```
+//!
+//! With the feature `html` `HtmlExport` turns some TableData
+//! into a minimal HTML table for reports.
+//!
+
+#[cfg(feature = "html")]
+use crate::_private::NonExhaustive;
+#[cfg(feature = "html")]
+use crate::textdata::Row;
+#[cfg(feature = "html")]
+use crate::TableData;
+use ratatui::buffer::Buffer;
+#[cfg(feature = "html")]
+use ratatui::layout::Alignment;
+use ratatui::style::{Color, Modifier, Style};
+#[cfg(feature = "html")]
+use std::cmp::max;
+use std::fmt::Write;
+use unicode_width::UnicodeWidthStr;
+
+/// Convert the buffer to a string with ANSI escape codes.
+///
+/// Every line ends with a reset and a newline.
+/// Trailing blank lines are removed.
+pub fn buffer_to_ansi(buf: &Buffer) -> String {
+    let mut lines = Vec::new();
+    let area = buf.area;
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut style = Style::default();
+        let mut blank = true;
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let Some(cell) = buf.cell((x, y)) else {
+                continue;
+            };
+            let cell_style = cell.style();
+            if cell_style != style {
+                line.push_str(&sgr(cell_style));
+                style = cell_style;
+            }
+            line.push_str(cell.symbol());
+            // wide characters cover the following cells.
+            skip = cell.symbol().width().saturating_sub(1);
+
+            if cell.symbol() != " " || cell_style.bg.is_some_and(|v| v != Color::Reset) {
+                blank = false;
+            }
+        }
+        line.push_str("\x1b[0m");
+        lines.push((line, blank));
+    }
+
+    while lines.last().is_some_and(|(_, blank)| *blank) {
+        lines.pop();
+    }
+
+    let mut text = String::new();
+    for (line, _) in lines {
+        text.push_str(&line);
+        text.push('\n');
+    }
+    text
+}
+
+// Select-graphic-rendition for the style. Starts with a reset.
+fn sgr(style: Style) -> String {
+    let mut codes = vec!["0".to_string()];
+
+    let modifiers = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+    for (m, code) in modifiers {
+        if style.add_modifier.contains(m) {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some(fg) = style.fg {
+        if let Some(code) = color(fg, 30) {
+            codes.push(code);
+        }
+    }
+    if let Some(bg) = style.bg {
+        if let Some(code) = color(bg, 40) {
+            codes.push(code);
+        }
+    }
+
+    let mut s = String::new();
+    _ = write!(s, "\x1b[{}m", codes.join(";"));
+    s
+}
+
+// Color code with base 30 for foreground and 40 for background.
+fn color(color: Color, base: u8) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => format!("{}", base),
+        Color::Red => format!("{}", base + 1),
+        Color::Green => format!("{}", base + 2),
+        Color::Yellow => format!("{}", base + 3),
+        Color::Blue => format!("{}", base + 4),
+        Color::Magenta => format!("{}", base + 5),
+        Color::Cyan => format!("{}", base + 6),
+        Color::Gray => format!("{}", base + 7),
+        Color::DarkGray => format!("{}", base + 60),
+        Color::LightRed => format!("{}", base + 61),
+        Color::LightGreen => format!("{}", base + 62),
+        Color::LightYellow => format!("{}", base + 63),
+        Color::LightBlue => format!("{}", base + 64),
+        Color::LightMagenta => format!("{}", base + 65),
+        Color::LightCyan => format!("{}", base + 66),
+        Color::White => format!("{}", base + 67),
+        Color::Indexed(i) => format!("{};5;{}", base + 8, i),
+        Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+    };
+    Some(code)
+}
+
+/// Export some TableData as a minimal HTML table.
+///
+/// The header cells give the column count and the alignment
+/// of each column. The text of the cells is taken from
+/// [TableData::cell_text].
+///
+/// ```rust
+/// use rat_ftable::export::HtmlExport;
+/// use rat_ftable::textdata::{Cell, Row};
+/// use rat_ftable::{TableContext, TableData};
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Alignment, Rect};
+/// use std::borrow::Cow;
+///
+/// struct Prices;
+///
+/// impl<'a> TableData<'a> for Prices {
+///     fn rows(&self) -> usize {
+///         1
+///     }
+///
+///     fn header(&self) -> Option<Row<'a>> {
+///         Some(Row::new([
+///             Cell::from("Item"),
+///             Cell::from("Price").alignment(Alignment::Right),
+///         ]))
+///     }
+///
+///     fn cell_text(&self, column: usize, _row: usize) -> Option<Cow<'_, str>> {
+///         Some(Cow::Borrowed(if column == 0 { "Tea & Cake" } else { "4.20" }))
+///     }
+///
+///     fn render_cell(&self, _: &TableContext, _: usize, _: usize, _: Rect, _: &mut Buffer) {}
+/// }
+///
+/// let html = HtmlExport::new().export(&Prices);
+/// assert!(html.contains("<td>Tea &amp; Cake</td>"));
+/// assert!(html.contains("<td style=\"text-align:right\">4.20</td>"));
+/// ```
+#[cfg(feature = "html")]
+#[derive(Debug, Clone)]
+pub struct HtmlExport {
+    /// CSS class for the table element.
+    pub class: Option<String>,
+    /// Use TableData::row_style for inline styles of the rows.
+    pub row_styles: bool,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+#[cfg(feature = "html")]
+impl Default for HtmlExport {
+    fn default() -> Self {
+        Self {
+            class: None,
+            row_styles: false,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+#[cfg(feature = "html")]
+impl HtmlExport {
+    /// New exporter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// CSS class for the table element.
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Use TableData::row_style for inline styles of the rows.
+    pub fn row_styles(mut self, row_styles: bool) -> Self {
+        self.row_styles = row_styles;
+        self
+    }
+
+    /// Create the HTML.
+    pub fn export<'a>(&self, data: &dyn TableData<'a>) -> String {
+        let header = data.header();
+        let footer = data.footer();
+        let columns = header
+            .as_ref()
+            .map(|v| v.cells.len())
+            .unwrap_or_default()
+            .max(data.widths().len());
+        let align = (0..columns)
+            .map(|c| {
+                match header
+                    .as_ref()
+                    .and_then(|v| v.cells.get(c))
+                    .and_then(|v| v.content.alignment)
+                {
+                    Some(Alignment::Center) => " style=\"text-align:center\"",
+                    Some(Alignment::Right) => " style=\"text-align:right\"",
+                    _ => "",
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut html = String::new();
+        match &self.class {
+            Some(class) => _ = writeln!(html, "<table class=\"{}\">", escape(class)),
+            None => html.push_str("<table>\n"),
+        }
+
+        if let Some(row) = &header {
+            html.push_str("<thead>\n");
+            html.push_str(&html_row(row, "th", &align));
+            html.push_str("</thead>\n");
+        }
+
+        html.push_str("<tbody>\n");
+        for row in 0..data.rows() {
+            let style = if self.row_styles {
+                data.row_style(row).map(css).unwrap_or_default()
+            } else {
+                String::new()
+            };
+            if style.is_empty() {
+                html.push_str("<tr>");
+            } else {
+                _ = write!(html, "<tr style=\"{}\">", style);
+            }
+            for (c, align) in align.iter().enumerate() {
+                let text = data.cell_text(c, row).unwrap_or_default();
+                _ = write!(html, "<td{}>{}</td>", align, escape(text.as_ref()));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n");
+
+        if let Some(row) = &footer {
+            html.push_str("<tfoot>\n");
+            html.push_str(&html_row(row, "td", &align));
+            html.push_str("</tfoot>\n");
+        }
+
+        html.push_str("</table>\n");
+        html
+    }
+}
+
+// One row of the header or footer.
+#[cfg(feature = "html")]
+fn html_row(row: &Row<'_>, tag: &str, align: &[&str]) -> String {
+    let mut html = String::from("<tr>");
+    let mut col = 0;
+    for cell in &row.cells {
+        let text = cell
+            .content
+            .lines
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let align = align.get(col).copied().unwrap_or_default();
+        if cell.span > 1 {
+            _ = write!(
+                html,
+                "<{} colspan=\"{}\"{}>{}</{}>",
+                tag,
+                cell.span,
+                align,
+                escape(&text),
+                tag
+            );
+        } else {
+            _ = write!(html, "<{}{}>{}</{}>", tag, align, escape(&text), tag);
+        }
+        col += max(1, cell.span);
+    }
+    html.push_str("</tr>\n");
+    html
+}
+
+// Inline CSS for the style.
+#[cfg(feature = "html")]
+fn css(style: Style) -> String {
+    let mut css = Vec::new();
+    if let Some(fg) = style.fg.and_then(css_color) {
+        css.push(format!("color:{}", fg));
+    }
+    if let Some(bg) = style.bg.and_then(css_color) {
+        css.push(format!("background-color:{}", bg));
+    }
+    if style.add_modifier.contains(Modifier::BOLD) {
+        css.push("font-weight:bold".to_string());
+    }
+    if style.add_modifier.contains(Modifier::ITALIC) {
+        css.push("font-style:italic".to_string());
+    }
+    if style.add_modifier.contains(Modifier::UNDERLINED) {
+        css.push("text-decoration:underline".to_string());
+    } else if style.add_modifier.contains(Modifier::CROSSED_OUT) {
+        css.push("text-decoration:line-through".to_string());
+    }
+    css.join(";")
+}
+
+// CSS color. Indexed colors beyond the basic 16 are ignored.
+#[cfg(feature = "html")]
+fn css_color(color: Color) -> Option<String> {
+    let c = match color {
+        Color::Reset => return None,
+        Color::Black | Color::Indexed(0) => "black",
+        Color::Red | Color::Indexed(1) => "maroon",
+        Color::Green | Color::Indexed(2) => "green",
+        Color::Yellow | Color::Indexed(3) => "olive",
+        Color::Blue | Color::Indexed(4) => "navy",
+        Color::Magenta | Color::Indexed(5) => "purple",
+        Color::Cyan | Color::Indexed(6) => "teal",
+        Color::Gray | Color::Indexed(7) => "silver",
+        Color::DarkGray | Color::Indexed(8) => "gray",
+        Color::LightRed | Color::Indexed(9) => "red",
+        Color::LightGreen | Color::Indexed(10) => "lime",
+        Color::LightYellow | Color::Indexed(11) => "yellow",
+        Color::LightBlue | Color::Indexed(12) => "blue",
+        Color::LightMagenta | Color::Indexed(13) => "fuchsia",
+        Color::LightCyan | Color::Indexed(14) => "aqua",
+        Color::White | Color::Indexed(15) => "white",
+        Color::Indexed(_) => return None,
+        Color::Rgb(r, g, b) => return Some(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+    };
+    Some(c.to_string())
+}
+
+// Escape text for HTML.
+#[cfg(feature = "html")]
+fn escape(text: &str) -> String {
+    let mut s = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => s.push_str("&amp;"),
+            '<' => s.push_str("&lt;"),
+            '>' => s.push_str("&gt;"),
+            '"' => s.push_str("&quot;"),
+            c => s.push(c),
+        }
+    }
+    s
+}
@@ -0,0 +1,315 @@
+//!
+//! Filtered view of some TableData.
+//!
+//! The table doesn't filter by itself. [FilterView] wraps the
+//! actual data and shows only a subset of the rows.
+//!
+//! [QuickFilter] uses [TableData::cell_text] to collect the
+//! distinct values of a column. Each call to [QuickFilter::cycle]
+//! filters by the next value, and after the last one shows all
+//! rows again. E.g. on a "level" column this goes
+//! ERROR → WARN → INFO → all.
+//!
+//! ```rust
+//! use std::borrow::Cow;
+//! use ratatui::buffer::Buffer;
+//! use ratatui::layout::Rect;
+//! use rat_ftable::filter::QuickFilter;
+//! use rat_ftable::{TableContext, TableData};
+//!
+//! struct Log<'a>(&'a [(&'a str, &'a str)]);
+//!
+//! impl<'a> TableData<'a> for Log<'a> {
+//!     fn rows(&self) -> usize {
+//!         self.0.len()
+//!     }
+//!
+//!     fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+//!         let (level, msg) = self.0[row];
+//!         Some(Cow::Borrowed(if column == 0 { level } else { msg }))
+//!     }
+//!
+//!     fn render_cell(&self, _: &TableContext, _: usize, _: usize, _: Rect, _: &mut Buffer) {}
+//! }
+//!
+//! let records = [("ERROR", "a"), ("INFO", "b"), ("ERROR", "c")];
+//! let data = Log(&records);
+//! let mut filter = QuickFilter::new();
+//!
+//! filter.cycle(&data, 0);
+//! assert_eq!(filter.value(), Some("ERROR"));
+//! assert_eq!(filter.view(&data).rows(), 2);
+//!
+//! filter.cycle(&data, 0);
+//! assert_eq!(filter.value(), Some("INFO"));
+//!
+//! filter.cycle(&data, 0);
+//! assert!(!filter.is_active());
+//! assert_eq!(filter.view(&data).rows(), 3);
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::textdata::Row;
+use crate::{Badge, Decoration, FormattedRow, TableContext, TableData};
+#[cfg(feature = "crossterm")]
+use rat_event::{ct_event, Outcome};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Shows a subset of the rows of some TableData.
+///
+/// All row indices are translated to the row of the
+/// underlying data. Without a row list all rows are shown.
+pub struct FilterView<'b, T: ?Sized> {
+    data: &'b T,
+    rows: Option<&'b [usize]>,
+}
+
+impl<'b, T: ?Sized> FilterView<'b, T> {
+    /// New view. None shows all rows.
+    pub fn new(data: &'b T, rows: Option<&'b [usize]>) -> Self {
+        Self { data, rows }
+    }
+
+    /// Row of the underlying data.
+    ///
+    /// None if the row is not part of the view.
+    pub fn data_row(&self, row: usize) -> Option<usize> {
+        match self.rows {
+            Some(rows) => rows.get(row).copied(),
+            None => Some(row),
+        }
+    }
+}
+
+impl<'a, 'b, T> TableData<'a> for FilterView<'b, T>
+where
+    T: TableData<'a> + ?Sized,
+{
+    fn rows(&self) -> usize {
+        match self.rows {
+            Some(rows) => rows.len(),
+            None => self.data.rows(),
+        }
+    }
+
+    fn header(&self) -> Option<Row<'a>> {
+        self.data.header()
+    }
+
+    fn footer(&self) -> Option<Row<'a>> {
+        self.data.footer()
+    }
+
+    fn row_height(&self, row: usize) -> u16 {
+        self.data_row(row).map_or(1, |v| self.data.row_height(v))
+    }
+
+    fn row_style(&self, row: usize) -> Option<Style> {
+        self.data.row_style(self.data_row(row)?)
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        self.data.widths()
+    }
+
+    fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        self.data.cell_text(column, self.data_row(row)?)
+    }
+
+    fn row_summary_style(&self, row: usize) -> Option<Style> {
+        self.data.row_summary_style(self.data_row(row)?)
+    }
+
+    fn version(&self) -> Option<u64> {
+        self.data.version()
+    }
+
+    fn row_progress(&self, row: usize) -> Option<f32> {
+        self.data.row_progress(self.data_row(row)?)
+    }
+
+    fn cell_badge(&self, column: usize, row: usize) -> Option<Badge> {
+        self.data.cell_badge(column, self.data_row(row)?)
+    }
+
+    fn render_detail(&self, row: usize, area: Rect, buf: &mut Buffer) {
+        if let Some(row) = self.data_row(row) {
+            self.data.render_detail(row, area, buf)
+        }
+    }
+
+    fn format_rows(&self, rows: Range<usize>) -> Vec<FormattedRow<'a>> {
+        match self.rows {
+            Some(_) => {
+                // the data rows are not contiguous, format them one by one.
+                let mut formatted = Vec::new();
+                for row in rows {
+                    let Some(data_row) = self.data_row(row) else {
+                        break;
+                    };
+                    for mut v in self.data.format_rows(data_row..data_row + 1) {
+                        v.row = row;
+                        formatted.push(v);
+                    }
+                }
+                formatted
+            }
+            None => self.data.format_rows(rows),
+        }
+    }
+
+    fn render_cell(
+        &self,
+        ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if let Some(row) = self.data_row(row) {
+            self.data.render_cell(ctx, column, row, area, buf)
+        }
+    }
+}
+
+/// Cycles the distinct values of a column as filter.
+#[derive(Debug, Clone)]
+pub struct QuickFilter {
+    /// Filtered column.
+    pub column: Option<usize>,
+    /// Distinct values of the column in order of appearance.
+    pub values: Vec<String>,
+    /// Index into values of the current filter.
+    pub current: Option<usize>,
+    /// Rows matching the current filter.
+    pub rows: Vec<usize>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for QuickFilter {
+    fn default() -> Self {
+        Self {
+            column: None,
+            values: Default::default(),
+            current: None,
+            rows: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl QuickFilter {
+    /// New filter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Is some filter active.
+    pub fn is_active(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Current filter value.
+    pub fn value(&self) -> Option<&str> {
+        self.current.map(|v| self.values[v].as_str())
+    }
+
+    /// Remove the filter.
+    pub fn clear(&mut self) {
+        self.column = None;
+        self.values.clear();
+        self.current = None;
+        self.rows.clear();
+    }
+
+    /// Filter by the next distinct value of the column.
+    /// After the last value the filter is removed.
+    ///
+    /// Switching to another column starts with its first value.
+    /// This needs the unfiltered data.
+    ///
+    /// Returns true if the filter is active.
+    pub fn cycle<'a, T>(&mut self, data: &T, column: usize) -> bool
+    where
+        T: TableData<'a> + ?Sized,
+    {
+        if self.column != Some(column) {
+            self.column = Some(column);
+            self.values.clear();
+            for row in 0..data.rows() {
+                if let Some(text) = data.cell_text(column, row) {
+                    if !self.values.iter().any(|v| *v == text) {
+                        self.values.push(text.into_owned());
+                    }
+                }
+            }
+            self.current = None;
+        }
+
+        self.current = match self.current {
+            None if !self.values.is_empty() => Some(0),
+            Some(idx) if idx + 1 < self.values.len() => Some(idx + 1),
+            _ => None,
+        };
+
+        self.rows.clear();
+        if let Some(value) = self.value() {
+            let rows = (0..data.rows())
+                .filter(|row| data.cell_text(column, *row).as_deref() == Some(value))
+                .collect();
+            self.rows = rows;
+        } else {
+            self.column = None;
+            self.values.clear();
+        }
+
+        self.is_active()
+    }
+
+    /// Filtered view of the data.
+    pub fn view<'b, T: ?Sized>(&'b self, data: &'b T) -> FilterView<'b, T> {
+        if self.is_active() {
+            FilterView::new(data, Some(&self.rows))
+        } else {
+            FilterView::new(data, None)
+        }
+    }
+
+    /// Header decoration for the filtered column.
+    /// Use with [Table::header_decorations](crate::Table::header_decorations).
+    pub fn decoration(&self) -> Option<(usize, Decoration)> {
+        match (self.column, self.current) {
+            (Some(column), Some(_)) => Some((column, Decoration::Filtered)),
+            _ => None,
+        }
+    }
+
+    /// Key action for the quick filter.
+    ///
+    /// Alt-F cycles the filter for the given column,
+    /// which is usually the selected column.
+    #[cfg(feature = "crossterm")]
+    pub fn handle<'a, T>(
+        &mut self,
+        event: &crossterm::event::Event,
+        data: &T,
+        column: usize,
+    ) -> Outcome
+    where
+        T: TableData<'a> + ?Sized,
+    {
+        match event {
+            ct_event!(key press ALT-'f') => {
+                self.cycle(data, column);
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//!
+//! Focus handling for several tables.
+//!
+//! [TablesFocus] collects the focus-flags of a group of tables,
+//! for example the panes of a miller-column browser. It can be used
+//! as a rat-focus container on its own or be added to a larger
+//! focus-tree, and offers next/prev-table commands that wrap around.
+//!
+//! ```rust
+//! use rat_focus::HasFocus;
+//! use rat_ftable::focus::TablesFocus;
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::TableState;
+//!
+//! let parent = TableState::<RowSelection>::named("parent");
+//! let current = TableState::<RowSelection>::named("current");
+//! let preview = TableState::<RowSelection>::named("preview");
+//!
+//! let mut panes = TablesFocus::new();
+//! panes.set_tables(&[&parent, &current, &preview]);
+//! let focus = panes.build(None);
+//!
+//! panes.focus_table(&focus, 1);
+//! assert_eq!(panes.focused_index(), Some(1));
+//! panes.next_table(&focus);
+//! assert!(preview.is_focused());
+//! panes.next_table(&focus);
+//! assert_eq!(panes.focused_index(), Some(0));
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use rat_focus::{
+    ContainerFlag, Focus, FocusBuilder, FocusContainer, FocusFlag, HasFocus, Navigation,
+};
+use ratatui::layout::Rect;
+
+/// Focus container over several tables.
+///
+/// The tables are remembered by their focus-flag and area.
+/// Call [set_tables](TablesFocus::set_tables) after rendering,
+/// so the areas for mouse-focus are current.
+#[derive(Debug, Clone)]
+pub struct TablesFocus {
+    /// Container flag.
+    pub container: ContainerFlag,
+    /// Focus flags of the tables, in navigation order.
+    pub flags: Vec<FocusFlag>,
+    /// Areas of the tables.
+    pub areas: Vec<Rect>,
+    /// Union of all table areas.
+    pub area: Rect,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for TablesFocus {
+    fn default() -> Self {
+        Self {
+            container: Default::default(),
+            flags: Default::default(),
+            areas: Default::default(),
+            area: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl TablesFocus {
+    /// New container.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New container with a name for the container flag.
+    pub fn named(name: &str) -> Self {
+        Self {
+            container: ContainerFlag::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// Set the tables in navigation order.
+    pub fn set_tables(&mut self, tables: &[&dyn HasFocus]) {
+        self.flags = tables.iter().map(|v| v.focus()).collect();
+        self.areas = tables.iter().map(|v| v.area()).collect();
+        self.area = self
+            .areas
+            .iter()
+            .fold(Rect::default(), |acc, v| acc.union(*v));
+    }
+
+    /// Build a Focus with only these tables.
+    ///
+    /// Pass the previous Focus to clear the flags of tables
+    /// that are no longer part of it.
+    pub fn build(&self, old: Option<Focus>) -> Focus {
+        let mut builder = FocusBuilder::new(old);
+        builder.container(self);
+        builder.build()
+    }
+
+    /// Number of tables.
+    pub fn len(&self) -> usize {
+        self.flags.len()
+    }
+
+    /// No tables.
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+
+    /// Index of the focused table.
+    pub fn focused_index(&self) -> Option<usize> {
+        self.flags.iter().position(|v| v.get())
+    }
+
+    /// Focus the table with the given index.
+    ///
+    /// Returns false if the index is out of bounds.
+    pub fn focus_table(&self, focus: &Focus, idx: usize) -> bool {
+        if let Some(flag) = self.flags.get(idx) {
+            focus.focus_flag(flag.clone());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Focus the next table. Wraps around at the end.
+    /// If no table is focused, focuses the first one.
+    ///
+    /// Returns false if there are no tables.
+    pub fn next_table(&self, focus: &Focus) -> bool {
+        if self.flags.is_empty() {
+            return false;
+        }
+        let idx = match self.focused_index() {
+            Some(idx) => (idx + 1) % self.flags.len(),
+            None => 0,
+        };
+        self.focus_table(focus, idx)
+    }
+
+    /// Focus the previous table. Wraps around at the start.
+    /// If no table is focused, focuses the last one.
+    ///
+    /// Returns false if there are no tables.
+    pub fn prev_table(&self, focus: &Focus) -> bool {
+        if self.flags.is_empty() {
+            return false;
+        }
+        let idx = match self.focused_index() {
+            Some(0) | None => self.flags.len() - 1,
+            Some(idx) => idx - 1,
+        };
+        self.focus_table(focus, idx)
+    }
+}
+
+impl FocusContainer for TablesFocus {
+    fn build(&self, builder: &mut FocusBuilder) {
+        for (flag, area) in self.flags.iter().zip(self.areas.iter()) {
+            builder.add_widget(flag.clone(), *area, 0, Navigation::Regular);
+        }
+    }
+
+    fn container(&self) -> Option<ContainerFlag> {
+        Some(self.container.clone())
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+}
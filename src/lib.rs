@@ -1,18 +1,37 @@
 #![doc = include_str!("../readme.md")]
 
 mod cellselection;
+pub mod chooser;
+pub mod command;
+#[cfg(feature = "compat")]
+pub mod compat;
 pub mod edit;
+pub mod export;
+pub mod filter;
+pub mod focus;
+#[cfg(feature = "log-data")]
+pub mod logdata;
+pub mod matcher;
 mod noselection;
+pub mod position;
+pub mod profile;
 mod rowselection;
 mod rowsetselection;
+pub mod shared;
+pub mod sync;
 mod table;
 pub mod textdata;
 mod util;
+pub mod version;
+pub mod widths;
 
 use crate::textdata::Row;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Rect};
 use ratatui::style::Style;
+use ratatui::text::Text;
+use std::borrow::Cow;
+use std::ops::Range;
 
 /// Render-context for rendering a table-cell.
 #[derive(Debug)]
@@ -33,6 +52,10 @@ pub struct TableContext {
     pub row_style: Option<Style>,
     /// Selection style if any.
     pub select_style: Option<Style>,
+    /// Base style, row style and selection style already
+    /// composed. The table calculates this once per row and
+    /// selection state, not for every cell.
+    pub composed_style: Style,
 
     /// Spacing after the cell. It's guaranteed that this
     /// is writeable in the buffer given to render_cell.
@@ -40,10 +63,147 @@ pub struct TableContext {
     /// Total area for the current row.
     pub row_area: Rect,
 
+    /// Byte-ranges of search matches in the cell-text.
+    /// See [Table::highlight].
+    ///
+    /// The table patches the highlight_style onto the rendered
+    /// cell, render_cell() needs to do nothing about it.
+    pub highlight: Vec<Range<usize>>,
+    /// Style for the highlighted ranges.
+    pub highlight_style: Option<Style>,
+    /// Order for composing the styles.
+    /// See [Table::patch_order].
+    pub patch_order: PatchOrder,
+
     /// Construct with `..Default::default()`
     pub non_exhaustive: NonExhaustive,
 }
 
+impl TableContext {
+    /// Composes the style for a cell.
+    ///
+    /// The base style comes first, the rest follows the
+    /// [patch_order](TableContext::patch_order). By default this
+    /// is row style, the given cell style and the selection style
+    /// last. This way a cell style never hides the selection.
+    pub fn cell_style(&self, style: Option<Style>) -> Style {
+        let Some(style) = style else {
+            return self.composed_style;
+        };
+        let order = match self.patch_order {
+            PatchOrder::SelectLast => [self.row_style, Some(style), self.select_style],
+            PatchOrder::CellLast => [self.row_style, self.select_style, Some(style)],
+            PatchOrder::RowLast => [Some(style), self.select_style, self.row_style],
+        };
+        order
+            .into_iter()
+            .flatten()
+            .fold(self.style, |result, style| result.patch(style))
+    }
+
+    /// Sets the composed [cell_style](TableContext::cell_style)
+    /// for the area.
+    ///
+    /// Use this in render_cell instead of setting the cell style
+    /// directly.
+    pub fn apply_cell_style(&self, buf: &mut Buffer, area: Rect, style: Option<Style>) {
+        buf.set_style(area, self.cell_style(style));
+    }
+}
+
+/// Pre-formatted cells of one row.
+///
+/// See [TableData::format_rows].
+#[derive(Debug, Clone)]
+pub struct FormattedRow<'a> {
+    /// Row index.
+    pub row: usize,
+    /// Cell content by column.
+    /// Missing columns are rendered with render_cell.
+    pub cells: Vec<Text<'a>>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl<'a> FormattedRow<'a> {
+    /// New row.
+    pub fn new(row: usize, cells: Vec<Text<'a>>) -> Self {
+        Self {
+            row,
+            cells,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+/// Kind of data a Table accepts.
+///
+/// [LocalData] is the default and takes any data. With [SendData]
+/// the data must be Send, and the Table becomes Send too. This way
+/// the table can be prepared on a worker thread and rendered on the
+/// UI thread. See [Table::new_send](crate::Table::new_send).
+pub trait DataKind<'a> {
+    /// Boxed TableData.
+    type Data: TableData<'a> + ?Sized + 'a;
+    /// Boxed TableDataIter.
+    type Iter: TableDataIter<'a> + ?Sized + 'a;
+    /// Boxed render function, e.g. for the watermark.
+    type Render: Fn(Rect, &mut Buffer) + ?Sized + 'a;
+
+    /// Drop the marker.
+    fn local_data(data: Box<Self::Data>) -> Box<dyn TableData<'a> + 'a>;
+
+    /// Drop the marker.
+    fn local_data_ref<'b>(data: &'b Self::Data) -> &'b (dyn TableData<'a> + 'a);
+
+    /// Drop the marker.
+    fn local_iter(iter: Box<Self::Iter>) -> Box<dyn TableDataIter<'a> + 'a>;
+}
+
+/// Table data without further bounds. This is the default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LocalData;
+
+/// Table data that is Send.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SendData;
+
+impl<'a> DataKind<'a> for LocalData {
+    type Data = dyn TableData<'a> + 'a;
+    type Iter = dyn TableDataIter<'a> + 'a;
+    type Render = dyn Fn(Rect, &mut Buffer) + 'a;
+
+    fn local_data(data: Box<Self::Data>) -> Box<dyn TableData<'a> + 'a> {
+        data
+    }
+
+    fn local_data_ref<'b>(data: &'b Self::Data) -> &'b (dyn TableData<'a> + 'a) {
+        data
+    }
+
+    fn local_iter(iter: Box<Self::Iter>) -> Box<dyn TableDataIter<'a> + 'a> {
+        iter
+    }
+}
+
+impl<'a> DataKind<'a> for SendData {
+    type Data = dyn TableData<'a> + Send + 'a;
+    type Iter = dyn TableDataIter<'a> + Send + 'a;
+    type Render = dyn Fn(Rect, &mut Buffer) + Send + 'a;
+
+    fn local_data(data: Box<Self::Data>) -> Box<dyn TableData<'a> + 'a> {
+        data
+    }
+
+    fn local_data_ref<'b>(data: &'b Self::Data) -> &'b (dyn TableData<'a> + 'a) {
+        data
+    }
+
+    fn local_iter(iter: Box<Self::Iter>) -> Box<dyn TableDataIter<'a> + 'a> {
+        iter
+    }
+}
+
 ///
 /// Trait for accessing the table-data by the Table.
 ///
@@ -82,8 +242,71 @@ pub trait TableData<'a> {
         Vec::default()
     }
 
+    /// Plain text of the cell given by column/row.
+    ///
+    /// This is not used for rendering, but for searching/filtering
+    /// the data. See [matcher](crate::matcher).
+    #[allow(unused_variables)]
+    fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    /// Condensed style of a row for the minimap.
+    /// See [Table::minimap].
+    #[allow(unused_variables)]
+    fn row_summary_style(&self, row: usize) -> Option<Style> {
+        None
+    }
+
+    /// Generation of the data.
+    /// See [DataVersion](crate::version::DataVersion).
+    fn version(&self) -> Option<u64> {
+        None
+    }
+
+    /// Progress of a row as fraction 0.0..=1.0.
+    /// The first part of the row is shaded with the progress style.
+    /// See [Table::progress_style].
+    #[allow(unused_variables)]
+    fn row_progress(&self, row: usize) -> Option<f32> {
+        None
+    }
+
+    /// Annotation for a cell, shown as a small glyph in the
+    /// top-right corner of the cell. Clicking it can be detected
+    /// with the [BadgeClick](crate::event::BadgeClick) handler.
+    #[allow(unused_variables)]
+    fn cell_badge(&self, column: usize, row: usize) -> Option<Badge> {
+        None
+    }
+
+    /// Render the details of the selected row.
+    /// See [Table::detail_pane].
+    #[allow(unused_variables)]
+    fn render_detail(&self, row: usize, area: Rect, buf: &mut Buffer) {}
+
+    /// Pre-format the cells of the given rows.
+    ///
+    /// This is called once per render with the rows that might
+    /// be visible, before any call to render_cell. The formatted
+    /// text is then rendered instead of calling render_cell.
+    /// Rows and columns missing in the result are still rendered
+    /// with render_cell.
+    ///
+    /// If the formatting of the cells is expensive, this can be used
+    /// to do it all at once, for example in parallel with rayon.
+    ///
+    /// Highlighted cells always use render_cell.
+    #[allow(unused_variables)]
+    fn format_rows(&self, rows: Range<usize>) -> Vec<FormattedRow<'a>> {
+        Vec::new()
+    }
+
     /// Render the cell given by column/row.
     /// * ctx - a lot of context data.
+    ///
+    /// Anything written outside the area and ctx.space_area
+    /// is clipped.
     fn render_cell(
         &self,
         ctx: &TableContext,
@@ -119,6 +342,34 @@ impl<'a> TableData<'a> for Box<dyn TableData<'a> + 'a> {
         (**self).widths()
     }
 
+    fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        (**self).cell_text(column, row)
+    }
+
+    fn row_summary_style(&self, row: usize) -> Option<Style> {
+        (**self).row_summary_style(row)
+    }
+
+    fn version(&self) -> Option<u64> {
+        (**self).version()
+    }
+
+    fn row_progress(&self, row: usize) -> Option<f32> {
+        (**self).row_progress(row)
+    }
+
+    fn cell_badge(&self, column: usize, row: usize) -> Option<Badge> {
+        (**self).cell_badge(column, row)
+    }
+
+    fn render_detail(&self, row: usize, area: Rect, buf: &mut Buffer) {
+        (**self).render_detail(row, area, buf)
+    }
+
+    fn format_rows(&self, rows: Range<usize>) -> Vec<FormattedRow<'a>> {
+        (**self).format_rows(rows)
+    }
+
     fn render_cell(
         &self,
         ctx: &TableContext,
@@ -151,6 +402,15 @@ pub trait TableDataIter<'a> {
     /// See [Table::no_row_count]
     fn rows(&self) -> Option<usize>;
 
+    /// Generation of the data.
+    /// See [TableData::version].
+    ///
+    /// If rows() returns None, the rows are counted once and the
+    /// count is kept as long as the version stays the same.
+    fn version(&self) -> Option<u64> {
+        None
+    }
+
     /// Header can be obtained from here.
     /// Alternative to setting on Table.
     fn header(&self) -> Option<Row<'a>> {
@@ -167,6 +427,20 @@ pub trait TableDataIter<'a> {
     /// nth(0) == next()
     fn nth(&mut self, n: usize) -> bool;
 
+    /// Skips to the nth item counted from the back, returns true if
+    /// such an item exists. nth_back(0) == next_back()
+    ///
+    /// This is optional. If rows() returns a count, the table uses
+    /// this to find the last page instead of skipping forward
+    /// through all the data. It's only called after the table is
+    /// done with iterating forward.
+    ///
+    /// The default returns false, which means not supported.
+    #[allow(unused_variables)]
+    fn nth_back(&mut self, n: usize) -> bool {
+        false
+    }
+
     /// Row height for the current item.
     fn row_height(&self) -> u16 {
         1
@@ -177,11 +451,33 @@ pub trait TableDataIter<'a> {
         None
     }
 
+    /// Progress of the current line as fraction 0.0..=1.0.
+    /// See [TableData::row_progress].
+    fn row_progress(&self) -> Option<f32> {
+        None
+    }
+
     /// Column constraints.
     fn widths(&self) -> Vec<Constraint> {
         Vec::default()
     }
 
+    /// Plain text of the cell for the current line.
+    ///
+    /// This is not used for rendering, but for searching/filtering
+    /// the data. See [matcher](crate::matcher).
+    #[allow(unused_variables)]
+    fn cell_text(&self, column: usize) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    /// Annotation for the cell of the current line.
+    /// See [TableData::cell_badge].
+    #[allow(unused_variables)]
+    fn cell_badge(&self, column: usize) -> Option<Badge> {
+        None
+    }
+
     /// Render the cell for the current line.
     /// * ctx - a lot of context data.
     fn render_cell(&self, ctx: &TableContext, column: usize, area: Rect, buf: &mut Buffer);
@@ -200,29 +496,67 @@ pub trait TableSelection {
 
     /// Selection lead, or the sole selected index.
     fn lead_selection(&self) -> Option<(usize, usize)>;
+
+    /// Move the selection lead to the given cell.
+    ///
+    /// Selection models that don't support this return false.
+    #[allow(unused_variables)]
+    fn move_lead(&mut self, column: usize, row: usize) -> bool {
+        false
+    }
+
+    /// Selection model can select rows.
+    /// Used by [Table::validate].
+    fn selects_rows() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+
+    /// Selection model can select columns and cells.
+    /// Used by [Table::validate].
+    fn selects_cells() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
 }
 
 use crate::_private::NonExhaustive;
 
-pub use table::{handle_doubleclick_events, Table, TableState, TableStyle};
+#[cfg(feature = "crossterm")]
+pub use table::handle_doubleclick_events;
+pub use table::{
+    Badge, CellError, ConfigError, CountPrefix, Decoration, DetailPane, FocusGained, JumpList,
+    PageScroll, PatchOrder, RenderContinuation, RenderDiagnostics, RowNumbers, Table, TableLayout,
+    TableSchema, TableState, TableStyle, Viewport, WheelScroll, WidthSource,
+};
 
 /// Different selection models for Table.
 pub mod selection {
     pub use crate::cellselection::CellSelection;
     pub mod cellselection {
-        pub use crate::cellselection::{handle_events, handle_mouse_events};
+        #[cfg(feature = "crossterm")]
+        pub use crate::cellselection::{handle_events, handle_mouse_events, handle_vim_events};
     }
     pub use crate::noselection::NoSelection;
     pub mod noselection {
-        pub use crate::noselection::{handle_events, handle_mouse_events};
+        #[cfg(feature = "crossterm")]
+        pub use crate::noselection::{
+            handle_activate_events, handle_events, handle_mouse_events, handle_vim_events,
+        };
     }
     pub use crate::rowselection::RowSelection;
     pub mod rowselection {
-        pub use crate::rowselection::{handle_events, handle_mouse_events};
+        #[cfg(feature = "crossterm")]
+        pub use crate::rowselection::{handle_events, handle_mouse_events, handle_vim_events};
     }
     pub use crate::rowsetselection::RowSetSelection;
     pub mod rowsetselection {
-        pub use crate::rowsetselection::{handle_events, handle_mouse_events};
+        #[cfg(feature = "crossterm")]
+        pub use crate::rowsetselection::{handle_events, handle_mouse_events, handle_vim_events};
     }
 }
 
@@ -230,6 +564,188 @@ pub mod selection {
 pub mod event {
     pub use rat_event::*;
 
+    /// Qualifier for vim-style keybindings.
+    ///
+    /// Adds hjkl motions and numeric count prefixes ("17j")
+    /// to the [Regular](rat_event::Regular) keybindings.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Vim;
+
+    /// Qualifier for row activation without a selection model.
+    ///
+    /// Tracks the row under the mouse and reports clicks
+    /// and activations. Otherwise works like
+    /// [Regular](rat_event::Regular).
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Activate;
+
+    /// Result type for the [Activate] keybindings.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum ActivateOutcome {
+        /// The given event has not been used at all.
+        Continue,
+        /// The event has been recognized, but the result was nil.
+        /// Further processing for this event may stop.
+        Unchanged,
+        /// The event has been recognized and there is some change
+        /// due to it.
+        /// Further processing for this event may stop.
+        /// Rendering the ui is advised.
+        Changed,
+        /// A row has been clicked.
+        Clicked(usize),
+        /// A row has been activated with a double-click, or with
+        /// Enter while hovering the row.
+        Activated(usize),
+    }
+
+    impl From<ActivateOutcome> for Outcome {
+        fn from(value: ActivateOutcome) -> Self {
+            match value {
+                ActivateOutcome::Continue => Outcome::Continue,
+                ActivateOutcome::Unchanged => Outcome::Unchanged,
+                ActivateOutcome::Changed => Outcome::Changed,
+                ActivateOutcome::Clicked(_) => Outcome::Changed,
+                ActivateOutcome::Activated(_) => Outcome::Changed,
+            }
+        }
+    }
+
+    impl From<Outcome> for ActivateOutcome {
+        fn from(value: Outcome) -> Self {
+            match value {
+                Outcome::Continue => ActivateOutcome::Continue,
+                Outcome::Unchanged => ActivateOutcome::Unchanged,
+                Outcome::Changed => ActivateOutcome::Changed,
+            }
+        }
+    }
+
+    impl ConsumedEvent for ActivateOutcome {
+        fn is_consumed(&self) -> bool {
+            !matches!(self, ActivateOutcome::Continue)
+        }
+    }
+
+    /// Qualifier for clicks on cell badges.
+    ///
+    /// See [TableData::cell_badge](crate::TableData::cell_badge).
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct BadgeClick;
+
+    /// Result type for the [BadgeClick] handler.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum BadgeOutcome {
+        /// The given event has not been used at all.
+        Continue,
+        /// The event has been recognized, but the result was nil.
+        /// Further processing for this event may stop.
+        Unchanged,
+        /// The event has been recognized and there is some change
+        /// due to it.
+        /// Further processing for this event may stop.
+        /// Rendering the ui is advised.
+        Changed,
+        /// The badge of a cell has been clicked. Contains (column, row)
+        Clicked(usize, usize),
+    }
+
+    impl From<BadgeOutcome> for Outcome {
+        fn from(value: BadgeOutcome) -> Self {
+            match value {
+                BadgeOutcome::Continue => Outcome::Continue,
+                BadgeOutcome::Unchanged => Outcome::Unchanged,
+                BadgeOutcome::Changed => Outcome::Changed,
+                BadgeOutcome::Clicked(_, _) => Outcome::Changed,
+            }
+        }
+    }
+
+    impl From<Outcome> for BadgeOutcome {
+        fn from(value: Outcome) -> Self {
+            match value {
+                Outcome::Continue => BadgeOutcome::Continue,
+                Outcome::Unchanged => BadgeOutcome::Unchanged,
+                Outcome::Changed => BadgeOutcome::Changed,
+            }
+        }
+    }
+
+    impl ConsumedEvent for BadgeOutcome {
+        fn is_consumed(&self) -> bool {
+            !matches!(self, BadgeOutcome::Continue)
+        }
+    }
+
+    /// Qualifier for dragging one header onto another to
+    /// group the columns.
+    ///
+    /// See [TableState::group_columns](crate::TableState::group_columns).
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct ColumnGroup;
+
+    /// Result type for the [ColumnGroup] handler.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum ColumnGroupOutcome {
+        /// The given event has not been used at all.
+        Continue,
+        /// The event has been recognized, but the result was nil.
+        /// Further processing for this event may stop.
+        Unchanged,
+        /// The event has been recognized and there is some change
+        /// due to it.
+        /// Further processing for this event may stop.
+        /// Rendering the ui is advised.
+        Changed,
+        /// Columns have been grouped. Contains the resulting
+        /// group as (first, last) column.
+        Grouped(usize, usize),
+    }
+
+    impl From<ColumnGroupOutcome> for Outcome {
+        fn from(value: ColumnGroupOutcome) -> Self {
+            match value {
+                ColumnGroupOutcome::Continue => Outcome::Continue,
+                ColumnGroupOutcome::Unchanged => Outcome::Unchanged,
+                ColumnGroupOutcome::Changed => Outcome::Changed,
+                ColumnGroupOutcome::Grouped(_, _) => Outcome::Changed,
+            }
+        }
+    }
+
+    impl From<Outcome> for ColumnGroupOutcome {
+        fn from(value: Outcome) -> Self {
+            match value {
+                Outcome::Continue => ColumnGroupOutcome::Continue,
+                Outcome::Unchanged => ColumnGroupOutcome::Unchanged,
+                Outcome::Changed => ColumnGroupOutcome::Changed,
+            }
+        }
+    }
+
+    impl ConsumedEvent for ColumnGroupOutcome {
+        fn is_consumed(&self) -> bool {
+            !matches!(self, ColumnGroupOutcome::Continue)
+        }
+    }
+
+    /// Details of a double- or triple-click.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Click {
+        /// Column.
+        pub column: usize,
+        /// Row.
+        pub row: usize,
+        /// Screen position of the click.
+        pub position: (u16, u16),
+        /// Shift was held.
+        pub shift: bool,
+        /// Control was held.
+        pub ctrl: bool,
+        /// Alt was held.
+        pub alt: bool,
+    }
+
     /// Result type for double-click event-handling.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
     pub enum DoubleClickOutcome {
@@ -243,8 +759,11 @@ pub mod event {
         /// Further processing for this event may stop.
         /// Rendering the ui is advised.
         Changed,
-        /// Double click occurred. Contains (column, row)
-        ClickClick(usize, usize),
+        /// Double click occurred.
+        ClickClick(Click),
+        /// Triple click occurred.
+        /// See [TableState::triple_click](crate::TableState::triple_click).
+        ClickClickClick(Click),
     }
 
     impl From<DoubleClickOutcome> for Outcome {
@@ -253,7 +772,8 @@ pub mod event {
                 DoubleClickOutcome::Continue => Outcome::Continue,
                 DoubleClickOutcome::Unchanged => Outcome::Unchanged,
                 DoubleClickOutcome::Changed => Outcome::Changed,
-                DoubleClickOutcome::ClickClick(_, _) => Outcome::Changed,
+                DoubleClickOutcome::ClickClick(_) => Outcome::Changed,
+                DoubleClickOutcome::ClickClickClick(_) => Outcome::Changed,
             }
         }
     }
@@ -304,6 +824,12 @@ pub mod event {
         /// Append an item after last row.
         /// Might want to start the edit too.
         Append,
+        /// Flip the boolean value in a toggle column.
+        /// Contains (column, row).
+        Toggle(usize, usize),
+        /// Step the numeric value in an adjustable column.
+        /// Contains (column, row, delta).
+        ValueDelta(usize, usize, i32),
     }
 
     impl From<Outcome> for EditOutcome {
@@ -330,6 +856,8 @@ pub mod event {
                 EditOutcome::Commit => Outcome::Unchanged,
                 EditOutcome::CommitAndAppend => Outcome::Unchanged,
                 EditOutcome::CommitAndEdit => Outcome::Unchanged,
+                EditOutcome::Toggle(_, _) => Outcome::Unchanged,
+                EditOutcome::ValueDelta(_, _, _) => Outcome::Unchanged,
             }
         }
     }
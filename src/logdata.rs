@@ -0,0 +1,263 @@
+//!
+//! TableData for log records.
+//!
+//! [LogTableData] shows a slice of log records with the columns
+//! time, level, target and message. The rows are styled by
+//! their level.
+//!
+//! ```rust
+//! use log::Level;
+//! use rat_ftable::logdata::{follow_tail, LogEntry, LogTableData};
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::{Table, TableState};
+//!
+//! let records = vec![
+//!     LogEntry::new("12:00:01", Level::Info, "app", "started"),
+//!     LogEntry::new("12:00:02", Level::Warn, "app::db", "slow query"),
+//! ];
+//! let mut state = TableState::<RowSelection>::default();
+//!
+//! follow_tail(&mut state, records.len());
+//! assert_eq!(state.selected(), Some(1));
+//!
+//! let table: Table<RowSelection> = Table::default().data(LogTableData::new(&records));
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::selection::RowSelection;
+use crate::textdata::{Cell, Row};
+use crate::{TableContext, TableData, TableState};
+use log::Level;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Span;
+use ratatui::widgets::Widget;
+use std::borrow::Cow;
+
+/// Access to a log record.
+pub trait LogRecord {
+    /// Formatted timestamp.
+    fn time(&self) -> Cow<'_, str>;
+
+    /// Log level.
+    fn level(&self) -> Level;
+
+    /// Log target.
+    fn target(&self) -> &str;
+
+    /// Message.
+    fn message(&self) -> &str;
+}
+
+/// Simple log record.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// Formatted timestamp.
+    pub time: String,
+    /// Log level.
+    pub level: Level,
+    /// Log target.
+    pub target: String,
+    /// Message.
+    pub message: String,
+}
+
+impl LogEntry {
+    /// New entry.
+    pub fn new(
+        time: impl Into<String>,
+        level: Level,
+        target: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            time: time.into(),
+            level,
+            target: target.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl LogRecord for LogEntry {
+    fn time(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.time)
+    }
+
+    fn level(&self) -> Level {
+        self.level
+    }
+
+    fn target(&self) -> &str {
+        &self.target
+    }
+
+    fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Row styles per log level.
+#[derive(Debug, Clone)]
+pub struct LevelStyles {
+    pub error: Option<Style>,
+    pub warn: Option<Style>,
+    pub info: Option<Style>,
+    pub debug: Option<Style>,
+    pub trace: Option<Style>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for LevelStyles {
+    fn default() -> Self {
+        Self {
+            error: Some(Style::new().red()),
+            warn: Some(Style::new().yellow()),
+            info: None,
+            debug: Some(Style::new().dark_gray()),
+            trace: Some(Style::new().dark_gray()),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl LevelStyles {
+    /// Style for the level.
+    pub fn style(&self, level: Level) -> Option<Style> {
+        match level {
+            Level::Error => self.error,
+            Level::Warn => self.warn,
+            Level::Info => self.info,
+            Level::Debug => self.debug,
+            Level::Trace => self.trace,
+        }
+    }
+}
+
+/// TableData for a slice of log records.
+#[derive(Debug)]
+pub struct LogTableData<'a, R> {
+    records: &'a [R],
+    styles: LevelStyles,
+    time_width: u16,
+    target_width: u16,
+}
+
+impl<'a, R: LogRecord> LogTableData<'a, R> {
+    /// New log data.
+    pub fn new(records: &'a [R]) -> Self {
+        Self {
+            records,
+            styles: Default::default(),
+            time_width: 12,
+            target_width: 16,
+        }
+    }
+
+    /// Row styles per level.
+    pub fn styles(mut self, styles: LevelStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Width of the time column.
+    pub fn time_width(mut self, width: u16) -> Self {
+        self.time_width = width;
+        self
+    }
+
+    /// Width of the target column.
+    pub fn target_width(mut self, width: u16) -> Self {
+        self.target_width = width;
+        self
+    }
+}
+
+impl<'a, R: LogRecord> TableData<'a> for LogTableData<'a, R> {
+    fn rows(&self) -> usize {
+        self.records.len()
+    }
+
+    fn header(&self) -> Option<Row<'a>> {
+        Some(Row::new([
+            Cell::from("Time"),
+            Cell::from("Level"),
+            Cell::from("Target"),
+            Cell::from("Message"),
+        ]))
+    }
+
+    fn row_style(&self, row: usize) -> Option<Style> {
+        self.records
+            .get(row)
+            .and_then(|v| self.styles.style(v.level()))
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::Length(self.time_width),
+            Constraint::Length(5),
+            Constraint::Length(self.target_width),
+            Constraint::Fill(1),
+        ]
+    }
+
+    fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        let record = self.records.get(row)?;
+        match column {
+            0 => Some(record.time()),
+            1 => Some(Cow::Borrowed(record.level().as_str())),
+            2 => Some(Cow::Borrowed(record.target())),
+            3 => Some(Cow::Borrowed(record.message())),
+            _ => None,
+        }
+    }
+
+    fn row_summary_style(&self, row: usize) -> Option<Style> {
+        let record = self.records.get(row)?;
+        match record.level() {
+            Level::Error | Level::Warn => self.styles.style(record.level()),
+            _ => None,
+        }
+    }
+
+    fn render_cell(
+        &self,
+        _ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if let Some(text) = self.cell_text(column, row) {
+            Span::from(text.as_ref()).render(area, buf);
+        }
+    }
+}
+
+/// Keeps the selection at the end of the log.
+///
+/// Call this with the new row count before rendering. If the
+/// last row or nothing was selected, the new last row is
+/// selected and [TableState::set_follow] keeps it in view.
+/// Otherwise only the row count is updated.
+///
+/// Returns true if the selection changed.
+pub fn follow_tail(state: &mut TableState<RowSelection>, rows: usize) -> bool {
+    let at_end = match state.selected() {
+        None => true,
+        Some(row) => row + 1 >= state.rows,
+    };
+    state.rows = rows;
+    if at_end && rows > 0 {
+        if !state.is_following() {
+            state.set_follow(true);
+        }
+        state.select(Some(rows - 1))
+    } else {
+        false
+    }
+}
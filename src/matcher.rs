@@ -0,0 +1,289 @@
+//!
+//! Text matching across the columns of a table.
+//!
+//! The matcher uses [TableData::cell_text]
+//! or any other function that can provide the plain text of a cell.
+//! It returns the matching rows and the byte-ranges of the matches
+//! for each cell. These can be used to highlight the matches
+//! when rendering.
+//!
+//! ```rust
+//! use std::borrow::Cow;
+//! use rat_ftable::matcher::{MatchMode, Matcher};
+//!
+//! let data = [["alpha", "beta"], ["gamma", "delta"]];
+//!
+//! let matcher = Matcher::new("ta")
+//!     .mode(MatchMode::Substring)
+//!     .columns([1]);
+//! let found = matcher.find(data.len(), 2, |c, r| Some(Cow::Borrowed(data[r][c])));
+//!
+//! assert_eq!(found.len(), 2);
+//! assert_eq!(found[0].cells[0].spans, vec![2..4]);
+//! ```
+//!
+
+use crate::TableData;
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// How the pattern is matched against the cell-text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Plain substring search. Finds all non-overlapping occurrences.
+    #[default]
+    Substring,
+    /// All characters of the pattern must occur in order,
+    /// but there may be gaps between them.
+    Fuzzy,
+    /// The pattern is a regular expression.
+    #[cfg(feature = "regex")]
+    Regex,
+}
+
+/// Matches for a single cell.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CellMatch {
+    /// Column.
+    pub column: usize,
+    /// Byte-ranges of the matches in the cell-text.
+    pub spans: Vec<Range<usize>>,
+}
+
+/// Matches for a row.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RowMatch {
+    /// Row.
+    pub row: usize,
+    /// All matching cells of the row.
+    pub cells: Vec<CellMatch>,
+}
+
+/// Search/filter helper.
+///
+/// Matches a pattern against the text of the chosen columns
+/// and returns the matching rows.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    pattern: String,
+    mode: MatchMode,
+    ignore_case: bool,
+    columns: Option<Vec<usize>>,
+    #[cfg(feature = "regex")]
+    regex: Option<regex::Regex>,
+}
+
+impl Matcher {
+    /// New matcher. Defaults to a case-sensitive substring search
+    /// across all columns.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            mode: MatchMode::Substring,
+            ignore_case: false,
+            columns: None,
+            #[cfg(feature = "regex")]
+            regex: None,
+        }
+    }
+
+    /// New matcher with a regular expression.
+    ///
+    /// Fails if the pattern is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: impl Into<String>) -> Result<Self, regex::Error> {
+        let mut matcher = Self::new(pattern);
+        matcher.mode = MatchMode::Regex;
+        matcher.compile_regex()?;
+        Ok(matcher)
+    }
+
+    /// Match mode.
+    ///
+    /// `MatchMode::Regex` compiles the pattern. An invalid
+    /// pattern matches nothing, use `Matcher::regex` to get
+    /// the error.
+    pub fn mode(mut self, mode: MatchMode) -> Self {
+        self.mode = mode;
+        #[cfg(feature = "regex")]
+        let _ = self.compile_regex();
+        self
+    }
+
+    /// Ignore case.
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        #[cfg(feature = "regex")]
+        let _ = self.compile_regex();
+        self
+    }
+
+    /// Restrict the search to these columns.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = usize>) -> Self {
+        self.columns = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// The search pattern.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Empty pattern. Matches nothing.
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_empty()
+    }
+
+    /// Match the pattern against the text.
+    ///
+    /// Returns the byte-ranges of all matches.
+    pub fn match_text(&self, text: &str) -> Vec<Range<usize>> {
+        if self.pattern.is_empty() {
+            return Vec::new();
+        }
+        match self.mode {
+            MatchMode::Substring => self.match_substring(text),
+            MatchMode::Fuzzy => self.match_fuzzy(text),
+            #[cfg(feature = "regex")]
+            MatchMode::Regex => {
+                if let Some(regex) = &self.regex {
+                    regex
+                        .find_iter(text)
+                        .filter(|m| !m.is_empty())
+                        .map(|m| m.range())
+                        .collect()
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    /// Find all matching rows.
+    ///
+    /// * rows: number of rows.
+    /// * columns: number of columns. Used if no explicit columns are set.
+    /// * cell_text: returns the text for (column, row).
+    pub fn find<'t, F>(&self, rows: usize, columns: usize, cell_text: F) -> Vec<RowMatch>
+    where
+        F: Fn(usize, usize) -> Option<Cow<'t, str>>,
+    {
+        let mut result = Vec::new();
+        if self.pattern.is_empty() {
+            return result;
+        }
+
+        let all_columns = (0..columns).collect::<Vec<_>>();
+        let columns = self.columns.as_ref().unwrap_or(&all_columns);
+
+        for row in 0..rows {
+            let mut cells = Vec::new();
+            for column in columns.iter().copied() {
+                if let Some(text) = cell_text(column, row) {
+                    let spans = self.match_text(text.as_ref());
+                    if !spans.is_empty() {
+                        cells.push(CellMatch { column, spans });
+                    }
+                }
+            }
+            if !cells.is_empty() {
+                result.push(RowMatch { row, cells });
+            }
+        }
+
+        result
+    }
+
+    /// Find all matching rows using [TableData::cell_text].
+    ///
+    /// The number of columns is taken from [TableData::widths]
+    /// if no explicit columns are set.
+    pub fn find_in<'a>(&self, data: &dyn TableData<'a>) -> Vec<RowMatch> {
+        self.find(data.rows(), data.widths().len(), |c, r| {
+            data.cell_text(c, r)
+        })
+    }
+
+    #[cfg(feature = "regex")]
+    fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        self.regex = None;
+        if self.mode == MatchMode::Regex {
+            let regex = regex::RegexBuilder::new(&self.pattern)
+                .case_insensitive(self.ignore_case)
+                .build()?;
+            self.regex = Some(regex);
+        }
+        Ok(())
+    }
+
+    fn chars_eq(&self, a: char, b: char) -> bool {
+        if self.ignore_case {
+            a == b || a.to_lowercase().eq(b.to_lowercase())
+        } else {
+            a == b
+        }
+    }
+
+    fn match_substring(&self, text: &str) -> Vec<Range<usize>> {
+        let mut result = Vec::new();
+
+        let mut start = 0;
+        'outer: while start < text.len() {
+            for (pos, _) in text[start..].char_indices() {
+                let begin = start + pos;
+                if let Some(end) = self.match_at(text, begin) {
+                    result.push(begin..end);
+                    start = end;
+                    continue 'outer;
+                }
+            }
+            break;
+        }
+
+        result
+    }
+
+    // Match the pattern at the given position. Returns the end of the match.
+    fn match_at(&self, text: &str, pos: usize) -> Option<usize> {
+        let mut text_chars = text[pos..].char_indices();
+        for pc in self.pattern.chars() {
+            let (_, tc) = text_chars.next()?;
+            if !self.chars_eq(pc, tc) {
+                return None;
+            }
+        }
+        Some(match text_chars.next() {
+            Some((idx, _)) => pos + idx,
+            None => text.len(),
+        })
+    }
+
+    fn match_fuzzy(&self, text: &str) -> Vec<Range<usize>> {
+        let mut result: Vec<Range<usize>> = Vec::new();
+
+        let mut pattern = self.pattern.chars().peekable();
+        for (idx, tc) in text.char_indices() {
+            let Some(pc) = pattern.peek() else {
+                break;
+            };
+            if self.chars_eq(*pc, tc) {
+                pattern.next();
+                let end = idx + tc.len_utf8();
+                // join adjacent ranges.
+                if let Some(last) = result.last_mut() {
+                    if last.end == idx {
+                        last.end = end;
+                        continue;
+                    }
+                }
+                result.push(idx..end);
+            }
+        }
+
+        if pattern.peek().is_some() {
+            Vec::new()
+        } else {
+            result
+        }
+    }
+}
@@ -1,18 +1,32 @@
+use crate::command::{TableCommand, TableCommands};
 use crate::event::Outcome;
+#[cfg(feature = "crossterm")]
+use crate::event::{Activate, ActivateOutcome, Vim};
 use crate::{TableSelection, TableState};
-use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
+#[cfg(feature = "crossterm")]
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Regular};
+#[cfg(feature = "crossterm")]
 use rat_focus::HasFocus;
+#[cfg(feature = "crossterm")]
 use rat_scrolled::event::ScrollOutcome;
+#[cfg(feature = "crossterm")]
 use rat_scrolled::ScrollAreaState;
-use std::cmp::max;
 
 /// Doesn't do any selection for the table.
 ///
 /// But it implements scrolling via mouse and keyboard.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct NoSelection;
 
 impl TableSelection for NoSelection {
+    fn selects_rows() -> bool {
+        false
+    }
+
+    fn selects_cells() -> bool {
+        false
+    }
+
     fn is_selected_row(&self, _row: usize) -> bool {
         false
     }
@@ -30,33 +44,136 @@ impl TableSelection for NoSelection {
     }
 }
 
+impl TableState<NoSelection> {
+    /// Execute a command. This has the same semantics as the
+    /// corresponding keybindings.
+    pub fn execute(&mut self, cmd: TableCommand) -> Outcome {
+        match cmd {
+            TableCommand::MoveFirst => self.scroll_to_row(0).into(),
+            TableCommand::MoveLast => self.scroll_to_row(self.rows.saturating_sub(1)).into(),
+            TableCommand::PageUp => self.scroll_up(self.page_up_len()).into(),
+            TableCommand::PageDown => self.scroll_down(self.page_down_len()).into(),
+            TableCommand::ScrollUp(n) => self.scroll_up(n).into(),
+            TableCommand::ScrollDown(n) => self.scroll_down(n).into(),
+            TableCommand::ScrollLeft(n) => self.scroll_left(n).into(),
+            TableCommand::ScrollRight(n) => self.scroll_right(n).into(),
+            TableCommand::ScrollToRow(row) => self.scroll_to_row(row).into(),
+            TableCommand::ScrollToColumn(col) => self.scroll_to_col(col).into(),
+            TableCommand::ScrollLeftmost => self.scroll_to_x(0).into(),
+            TableCommand::ScrollRightmost => self.scroll_to_x(self.x_max_offset()).into(),
+            _ => Outcome::Continue,
+        }
+    }
+
+    /// Command for a key event with the regular keybindings.
+    /// The Regular event-handler uses the same mapping.
+    #[cfg(feature = "crossterm")]
+    fn key_command(&self, event: &crossterm::event::Event) -> Option<TableCommand> {
+        match event {
+            ct_event!(keycode press Up) => Some(TableCommand::ScrollUp(1)),
+            ct_event!(keycode press Down) => Some(TableCommand::ScrollDown(1)),
+            ct_event!(keycode press CONTROL-Up)
+            | ct_event!(keycode press CONTROL-Home)
+            | ct_event!(keycode press Home) => Some(TableCommand::MoveFirst),
+            ct_event!(keycode press CONTROL-Down)
+            | ct_event!(keycode press CONTROL-End)
+            | ct_event!(keycode press End) => Some(TableCommand::MoveLast),
+            ct_event!(keycode press PageUp) => Some(TableCommand::PageUp),
+            ct_event!(keycode press PageDown) => Some(TableCommand::PageDown),
+            ct_event!(keycode press Left) => Some(TableCommand::ScrollLeft(1)),
+            ct_event!(keycode press Right) => Some(TableCommand::ScrollRight(1)),
+            ct_event!(keycode press CONTROL-Left) => Some(TableCommand::ScrollLeftmost),
+            ct_event!(keycode press CONTROL-Right) => Some(TableCommand::ScrollRightmost),
+            _ => None,
+        }
+    }
+}
+
+impl TableCommands for TableState<NoSelection> {
+    #[cfg(feature = "crossterm")]
+    fn command_for(&self, event: &crossterm::event::Event) -> Option<TableCommand> {
+        if self.is_focused() {
+            if let Some(cmd) = self.key_command(event) {
+                return Some(cmd);
+            }
+        }
+        match event {
+            ct_event!(scroll up for column, row) if self.inner.contains((*column, *row).into()) => {
+                Some(TableCommand::ScrollUp(self.row_scroll_by()))
+            }
+            ct_event!(scroll down for column, row)
+                if self.inner.contains((*column, *row).into()) =>
+            {
+                Some(TableCommand::ScrollDown(self.row_scroll_by()))
+            }
+            _ => None,
+        }
+    }
+
+    fn execute_command(&mut self, cmd: TableCommand) -> Outcome {
+        self.execute(cmd)
+    }
+}
+
+#[cfg(feature = "crossterm")]
 impl HandleEvent<crossterm::event::Event, Regular, Outcome> for TableState<NoSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> Outcome {
+        self.handle_focus_gained();
+        flow!(self.handle_peek(event));
+        flow!(self.handle_annotation(event));
+
+        let res = if self.is_focused() {
+            match self.key_command(event) {
+                Some(cmd) => self.execute(cmd),
+                None => Outcome::Continue,
+            }
+        } else {
+            Outcome::Continue
+        };
+
+        if res == Outcome::Continue {
+            self.handle(event, MouseOnly)
+        } else {
+            res
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandleEvent<crossterm::event::Event, Vim, Outcome> for TableState<NoSelection> {
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: Vim) -> Outcome {
         let res = if self.is_focused() {
+            flow!(self.handle_count_prefix(event));
             match event {
-                ct_event!(keycode press Up) => self.scroll_up(1).into(),
-                ct_event!(keycode press Down) => self.scroll_down(1).into(),
-                ct_event!(keycode press CONTROL-Up)
-                | ct_event!(keycode press CONTROL-Home)
-                | ct_event!(keycode press Home) => self.scroll_to_row(0).into(),
-                ct_event!(keycode press CONTROL-Down)
-                | ct_event!(keycode press CONTROL-End)
-                | ct_event!(keycode press End) => {
-                    self.scroll_to_row(self.rows.saturating_sub(1)).into()
+                ct_event!(key press 'j') | ct_event!(keycode press Down) => {
+                    let n = self.count_prefix.take(self.rows);
+                    self.scroll_down(n).into()
                 }
-
-                ct_event!(keycode press PageUp) => self
-                    .scroll_up(max(1, self.page_len().saturating_sub(1)))
-                    .into(),
-                ct_event!(keycode press PageDown) => self
-                    .scroll_down(max(1, self.page_len().saturating_sub(1)))
-                    .into(),
-
-                ct_event!(keycode press Left) => self.scroll_left(1).into(),
-                ct_event!(keycode press Right) => self.scroll_right(1).into(),
-                ct_event!(keycode press CONTROL-Left) => self.scroll_to_x(0).into(),
-                ct_event!(keycode press CONTROL-Right) => {
-                    self.scroll_to_x(self.x_max_offset()).into()
+                ct_event!(key press 'k') | ct_event!(keycode press Up) => {
+                    let n = self.count_prefix.take(self.rows);
+                    self.scroll_up(n).into()
+                }
+                ct_event!(key press 'h') | ct_event!(keycode press Left) => {
+                    let n = self.count_prefix.take(self.hscroll.max_offset());
+                    self.scroll_left(n).into()
+                }
+                ct_event!(key press 'l') | ct_event!(keycode press Right) => {
+                    let n = self.count_prefix.take(self.hscroll.max_offset());
+                    self.scroll_right(n).into()
+                }
+                ct_event!(key press SHIFT-'G') => match self.count_prefix.pending() {
+                    Some(_) => {
+                        let n = self.count_prefix.take(self.rows);
+                        self.scroll_to_row(n - 1).into()
+                    }
+                    None => self.scroll_to_row(self.rows.saturating_sub(1)).into(),
+                },
+                // other keys drop the count. mouse and focus events don't.
+                crossterm::event::Event::Key(key)
+                    if key.kind != crossterm::event::KeyEventKind::Release =>
+                {
+                    self.count_prefix.clear();
+                    Outcome::Continue
                 }
                 _ => Outcome::Continue,
             }
@@ -65,23 +182,96 @@ impl HandleEvent<crossterm::event::Event, Regular, Outcome> for TableState<NoSel
         };
 
         if res == Outcome::Continue {
-            self.handle(event, MouseOnly)
+            self.handle(event, Regular)
+        } else {
+            res
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl HandleEvent<crossterm::event::Event, Activate, ActivateOutcome> for TableState<NoSelection> {
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: Activate) -> ActivateOutcome {
+        let res = match event {
+            ct_event!(mouse moved for column, row) => {
+                let hover = if self.table_area.contains((*column, *row).into()) {
+                    self.row_at_clicked((*column, *row))
+                } else {
+                    None
+                };
+                if self.hover_row != hover {
+                    self.hover_row = hover;
+                    ActivateOutcome::Changed
+                } else {
+                    ActivateOutcome::Continue
+                }
+            }
+            ct_event!(mouse any for m) if self.mouse.doubleclick(self.table_area, m) => {
+                if let Some(row) = self.row_at_clicked((m.column, m.row)) {
+                    ActivateOutcome::Activated(row)
+                } else {
+                    ActivateOutcome::Continue
+                }
+            }
+            ct_event!(mouse down Left for column, row)
+                if self.table_area.contains((*column, *row).into()) =>
+            {
+                if let Some(row) = self.row_at_clicked((*column, *row)) {
+                    ActivateOutcome::Clicked(row)
+                } else {
+                    ActivateOutcome::Continue
+                }
+            }
+            ct_event!(keycode press Enter) if self.is_focused() => {
+                if let Some(row) = self.hover_row {
+                    ActivateOutcome::Activated(row)
+                } else {
+                    ActivateOutcome::Continue
+                }
+            }
+            _ => ActivateOutcome::Continue,
+        };
+
+        if res == ActivateOutcome::Continue {
+            self.handle(event, Regular).into()
         } else {
             res
         }
     }
 }
 
+#[cfg(feature = "crossterm")]
 impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for TableState<NoSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> Outcome {
-        let mut sas = ScrollAreaState::new()
-            .area(self.inner)
-            .h_scroll(&mut self.hscroll)
-            .v_scroll(&mut self.vscroll);
-        let r = match sas.handle(event, MouseOnly) {
+        let touch = self.touch_event(event);
+        let event = touch.as_ref().unwrap_or(event);
+
+        flow!(self.handle_overflow(event));
+        flow!(self.handle_resize(event));
+        flow!(self.handle_minimap(event));
+        flow!(self.handle_swipe(event));
+
+        let hwheel = self.handle_hwheel(event);
+        let scroll = if hwheel != ScrollOutcome::Continue {
+            hwheel
+        } else {
+            // wheel_scroll_by only applies for this event.
+            let scroll_by = self.vscroll.scroll_by;
+            self.vscroll.scroll_by = self.wheel_scroll_by.or(scroll_by);
+            let mut sas = ScrollAreaState::new()
+                .area(self.inner)
+                .h_scroll(&mut self.hscroll)
+                .v_scroll(&mut self.vscroll);
+            let r = sas.handle(event, MouseOnly);
+            self.vscroll.scroll_by = scroll_by;
+            r
+        };
+        flow!(self.handle_coalesce(scroll));
+
+        let r = match scroll {
             ScrollOutcome::Up(v) => self.scroll_up(v),
             ScrollOutcome::Down(v) => self.scroll_down(v),
-            ScrollOutcome::VPos(v) => self.set_row_offset(v),
+            ScrollOutcome::VPos(v) => self.set_row_offset(self.remap_vpos(v)),
             ScrollOutcome::Left(v) => self.scroll_left(v),
             ScrollOutcome::Right(v) => self.scroll_right(v),
             ScrollOutcome::HPos(v) => self.set_x_offset(v),
@@ -101,6 +291,7 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for TableState<NoS
 /// Handle all events.
 /// Table events are only processed if focus is true.
 /// Mouse events are processed if they are in range.
+#[cfg(feature = "crossterm")]
 pub fn handle_events(
     state: &mut TableState<NoSelection>,
     focus: bool,
@@ -110,7 +301,36 @@ pub fn handle_events(
     state.handle(event, Regular)
 }
 
+/// Handle all events with the vim-style keybindings.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+#[cfg(feature = "crossterm")]
+pub fn handle_vim_events(
+    state: &mut TableState<NoSelection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    state.focus.set(focus);
+    state.handle(event, Vim)
+}
+
+/// Handle all events and track the row under the mouse.
+/// Clicking a row returns Clicked, double-click or Enter
+/// returns Activated.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+#[cfg(feature = "crossterm")]
+pub fn handle_activate_events(
+    state: &mut TableState<NoSelection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> ActivateOutcome {
+    state.focus.set(focus);
+    state.handle(event, Activate)
+}
+
 /// Handle only mouse-events.
+#[cfg(feature = "crossterm")]
 pub fn handle_mouse_events(
     state: &mut TableState<NoSelection>,
     event: &crossterm::event::Event,
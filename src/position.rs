@@ -0,0 +1,128 @@
+//!
+//! Status line snippet "Row X of Y".
+//!
+//! [TablePositionIndicator] renders the current row, the row count
+//! and the percentage, like "123/100,000 (42%)". The current row
+//! is the lead selection, or the first visible row if nothing is
+//! selected.
+//!
+//! With [no_row_count](crate::Table::no_row_count) the row count is
+//! unknown until the end of the data has been reached. Meanwhile
+//! the estimate is shown as "~4,096" and the percentage is left out.
+//!
+//! ```rust
+//! use rat_ftable::position::TablePositionIndicator;
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::TableState;
+//!
+//! let mut table = TableState::<RowSelection>::default();
+//! table.rows = 250_000;
+//! table.select(Some(99_999));
+//!
+//! let indicator = TablePositionIndicator::new(&table);
+//! assert_eq!(indicator.text(), "100,000/250,000 (40%)");
+//! ```
+//!
+
+use crate::{TableSelection, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::widgets::Widget;
+
+/// Renders "row/rows (percent)" for a table.
+#[derive(Debug, Default, Clone)]
+pub struct TablePositionIndicator {
+    row: Option<usize>,
+    rows: usize,
+    estimated_rows: Option<usize>,
+    style: Style,
+    alignment: Alignment,
+    percent: bool,
+}
+
+impl TablePositionIndicator {
+    /// Position of the table as of the last render.
+    pub fn new<Selection: TableSelection>(state: &TableState<Selection>) -> Self {
+        let row = match state.selection.lead_selection() {
+            Some((_, row)) => Some(row),
+            None if state.rows > 0 => Some(state.vscroll.offset()),
+            None => None,
+        };
+        let (rows, estimated_rows) = if state.rows == usize::MAX {
+            (0, Some(state.estimated_rows))
+        } else {
+            (state.rows, None)
+        };
+        Self {
+            row,
+            rows,
+            estimated_rows,
+            style: Default::default(),
+            alignment: Alignment::Right,
+            percent: true,
+        }
+    }
+
+    /// Base style.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Alignment within the area. Defaults to right.
+    #[inline]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Show the percentage. Defaults to true.
+    #[inline]
+    pub fn percent(mut self, percent: bool) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// The text that will be rendered.
+    pub fn text(&self) -> String {
+        let row = self.row.map(|v| group_digits(v + 1));
+        let row = row.as_deref().unwrap_or("-");
+        match self.estimated_rows {
+            Some(0) => format!("{}/?", row),
+            Some(estimate) => format!("{}/~{}", row, group_digits(estimate)),
+            None => {
+                let mut text = format!("{}/{}", row, group_digits(self.rows));
+                if self.percent && self.rows > 0 {
+                    let percent = self.row.map(|v| (v + 1) * 100 / self.rows).unwrap_or(0);
+                    text.push_str(&format!(" ({}%)", percent));
+                }
+                text
+            }
+        }
+    }
+}
+
+impl Widget for TablePositionIndicator {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Line::from(self.text())
+            .style(self.style)
+            .alignment(self.alignment)
+            .render(area, buf);
+    }
+}
+
+/// Formats with a comma between groups of three digits.
+fn group_digits(n: usize) -> String {
+    let digits = n.to_string();
+    let mut text = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            text.push(',');
+        }
+        text.push(c);
+    }
+    text
+}
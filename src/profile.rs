@@ -0,0 +1,322 @@
+//!
+//! Named table layouts.
+//!
+//! A [TableProfile] captures the column order, visibility and
+//! widths together with the sort and filter settings. Profiles
+//! can be saved as plain text and switched at runtime.
+//!
+//! The table itself only knows about the column widths. Those
+//! are applied with [TableProfile::apply]. Column order, sorting
+//! and filtering are left to the application, the profile just
+//! keeps the settings. [TableProfile::decorations] gives the
+//! matching header indicators.
+//!
+//! ```rust
+//! use rat_ftable::profile::{SortSpec, TableProfile};
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::TableState;
+//!
+//! let profile = TableProfile::new("errors")
+//!     .columns([2, 0, 1])
+//!     .hide(1)
+//!     .width(2, 40)
+//!     .sort(SortSpec::descending(0))
+//!     .filter(1, "ERROR");
+//!
+//! let text = profile.to_string();
+//! let loaded: TableProfile = text.parse().expect("profile");
+//! assert_eq!(loaded, profile);
+//! assert_eq!(loaded.visible_columns(3), vec![2, 0]);
+//!
+//! let mut state = TableState::<RowSelection>::default();
+//! loaded.apply(&mut state);
+//! assert_eq!(state.width_overrides.get(&2), Some(&40));
+//! ```
+//!
+//! The text format is one `key=value` per line.
+//!
+//! ```text
+//! name=errors
+//! columns=2,0,1
+//! hidden=1
+//! width=2:40
+//! sort=0:desc
+//! filter=1:ERROR
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::{Decoration, TableState};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Sort order for one column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortSpec {
+    /// Column.
+    pub column: usize,
+    /// Sort descending.
+    pub descending: bool,
+}
+
+impl SortSpec {
+    /// Sort ascending by the column.
+    pub fn ascending(column: usize) -> Self {
+        Self {
+            column,
+            descending: false,
+        }
+    }
+
+    /// Sort descending by the column.
+    pub fn descending(column: usize) -> Self {
+        Self {
+            column,
+            descending: true,
+        }
+    }
+}
+
+/// Saved column layout, sort and filter settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableProfile {
+    /// Name of the profile.
+    pub name: String,
+    /// Column order. Columns not listed follow in their
+    /// natural order. Empty keeps the natural order.
+    pub columns: Vec<usize>,
+    /// Hidden columns.
+    pub hidden: Vec<usize>,
+    /// Column widths. These use the column index of the table,
+    /// which is the display position after reordering.
+    pub widths: Vec<(usize, u16)>,
+    /// Sort columns, most significant first.
+    pub sort: Vec<SortSpec>,
+    /// Filter values per column.
+    pub filters: Vec<(usize, String)>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// Error when parsing a [TableProfile].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileError {
+    /// Line number, starting with 1.
+    pub line: usize,
+    /// The invalid line.
+    pub text: String,
+}
+
+impl Display for ProfileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid profile line {}: {:?}", self.line, self.text)
+    }
+}
+
+impl Error for ProfileError {}
+
+impl Default for TableProfile {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            columns: Default::default(),
+            hidden: Default::default(),
+            widths: Default::default(),
+            sort: Default::default(),
+            filters: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl TableProfile {
+    /// New profile.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Capture the current column widths of the table.
+    pub fn from_state<Selection>(name: impl Into<String>, state: &TableState<Selection>) -> Self {
+        let mut widths = state
+            .width_overrides
+            .iter()
+            .map(|(c, w)| (*c, *w))
+            .collect::<Vec<_>>();
+        widths.sort();
+        Self {
+            name: name.into(),
+            widths,
+            ..Default::default()
+        }
+    }
+
+    /// Column order.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = usize>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Hide a column.
+    pub fn hide(mut self, column: usize) -> Self {
+        if !self.hidden.contains(&column) {
+            self.hidden.push(column);
+        }
+        self
+    }
+
+    /// Fixed width for a column.
+    pub fn width(mut self, column: usize, width: u16) -> Self {
+        self.widths.retain(|(c, _)| *c != column);
+        self.widths.push((column, width));
+        self
+    }
+
+    /// Add a sort column.
+    pub fn sort(mut self, sort: SortSpec) -> Self {
+        self.sort.retain(|v| v.column != sort.column);
+        self.sort.push(sort);
+        self
+    }
+
+    /// Filter value for a column.
+    pub fn filter(mut self, column: usize, value: impl Into<String>) -> Self {
+        self.filters.retain(|(c, _)| *c != column);
+        self.filters.push((column, value.into()));
+        self
+    }
+
+    /// Visible columns in display order for a table with
+    /// the given number of columns.
+    pub fn visible_columns(&self, columns: usize) -> Vec<usize> {
+        let mut order = self
+            .columns
+            .iter()
+            .copied()
+            .filter(|c| *c < columns)
+            .collect::<Vec<_>>();
+        for c in 0..columns {
+            if !order.contains(&c) {
+                order.push(c);
+            }
+        }
+        order.retain(|c| !self.hidden.contains(c));
+        order
+    }
+
+    /// Header indicators for the sort and filter columns.
+    ///
+    /// The column index is the display position as given by
+    /// [visible_columns](TableProfile::visible_columns).
+    pub fn decorations(&self, columns: usize) -> Vec<(usize, Decoration)> {
+        let order = self.visible_columns(columns);
+        let mut result = Vec::new();
+        for (pos, col) in order.iter().enumerate() {
+            if let Some(sort) = self.sort.iter().find(|v| v.column == *col) {
+                if sort.descending {
+                    result.push((pos, Decoration::SortDesc));
+                } else {
+                    result.push((pos, Decoration::SortAsc));
+                }
+            } else if self.filters.iter().any(|(c, _)| c == col) {
+                result.push((pos, Decoration::Filtered));
+            }
+        }
+        result
+    }
+
+    /// Apply the column widths to the table.
+    ///
+    /// Replaces any widths set by resizing the columns.
+    pub fn apply<Selection>(&self, state: &mut TableState<Selection>) {
+        state.width_overrides.clear();
+        state.width_overrides.extend(self.widths.iter().copied());
+    }
+}
+
+impl Display for TableProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "name={}", self.name)?;
+        if !self.columns.is_empty() {
+            let columns = self
+                .columns
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>();
+            writeln!(f, "columns={}", columns.join(","))?;
+        }
+        if !self.hidden.is_empty() {
+            let hidden = self
+                .hidden
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>();
+            writeln!(f, "hidden={}", hidden.join(","))?;
+        }
+        for (column, width) in &self.widths {
+            writeln!(f, "width={}:{}", column, width)?;
+        }
+        for sort in &self.sort {
+            let dir = if sort.descending { "desc" } else { "asc" };
+            writeln!(f, "sort={}:{}", sort.column, dir)?;
+        }
+        for (column, value) in &self.filters {
+            writeln!(f, "filter={}:{}", column, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TableProfile {
+    type Err = ProfileError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn list(v: &str) -> Option<Vec<usize>> {
+            v.split(',').map(|v| v.trim().parse().ok()).collect()
+        }
+
+        let mut profile = TableProfile::default();
+        for (idx, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let err = || ProfileError {
+                line: idx + 1,
+                text: line.to_string(),
+            };
+
+            let (key, value) = line.split_once('=').ok_or_else(err)?;
+            match key.trim() {
+                "name" => profile.name = value.to_string(),
+                "columns" => profile.columns = list(value).ok_or_else(err)?,
+                "hidden" => profile.hidden = list(value).ok_or_else(err)?,
+                "width" => {
+                    let (c, w) = value.split_once(':').ok_or_else(err)?;
+                    let c = c.trim().parse().map_err(|_| err())?;
+                    let w = w.trim().parse().map_err(|_| err())?;
+                    profile.widths.push((c, w));
+                }
+                "sort" => {
+                    let (c, d) = value.split_once(':').ok_or_else(err)?;
+                    let column = c.trim().parse().map_err(|_| err())?;
+                    let descending = match d.trim() {
+                        "asc" => false,
+                        "desc" => true,
+                        _ => return Err(err()),
+                    };
+                    profile.sort.push(SortSpec { column, descending });
+                }
+                "filter" => {
+                    let (c, v) = value.split_once(':').ok_or_else(err)?;
+                    let c = c.trim().parse().map_err(|_| err())?;
+                    profile.filters.push((c, v.to_string()));
+                }
+                _ => return Err(err()),
+            }
+        }
+        Ok(profile)
+    }
+}
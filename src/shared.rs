@@ -0,0 +1,225 @@
+//!
+//! TableData that is shared between the application and the widget.
+//!
+//! [SharedTableData] wraps the data in a `Rc<RefCell<T>>`,
+//! [SyncTableData] in a `Arc<RwLock<T>>`. The application keeps
+//! one handle and updates the data, and hands a clone to the Table
+//! each frame. Both implement TableData by delegation.
+//!
+//! ```rust
+//! use rat_ftable::shared::SyncTableData;
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::{Table, TableContext, TableData};
+//! use ratatui::buffer::Buffer;
+//! use ratatui::layout::Rect;
+//!
+//! struct Counters(Vec<u64>);
+//!
+//! impl<'a> TableData<'a> for Counters {
+//!     fn rows(&self) -> usize {
+//!         self.0.len()
+//!     }
+//!
+//!     fn render_cell(&self, _: &TableContext, _: usize, _: usize, _: Rect, _: &mut Buffer) {}
+//! }
+//!
+//! let data = SyncTableData::new(Counters(vec![1, 2]));
+//!
+//! // each frame
+//! let table: Table<RowSelection> = Table::default().data(data.clone());
+//!
+//! // somewhere else
+//! data.write().0.push(3);
+//! assert_eq!(data.rows(), 3);
+//! ```
+//!
+//! __Attention__
+//!
+//! [TableData::cell_text] can't borrow through the lock, so the
+//! text is always returned as an owned copy.
+//!
+//! A Table created with [Table::new_send](crate::Table::new_send)
+//! only takes data that is Send (see [SendData](crate::SendData)).
+//! Use SyncTableData there.
+//!
+
+use crate::textdata::Row;
+use crate::{Badge, FormattedRow, TableContext, TableData};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use std::borrow::Cow;
+use std::cell::{Ref, RefCell, RefMut};
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// TableData in a `Rc<RefCell<T>>`.
+///
+/// The data must not be borrowed mutably while rendering.
+#[derive(Debug, Default)]
+pub struct SharedTableData<T> {
+    data: Rc<RefCell<T>>,
+}
+
+impl<T> Clone for SharedTableData<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: Rc::clone(&self.data),
+        }
+    }
+}
+
+impl<T> From<Rc<RefCell<T>>> for SharedTableData<T> {
+    fn from(data: Rc<RefCell<T>>) -> Self {
+        Self { data }
+    }
+}
+
+impl<T> SharedTableData<T> {
+    /// New shared data.
+    pub fn new(data: T) -> Self {
+        Self {
+            data: Rc::new(RefCell::new(data)),
+        }
+    }
+
+    /// Borrow the data.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        self.data.borrow()
+    }
+
+    /// Borrow the data for modification.
+    pub fn borrow_mut(&self) -> RefMut<'_, T> {
+        self.data.borrow_mut()
+    }
+
+    /// The underlying Rc.
+    pub fn as_rc(&self) -> &Rc<RefCell<T>> {
+        &self.data
+    }
+}
+
+/// TableData in a `Arc<RwLock<T>>`.
+///
+/// A poisoned lock is ignored, the data is used anyway.
+/// The data must not be locked for writing while rendering.
+#[derive(Debug, Default)]
+pub struct SyncTableData<T> {
+    data: Arc<RwLock<T>>,
+}
+
+impl<T> Clone for SyncTableData<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: Arc::clone(&self.data),
+        }
+    }
+}
+
+impl<T> From<Arc<RwLock<T>>> for SyncTableData<T> {
+    fn from(data: Arc<RwLock<T>>) -> Self {
+        Self { data }
+    }
+}
+
+impl<T> SyncTableData<T> {
+    /// New shared data.
+    pub fn new(data: T) -> Self {
+        Self {
+            data: Arc::new(RwLock::new(data)),
+        }
+    }
+
+    /// Lock the data for reading.
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.data.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Lock the data for writing.
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        self.data.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The underlying Arc.
+    pub fn as_arc(&self) -> &Arc<RwLock<T>> {
+        &self.data
+    }
+}
+
+macro_rules! delegate_table_data {
+    ($ty:ident, $get:ident) => {
+        impl<'a, T> TableData<'a> for $ty<T>
+        where
+            T: TableData<'a>,
+        {
+            fn rows(&self) -> usize {
+                self.$get().rows()
+            }
+
+            fn header(&self) -> Option<Row<'a>> {
+                self.$get().header()
+            }
+
+            fn footer(&self) -> Option<Row<'a>> {
+                self.$get().footer()
+            }
+
+            fn row_height(&self, row: usize) -> u16 {
+                self.$get().row_height(row)
+            }
+
+            fn row_style(&self, row: usize) -> Option<Style> {
+                self.$get().row_style(row)
+            }
+
+            fn widths(&self) -> Vec<Constraint> {
+                self.$get().widths()
+            }
+
+            fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+                self.$get()
+                    .cell_text(column, row)
+                    .map(|v| Cow::Owned(v.into_owned()))
+            }
+
+            fn row_summary_style(&self, row: usize) -> Option<Style> {
+                self.$get().row_summary_style(row)
+            }
+
+            fn version(&self) -> Option<u64> {
+                self.$get().version()
+            }
+
+            fn row_progress(&self, row: usize) -> Option<f32> {
+                self.$get().row_progress(row)
+            }
+
+            fn cell_badge(&self, column: usize, row: usize) -> Option<Badge> {
+                self.$get().cell_badge(column, row)
+            }
+
+            fn render_detail(&self, row: usize, area: Rect, buf: &mut Buffer) {
+                self.$get().render_detail(row, area, buf)
+            }
+
+            fn format_rows(&self, rows: Range<usize>) -> Vec<FormattedRow<'a>> {
+                self.$get().format_rows(rows)
+            }
+
+            fn render_cell(
+                &self,
+                ctx: &TableContext,
+                column: usize,
+                row: usize,
+                area: Rect,
+                buf: &mut Buffer,
+            ) {
+                self.$get().render_cell(ctx, column, row, area, buf)
+            }
+        }
+    };
+}
+
+delegate_table_data!(SharedTableData, borrow);
+delegate_table_data!(SyncTableData, read);
@@ -0,0 +1,232 @@
+//!
+//! Keep two tables over the same rows in sync.
+//!
+//! This is useful if you split a table into two widgets, for example
+//! a left table with frozen columns and a right table that scrolls
+//! horizontally.
+//!
+//! Call [LinkedTables::sync] after the event-handling of either table.
+//! Whichever table changed is copied to the other one.
+//!
+//! [ScrollLock] only locks the vertical offsets together. The tables
+//! can use different selection models and scroll horizontally on their
+//! own. Use this for side-by-side comparison views.
+//!
+//! ```rust
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::sync::LinkedTables;
+//! use rat_ftable::TableState;
+//!
+//! let mut left = TableState::<RowSelection>::default();
+//! let mut right = TableState::<RowSelection>::default();
+//! left.rows = 100;
+//! right.rows = 100;
+//!
+//! let mut link = LinkedTables::new();
+//! link.sync(&mut left, &mut right);
+//!
+//! right.select(Some(42));
+//! assert!(link.sync(&mut left, &mut right));
+//! assert_eq!(left.selected(), Some(42));
+//! ```
+//!
+//! [TableRequests] lets other threads steer a table. The requests
+//! are only recorded, and applied with the next render when the
+//! row count and the layout are known.
+//!
+//! ```rust
+//! use rat_ftable::selection::RowSelection;
+//! use rat_ftable::TableState;
+//!
+//! let mut state = TableState::<RowSelection>::default();
+//! state.rows = 100;
+//!
+//! let requests = state.requests.clone();
+//! std::thread::spawn(move || {
+//!     requests.request_select(500);
+//! })
+//! .join()
+//! .expect("thread");
+//!
+//! // done by render.
+//! state.apply_requests();
+//! assert_eq!(state.selected(), Some(99));
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::{TableSelection, TableState};
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+/// Locks the vertical offsets of two tables together.
+///
+/// The horizontal offsets stay independent.
+#[derive(Debug, Clone)]
+pub struct ScrollLock {
+    /// Last synced vertical offset.
+    pub offset: Option<usize>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for ScrollLock {
+    fn default() -> Self {
+        Self {
+            offset: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl ScrollLock {
+    /// New lock.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy the vertical offset from whichever table changed
+    /// since the last sync. If both changed, the first one wins.
+    ///
+    /// Returns true if one of the tables has been changed.
+    pub fn sync<A, B>(&mut self, a: &mut TableState<A>, b: &mut TableState<B>) -> bool
+    where
+        A: TableSelection,
+        B: TableSelection,
+    {
+        let offset = if Some(a.row_offset()) != self.offset {
+            a.row_offset()
+        } else {
+            b.row_offset()
+        };
+        let mut changed = a.set_row_offset(offset);
+        changed |= b.set_row_offset(offset);
+        self.offset = Some(offset);
+        changed
+    }
+
+    /// Forget the last synced offset. The next sync copies
+    /// from the first table.
+    pub fn reset(&mut self) {
+        self.offset = None;
+    }
+}
+
+/// Mirrors the selection and the vertical offset of two tables.
+///
+/// Both tables must use the same selection model. The horizontal
+/// offset is not synced.
+#[derive(Debug, Clone)]
+pub struct LinkedTables<Selection> {
+    /// Vertical offset.
+    pub scroll: ScrollLock,
+    /// Last synced selection.
+    pub selection: Option<Selection>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl<Selection> Default for LinkedTables<Selection> {
+    fn default() -> Self {
+        Self {
+            scroll: Default::default(),
+            selection: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<Selection> LinkedTables<Selection>
+where
+    Selection: TableSelection + Clone + PartialEq,
+{
+    /// New link.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy the offset and selection from whichever table changed
+    /// since the last sync. If both changed, the first one wins.
+    ///
+    /// Returns true if one of the tables has been changed.
+    pub fn sync(&mut self, a: &mut TableState<Selection>, b: &mut TableState<Selection>) -> bool {
+        let mut changed = self.scroll.sync(a, b);
+
+        let selection = if self.selection.as_ref() != Some(&a.selection) {
+            a.selection.clone()
+        } else {
+            b.selection.clone()
+        };
+        if a.selection != selection {
+            a.selection = selection.clone();
+            changed = true;
+        }
+        if b.selection != selection {
+            b.selection = selection.clone();
+            changed = true;
+        }
+        self.selection = Some(selection);
+
+        changed
+    }
+
+    /// Forget the last synced state. The next sync copies
+    /// from the first table.
+    pub fn reset(&mut self) {
+        self.scroll.reset();
+        self.selection = None;
+    }
+}
+
+/// Scroll and selection requests from outside the render loop.
+///
+/// This is a handle that can be cloned and sent to other threads.
+/// All clones share the same requests. A later request of the
+/// same kind replaces an earlier one.
+///
+/// The requests are validated against the row count and applied
+/// at the start of the next render, see [TableState::apply_requests].
+#[derive(Debug, Default, Clone)]
+pub struct TableRequests {
+    pending: Arc<Mutex<PendingRequests>>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct PendingRequests {
+    pub(crate) scroll_to_row: Option<usize>,
+    pub(crate) select: Option<usize>,
+}
+
+impl TableRequests {
+    /// New requests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scroll the row into view with the next render.
+    pub fn request_scroll_to_row(&self, row: usize) {
+        self.lock().scroll_to_row = Some(row);
+    }
+
+    /// Select the row and scroll it into view with the next render.
+    /// The row is limited to the row count.
+    pub fn request_select(&self, row: usize) {
+        self.lock().select = Some(row);
+    }
+
+    /// Are there any requests.
+    pub fn is_pending(&self) -> bool {
+        let pending = self.lock();
+        pending.scroll_to_row.is_some() || pending.select.is_some()
+    }
+
+    /// Take all pending requests.
+    pub(crate) fn take(&self) -> PendingRequests {
+        mem::take(&mut *self.lock())
+    }
+
+    // A poisoned lock is ignored, the requests are plain values.
+    fn lock(&self) -> std::sync::MutexGuard<'_, PendingRequests> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
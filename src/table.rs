@@ -1,29 +1,55 @@
 #![allow(clippy::collapsible_if)]
 
 use crate::_private::NonExhaustive;
-use crate::event::{DoubleClick, DoubleClickOutcome};
+#[cfg(feature = "crossterm")]
+use crate::event::{
+    BadgeClick, BadgeOutcome, Click, ColumnGroup, ColumnGroupOutcome, DoubleClick,
+    DoubleClickOutcome, Outcome,
+};
+use crate::export::buffer_to_ansi;
+use crate::matcher::RowMatch;
 use crate::selection::{CellSelection, RowSelection, RowSetSelection};
+use crate::sync::TableRequests;
 use crate::table::data::{DataRepr, DataReprIter};
 use crate::textdata::{Row, TextTableData};
-use crate::util::{fallback_select_style, revert_style, transfer_buffer};
-use crate::{TableContext, TableData, TableDataIter, TableSelection};
+use crate::util::{
+    copy_area, fallback_select_style, fit_text, high_contrast_style, patch_ranges, revert_style,
+    shift_down, transfer_buffer, transfer_columns, wrap_text,
+};
+use crate::{
+    DataKind, LocalData, SendData, TableContext, TableData, TableDataIter, TableSelection,
+};
+#[cfg(feature = "crossterm")]
+use rat_event::util::double_click_timeout;
 use rat_event::util::MouseFlags;
+#[cfg(feature = "crossterm")]
 use rat_event::{ct_event, HandleEvent};
 use rat_focus::{FocusFlag, HasFocus};
 use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
-use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle};
+#[cfg(feature = "crossterm")]
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::{
+    Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle, ScrollbarPolicy,
+};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Flex, Layout, Rect};
-use ratatui::style::Style;
-use ratatui::widgets::{Block, StatefulWidget, Widget};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Text;
+use ratatui::widgets::{Block, Clear, StatefulWidget, Widget};
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::{StatefulWidgetRef, WidgetRef};
+use std::borrow::Cow;
 use std::cmp::{max, min};
-use std::collections::HashSet;
-use std::fmt::Debug;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::mem;
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 /// Table widget.
 ///
@@ -40,18 +66,23 @@ use std::rc::Rc;
 ///
 /// See [Table::data] and [Table::iter] for an example.
 #[derive(Debug)]
-pub struct Table<'a, Selection> {
-    data: DataRepr<'a>,
+pub struct Table<'a, Selection, Kind: DataKind<'a> = LocalData> {
+    data: DataRepr<'a, Kind>,
     no_row_count: bool,
+    paged: bool,
+    progressive: Option<u16>,
+    overscroll: usize,
+    row_count_budget: Option<usize>,
 
-    header: Option<Row<'a>>,
-    footer: Option<Row<'a>>,
+    header: Option<Cow<'a, Row<'a>>>,
+    footer: Option<Cow<'a, Row<'a>>>,
 
-    widths: Vec<Constraint>,
+    widths: Cow<'a, [Constraint]>,
     flex: Flex,
     column_spacing: u16,
     layout_width: Option<u16>,
     auto_layout_width: bool,
+    latch_layout_width: bool,
 
     block: Option<Block<'a>>,
     hscroll: Option<Scroll<'a>>,
@@ -74,38 +105,97 @@ pub struct Table<'a, Selection> {
 
     focus_style: Option<Style>,
 
+    highlight: Option<&'a [RowMatch]>,
+    highlight_style: Option<Style>,
+
+    overflow_indicator: bool,
+    overflow_style: Option<Style>,
+
+    resize_handles: bool,
+    wrap_header: bool,
+    header_decorations: Vec<(usize, Decoration)>,
+    decoration_style: Option<Style>,
+    row_numbers: RowNumbers,
+    row_number_style: Option<Style>,
+    minimap: u16,
+    minimap_style: Option<Style>,
+    app_gutter: u16,
+    app_gutter_style: Option<Style>,
+    progress_style: Option<Style>,
+    fill_space: bool,
+    anchor_bottom: bool,
+    reserve_scrollbars: bool,
+    start_at: Option<StartAt>,
+    hover_style: Option<Style>,
+    indicator_style: Option<Style>,
+    column_group_style: Option<Style>,
+    annotation_style: Option<Style>,
+    resize_handle_style: Option<Style>,
+    resize_handle_hover_style: Option<Style>,
+    peek_style: Option<Style>,
+    drag_style: Option<Style>,
+    badge_style: Option<Style>,
+    detail_pane: DetailPane,
+    detail_block: Option<Block<'a>>,
+    catch_cell_panics: bool,
+    list: bool,
+    pin_right: usize,
+    blend: bool,
+    watermark: Option<Watermark<'a, Kind>>,
+    watermark_style: Option<Style>,
+
+    row_order: Option<&'a [usize]>,
+    wheel_scroll: WheelScroll,
+    patch_order: PatchOrder,
+
     debug: bool,
 
     _phantom: PhantomData<Selection>,
 }
 
+/// Watermark of the table.
+enum Watermark<'a, Kind: DataKind<'a>> {
+    Text(Text<'a>),
+    Render(Box<Kind::Render>),
+}
+
+impl<'a, Kind: DataKind<'a>> Debug for Watermark<'a, Kind> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Watermark::Text(v) => f.debug_tuple("Text").field(v).finish(),
+            Watermark::Render(_) => f.debug_tuple("Render").finish(),
+        }
+    }
+}
+
 mod data {
-    use crate::textdata::TextTableData;
-    use crate::{TableContext, TableData, TableDataIter};
+    use crate::textdata::{Row, TextTableData};
+    use crate::{Badge, DataKind, FormattedRow, TableContext, TableData, TableDataIter};
     #[cfg(debug_assertions)]
     use log::warn;
     use ratatui::buffer::Buffer;
-    use ratatui::layout::Rect;
+    use ratatui::layout::{Constraint, Rect};
     use ratatui::style::{Style, Stylize};
+    use std::borrow::Cow;
+    use std::cmp::min;
     use std::fmt::{Debug, Formatter};
+    use std::ops::Range;
 
-    #[derive(Default)]
-    pub(super) enum DataRepr<'a> {
-        #[default]
+    pub(super) enum DataRepr<'a, Kind: DataKind<'a>> {
         None,
         Text(TextTableData<'a>),
-        Data(Box<dyn TableData<'a> + 'a>),
-        Iter(Box<dyn TableDataIter<'a> + 'a>),
+        Data(Box<Kind::Data>),
+        Iter(Box<Kind::Iter>),
         // TODO: maybe add an Owned where data is kept in the state?
     }
 
-    impl<'a> DataRepr<'a> {
+    impl<'a, Kind: DataKind<'a>> DataRepr<'a, Kind> {
         pub(super) fn into_iter(self) -> DataReprIter<'a, 'a> {
             match self {
                 DataRepr::None => DataReprIter::None,
                 DataRepr::Text(v) => DataReprIter::IterText(v, None),
-                DataRepr::Data(v) => DataReprIter::IterData(v, None),
-                DataRepr::Iter(v) => DataReprIter::IterIter(v),
+                DataRepr::Data(v) => DataReprIter::IterData(Kind::local_data(v), None),
+                DataRepr::Iter(v) => DataReprIter::IterIter(Kind::local_iter(v)),
             }
         }
 
@@ -114,7 +204,9 @@ mod data {
             match self {
                 DataRepr::None => DataReprIter::None,
                 DataRepr::Text(v) => DataReprIter::IterDataRef(v, None),
-                DataRepr::Data(v) => DataReprIter::IterDataRef(v.as_ref(), None),
+                DataRepr::Data(v) => {
+                    DataReprIter::IterDataRef(Kind::local_data_ref(v.as_ref()), None)
+                }
                 DataRepr::Iter(v) => {
                     // TableDataIter might not implement a valid cloned().
                     if let Some(v) = v.cloned() {
@@ -127,7 +219,7 @@ mod data {
         }
     }
 
-    impl Debug for DataRepr<'_> {
+    impl<'a, Kind: DataKind<'a>> Debug for DataRepr<'a, Kind> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
             f.debug_struct("Data").finish()
         }
@@ -146,6 +238,183 @@ mod data {
         IterIter(Box<dyn TableDataIter<'a> + 'a>),
     }
 
+    /// Shows the rows of the data in the given order.
+    pub(super) struct OrderedData<'a> {
+        data: Box<dyn TableData<'a> + 'a>,
+        order: Cow<'a, [usize]>,
+    }
+
+    impl OrderedData<'_> {
+        fn data_row(&self, row: usize) -> usize {
+            self.order[row]
+        }
+    }
+
+    impl<'a> TableData<'a> for OrderedData<'a> {
+        fn rows(&self) -> usize {
+            self.order.len()
+        }
+
+        fn header(&self) -> Option<Row<'a>> {
+            self.data.header()
+        }
+
+        fn footer(&self) -> Option<Row<'a>> {
+            self.data.footer()
+        }
+
+        fn row_height(&self, row: usize) -> u16 {
+            self.data.row_height(self.data_row(row))
+        }
+
+        fn row_style(&self, row: usize) -> Option<Style> {
+            self.data.row_style(self.data_row(row))
+        }
+
+        fn widths(&self) -> Vec<Constraint> {
+            self.data.widths()
+        }
+
+        fn cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+            self.data.cell_text(column, self.data_row(row))
+        }
+
+        fn row_summary_style(&self, row: usize) -> Option<Style> {
+            self.data.row_summary_style(self.data_row(row))
+        }
+
+        fn version(&self) -> Option<u64> {
+            self.data.version()
+        }
+
+        fn row_progress(&self, row: usize) -> Option<f32> {
+            self.data.row_progress(self.data_row(row))
+        }
+
+        fn cell_badge(&self, column: usize, row: usize) -> Option<Badge> {
+            self.data.cell_badge(column, self.data_row(row))
+        }
+
+        fn render_detail(&self, row: usize, area: Rect, buf: &mut Buffer) {
+            self.data.render_detail(self.data_row(row), area, buf)
+        }
+
+        fn format_rows(&self, rows: Range<usize>) -> Vec<FormattedRow<'a>> {
+            // the data rows are not contiguous, format them one by one.
+            let mut formatted = Vec::new();
+            for row in rows.start..min(rows.end, self.order.len()) {
+                let data_row = self.data_row(row);
+                for mut v in self.data.format_rows(data_row..data_row + 1) {
+                    v.row = row;
+                    formatted.push(v);
+                }
+            }
+            formatted
+        }
+
+        fn render_cell(
+            &self,
+            ctx: &TableContext,
+            column: usize,
+            row: usize,
+            area: Rect,
+            buf: &mut Buffer,
+        ) {
+            self.data
+                .render_cell(ctx, column, self.data_row(row), area, buf)
+        }
+    }
+
+    impl<'a, 'b> DataReprIter<'a, 'b> {
+        /// Applies a row order to the data. Rows in the order
+        /// that don't exist in the data are left out.
+        ///
+        /// Not available for TableDataIter and borrowed data.
+        /// Returns the order that is used.
+        pub(super) fn ordered(
+            self,
+            order: &'a [usize],
+        ) -> (DataReprIter<'a, 'b>, Option<Vec<usize>>) {
+            let data: Box<dyn TableData<'a> + 'a> = match self {
+                DataReprIter::IterText(v, _) => Box::new(v),
+                DataReprIter::IterData(v, _) => v,
+                v @ (DataReprIter::IterDataRef(_, _) | DataReprIter::IterIter(_)) => {
+                    #[cfg(debug_assertions)]
+                    warn!("Table::row_order - only works with Table::rows and Table::data");
+                    return (v, None);
+                }
+                v => return (v, None),
+            };
+            let rows = data.rows();
+            let order = if order.iter().all(|v| *v < rows) {
+                Cow::Borrowed(order)
+            } else {
+                #[cfg(debug_assertions)]
+                warn!("Table::row_order - rows out of range");
+                Cow::Owned(order.iter().copied().filter(|v| *v < rows).collect())
+            };
+            let used = order.to_vec();
+            (
+                DataReprIter::IterData(Box::new(OrderedData { data, order }), None),
+                Some(used),
+            )
+        }
+    }
+
+    impl<'a> DataReprIter<'a, '_> {
+        /// Random access to the summary style.
+        /// Not available for TableDataIter.
+        pub(super) fn row_summary_style(&self, row: usize) -> Option<Style> {
+            match self {
+                DataReprIter::None => None,
+                DataReprIter::Invalid(_) => None,
+                DataReprIter::IterText(v, _) => v.row_summary_style(row),
+                DataReprIter::IterData(v, _) => v.row_summary_style(row),
+                DataReprIter::IterDataRef(v, _) => v.row_summary_style(row),
+                DataReprIter::IterIter(_) => None,
+            }
+        }
+
+        /// Random access to the row height.
+        /// Not available for TableDataIter.
+        pub(super) fn row_height_at(&self, row: usize) -> Option<u16> {
+            match self {
+                DataReprIter::None => None,
+                DataReprIter::Invalid(_) => None,
+                DataReprIter::IterText(v, _) => Some(v.row_height(row)),
+                DataReprIter::IterData(v, _) => Some(v.row_height(row)),
+                DataReprIter::IterDataRef(v, _) => Some(v.row_height(row)),
+                DataReprIter::IterIter(_) => None,
+            }
+        }
+
+        /// Random access to the row details.
+        /// Not available for TableDataIter.
+        pub(super) fn render_detail(&self, row: usize, area: Rect, buf: &mut Buffer) {
+            match self {
+                DataReprIter::None => {}
+                DataReprIter::Invalid(_) => {}
+                DataReprIter::IterText(v, _) => v.render_detail(row, area, buf),
+                DataReprIter::IterData(v, _) => v.render_detail(row, area, buf),
+                DataReprIter::IterDataRef(v, _) => v.render_detail(row, area, buf),
+                DataReprIter::IterIter(_) => {}
+            }
+        }
+
+        /// Pre-formatted rows.
+        /// Not available for TableDataIter.
+        pub(super) fn format_rows(&self, rows: Range<usize>) -> Vec<FormattedRow<'a>> {
+            match self {
+                DataReprIter::None => Vec::new(),
+                DataReprIter::Invalid(_) => Vec::new(),
+                DataReprIter::IterText(v, _) => v.format_rows(rows),
+                DataReprIter::IterData(v, _) => v.format_rows(rows),
+                DataReprIter::IterDataRef(v, _) => v.format_rows(rows),
+                DataReprIter::IterIter(_) => Vec::new(),
+            }
+        }
+    }
+
     impl<'a> TableDataIter<'a> for DataReprIter<'a, '_> {
         fn rows(&self) -> Option<usize> {
             match self {
@@ -158,6 +427,24 @@ mod data {
             }
         }
 
+        fn nth_back(&mut self, n: usize) -> bool {
+            match self {
+                DataReprIter::IterIter(v) => v.nth_back(n),
+                _ => false,
+            }
+        }
+
+        fn version(&self) -> Option<u64> {
+            match self {
+                DataReprIter::None => None,
+                DataReprIter::Invalid(_) => None,
+                DataReprIter::IterText(_, _) => None,
+                DataReprIter::IterData(v, _) => v.version(),
+                DataReprIter::IterDataRef(v, _) => v.version(),
+                DataReprIter::IterIter(v) => v.version(),
+            }
+        }
+
         fn nth(&mut self, n: usize) -> bool {
             let incr = |row: &mut Option<usize>, rows: usize| match *row {
                 None => {
@@ -203,6 +490,39 @@ mod data {
             }
         }
 
+        fn row_progress(&self) -> Option<f32> {
+            match self {
+                DataReprIter::None => None,
+                DataReprIter::Invalid(_) => None,
+                DataReprIter::IterText(v, n) => v.row_progress(n.expect("row")),
+                DataReprIter::IterData(v, n) => v.row_progress(n.expect("row")),
+                DataReprIter::IterDataRef(v, n) => v.row_progress(n.expect("row")),
+                DataReprIter::IterIter(v) => v.row_progress(),
+            }
+        }
+
+        fn cell_text(&self, column: usize) -> Option<Cow<'_, str>> {
+            match self {
+                DataReprIter::None => None,
+                DataReprIter::Invalid(_) => None,
+                DataReprIter::IterText(v, n) => v.cell_text(column, n.expect("row")),
+                DataReprIter::IterData(v, n) => v.cell_text(column, n.expect("row")),
+                DataReprIter::IterDataRef(v, n) => v.cell_text(column, n.expect("row")),
+                DataReprIter::IterIter(v) => v.cell_text(column),
+            }
+        }
+
+        fn cell_badge(&self, column: usize) -> Option<Badge> {
+            match self {
+                DataReprIter::None => None,
+                DataReprIter::Invalid(_) => None,
+                DataReprIter::IterText(v, n) => v.cell_badge(column, n.expect("row")),
+                DataReprIter::IterData(v, n) => v.cell_badge(column, n.expect("row")),
+                DataReprIter::IterDataRef(v, n) => v.cell_badge(column, n.expect("row")),
+                DataReprIter::IterIter(v) => v.cell_badge(column),
+            }
+        }
+
         /// Render the cell given by column/row.
         fn render_cell(&self, ctx: &TableContext, column: usize, area: Rect, buf: &mut Buffer) {
             match self {
@@ -235,394 +555,399 @@ mod data {
     }
 }
 
-/// Combined style.
-#[derive(Debug)]
-pub struct TableStyle {
-    pub style: Style,
-    pub header: Option<Style>,
-    pub footer: Option<Style>,
+/// Indicator shown at the right edge of a header cell.
+///
+/// The table doesn't sort or filter by itself, this is only
+/// to get consistent indicators if the application does it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoration {
+    /// Sorted ascending.
+    SortAsc,
+    /// Sorted descending.
+    SortDesc,
+    /// Filtered.
+    Filtered,
+    /// Any other indicator.
+    Custom(char),
+}
 
-    pub select_row: Option<Style>,
-    pub select_column: Option<Style>,
-    pub select_cell: Option<Style>,
-    pub select_header: Option<Style>,
-    pub select_footer: Option<Style>,
+impl Decoration {
+    /// Symbol for the decoration.
+    pub fn symbol(&self) -> char {
+        match self {
+            Decoration::SortAsc => '▲',
+            Decoration::SortDesc => '▼',
+            Decoration::Filtered => '≡',
+            Decoration::Custom(c) => *c,
+        }
+    }
+}
 
-    pub show_row_focus: bool,
-    pub show_column_focus: bool,
-    pub show_cell_focus: bool,
-    pub show_header_focus: bool,
-    pub show_footer_focus: bool,
+/// Rule that produced the width of a column.
+///
+/// The column widths are resolved in this order, the first
+/// rule that applies wins:
+///
+/// 1. [Collapsed](WidthSource::Collapsed): the column is hidden with
+///    [TableState::collapsed_columns] and gets width 0.
+/// 2. [Override](WidthSource::Override): the column was resized with
+///    the mouse and uses the width from [TableState::width_overrides].
+/// 3. [Latched](WidthSource::Latched): [Table::latch_layout_width] keeps
+///    a layout width that is wider than the current one. Columns that
+///    can grow take up the extra space.
+/// 4. [Constraint](WidthSource::Constraint): the constraint given
+///    with [Table::widths].
+///
+/// See [TableState::width_sources].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WidthSource {
+    /// Column constraint.
+    #[default]
+    Constraint,
+    /// Latched layout width.
+    Latched,
+    /// Width set by resizing the column.
+    Override,
+    /// Hidden column.
+    Collapsed,
+}
 
-    pub focus_style: Option<Style>,
+/// Annotation for a cell.
+///
+/// See [TableData::cell_badge].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Badge {
+    /// Value has been changed.
+    Changed,
+    /// Value has an error.
+    Error,
+    /// There is a comment for the value.
+    Comment,
+    /// Any other annotation with its own glyph and style.
+    Custom(char, Style),
+}
 
-    pub block: Option<Block<'static>>,
-    pub border_style: Option<Style>,
-    pub scroll: Option<ScrollStyle>,
+impl Badge {
+    /// Glyph for the badge.
+    pub fn symbol(&self) -> char {
+        match self {
+            Badge::Changed => '•',
+            Badge::Error => '!',
+            Badge::Comment => '◥',
+            Badge::Custom(c, _) => *c,
+        }
+    }
 
-    pub non_exhaustive: NonExhaustive,
+    /// Default style for the badge.
+    pub fn style(&self) -> Style {
+        match self {
+            Badge::Changed => Style::new().yellow(),
+            Badge::Error => Style::new().red().bold(),
+            Badge::Comment => Style::new().cyan(),
+            Badge::Custom(_, style) => *style,
+        }
+    }
 }
 
-/// Table state.
-#[derive(Debug)]
-pub struct TableState<Selection> {
-    /// Current focus state.
-    pub focus: FocusFlag,
-
-    /// Total area.
-    pub area: Rect,
-    /// Area inside the border and scrollbars
-    pub inner: Rect,
+/// Row-number gutter.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RowNumbers {
+    /// No gutter.
+    #[default]
+    None,
+    /// Row number, starting with 1.
+    Absolute,
+    /// Distance from the selected row, like vim's `relativenumber`.
+    /// Shows the absolute row number if there is no selection.
+    Relative,
+}
 
-    /// Total header area.
-    pub header_area: Rect,
-    /// Total table area.
-    pub table_area: Rect,
-    /// Area per visible row. The first element is at row_offset.
-    pub row_areas: Vec<Rect>,
-    /// Area for each column plus the following spacer if any.
-    /// Invisible columns have width 0, height is the height of the table_area.
-    pub column_areas: Vec<Rect>,
-    /// Layout areas for each column plus the following spacer if any.
-    /// Positions are 0-based, y and height are 0.
-    pub column_layout: Vec<Rect>,
-    /// Total footer area.
-    pub footer_area: Rect,
+/// Placement of the detail pane.
+///
+/// The size is given in cells and includes the detail block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DetailPane {
+    /// No detail pane.
+    #[default]
+    None,
+    /// Right of the table with the given width.
+    Right(u16),
+    /// Below the table with the given height.
+    Bottom(u16),
+}
 
-    /// Row count.
-    pub rows: usize,
-    // debug info
-    pub _counted_rows: usize,
-    /// Column count.
-    pub columns: usize,
+/// Rows scrolled per mouse wheel event.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WheelScroll {
+    /// Whatever the scrollbar uses, 1/10 of the page by default.
+    #[default]
+    Default,
+    /// A fixed number of rows.
+    Rows(usize),
+    /// A percentage of the page.
+    PagePercent(u16),
+}
 
-    /// Row scrolling data.
-    pub vscroll: ScrollState,
-    /// Column scrolling data.
-    pub hscroll: ScrollState,
+/// Order in which row, cell and selection style are patched
+/// together. The style patched last wins.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PatchOrder {
+    /// Row, cell, selection. The selection always shows.
+    #[default]
+    SelectLast,
+    /// Row, selection, cell. Cell styles show in selected rows.
+    CellLast,
+    /// Cell, selection, row. Row styles show in selected rows.
+    RowLast,
+}
 
-    /// Selection data.
-    pub selection: Selection,
+/// Distance for PageUp/PageDown.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PageScroll {
+    /// A full page.
+    #[default]
+    Page,
+    /// Half a page.
+    HalfPage,
+    /// A fixed number of rows.
+    Rows(usize),
+}
 
-    /// Helper for mouse interactions.
-    pub mouse: MouseFlags,
+/// Numeric count prefix for keyboard motions.
+///
+/// Collects the digits typed before a motion key ("17j").
+/// The count is dropped if the motion doesn't follow within
+/// the timeout, or another key is pressed. Mouse events keep it.
+#[derive(Debug, Clone)]
+pub struct CountPrefix {
+    /// Count collected so far. 0 if none.
+    pub count: usize,
+    /// Time of the last digit.
+    pub last: Option<Instant>,
+    /// Timeout between digits and the motion.
+    pub timeout: Duration,
 
     pub non_exhaustive: NonExhaustive,
 }
 
-impl<Selection> Default for Table<'_, Selection> {
+impl Default for CountPrefix {
     fn default() -> Self {
         Self {
-            data: Default::default(),
-            no_row_count: Default::default(),
-            header: Default::default(),
-            footer: Default::default(),
-            widths: Default::default(),
-            flex: Default::default(),
-            column_spacing: Default::default(),
-            layout_width: Default::default(),
-            auto_layout_width: Default::default(),
-            block: Default::default(),
-            hscroll: Default::default(),
-            vscroll: Default::default(),
-            header_style: Default::default(),
-            footer_style: Default::default(),
-            style: Default::default(),
-            select_row_style: Default::default(),
-            show_row_focus: true,
-            select_column_style: Default::default(),
-            show_column_focus: Default::default(),
-            select_cell_style: Default::default(),
-            show_cell_focus: Default::default(),
-            select_header_style: Default::default(),
-            show_header_focus: Default::default(),
-            select_footer_style: Default::default(),
-            show_footer_focus: Default::default(),
-            focus_style: Default::default(),
-            debug: Default::default(),
-            _phantom: Default::default(),
+            count: 0,
+            last: None,
+            timeout: Duration::from_millis(1500),
+            non_exhaustive: NonExhaustive,
         }
     }
 }
 
-impl<'a, Selection> Table<'a, Selection> {
-    /// New, empty Table.
-    pub fn new() -> Self
-    where
-        Selection: Default,
-    {
-        Self::default()
-    }
-
-    /// Create a new Table with preformatted data. For compatibility
-    /// with ratatui.
+impl CountPrefix {
+    /// Add a digit to the count.
     ///
-    /// Use of [Table::data] is preferred.
-    pub fn new_ratatui<R, C>(rows: R, widths: C) -> Self
-    where
-        R: IntoIterator,
-        R::Item: Into<Row<'a>>,
-        C: IntoIterator,
-        C::Item: Into<Constraint>,
-        Selection: Default,
-    {
-        let widths = widths.into_iter().map(|v| v.into()).collect::<Vec<_>>();
-        let data = TextTableData {
-            rows: rows.into_iter().map(|v| v.into()).collect(),
+    /// A leading '0' is not a digit of the count, this returns false.
+    pub fn push_digit(&mut self, digit: char) -> bool {
+        let Some(d) = digit.to_digit(10) else {
+            return false;
         };
-        Self {
-            data: DataRepr::Text(data),
-            widths,
-            ..Default::default()
+        if self.pending().is_none() {
+            self.count = 0;
+            if d == 0 {
+                return false;
+            }
         }
+        self.count = self.count.saturating_mul(10).saturating_add(d as usize);
+        self.last = Some(Instant::now());
+        true
     }
 
-    /// Set preformatted row-data. For compatibility with ratatui.
-    ///
-    /// Use of [Table::data] is preferred.
-    pub fn rows<T>(mut self, rows: T) -> Self
-    where
-        T: IntoIterator<Item = Row<'a>>,
-    {
-        let rows = rows.into_iter().collect();
-        self.data = DataRepr::Text(TextTableData { rows });
-        self
+    /// Current count if any and not timed out.
+    pub fn pending(&self) -> Option<usize> {
+        match self.last {
+            Some(last) if self.count > 0 && last.elapsed() <= self.timeout => Some(self.count),
+            _ => None,
+        }
     }
 
-    /// Set a reference to the TableData facade to your data.
+    /// Take the count and reset. Returns 1 if there is no count.
     ///
-    /// The way to go is to define a small struct that contains just a
-    /// reference to your data. Then implement TableData for this struct.
-    ///
-    /// ```rust
-    /// use ratatui::buffer::Buffer;
-    /// use ratatui::layout::Rect;
-    /// use ratatui::prelude::Style;
-    /// use ratatui::text::Span;
-    /// use ratatui::widgets::{StatefulWidget, Widget};
-    /// use rat_ftable::{Table, TableContext, TableState, TableData};    ///
-    /// #
-    /// use rat_ftable::selection::RowSelection;
-    ///
-    /// struct SampleRow;
-    /// # impl Clone for SampleRow {
-    /// #     fn clone(&self) -> Self {
-    /// #        SampleRow
-    /// #     }
-    /// # }
-    /// # let area = Rect::default();
-    /// # let mut buf = Buffer::empty(area);
-    /// # let buf = &mut buf;
-    ///
-    /// struct Data1<'a>(&'a [SampleRow]);
-    ///
-    /// impl<'a> TableData<'a> for Data1<'a> {
-    ///     fn rows(&self) -> usize {
-    ///         self.0.len()
-    ///     }
-    ///
-    ///     fn row_height(&self, row: usize) -> u16 {
-    ///         // to some calculations ...
-    ///         1
-    ///     }
-    ///
-    ///     fn row_style(&self, row: usize) -> Option<Style> {
-    ///         // to some calculations ...
-    ///         None
-    ///     }
-    ///
-    ///     fn render_cell(&self, ctx: &TableContext, column: usize, row: usize, area: Rect, buf: &mut Buffer) {
-    ///         if let Some(data) = self.0.get(row) {
-    ///             let rend = match column {
-    ///                 0 => Span::from("column1"),
-    ///                 1 => Span::from("column2"),
-    ///                 2 => Span::from("column3"),
-    ///                 _ => return
-    ///             };
-    ///             rend.render(area, buf);
-    ///         }
-    ///     }
-    /// }
-    ///
-    /// // When you are creating the table widget you hand over a reference
-    /// // to the facade struct.
-    ///
-    /// let my_data_somewhere_else = vec![SampleRow;999999];
-    /// let mut table_state_somewhere_else = TableState::<RowSelection>::default();
-    ///
-    /// // ...
-    ///
-    /// let table1 = Table::default().data(Data1(&my_data_somewhere_else));
-    /// table1.render(area, buf, &mut table_state_somewhere_else);
-    /// ```
-    #[inline]
-    pub fn data(mut self, data: impl TableData<'a> + 'a) -> Self {
-        self.widths = data.widths();
-        self.header = data.header();
-        self.footer = data.footer();
-        self.data = DataRepr::Data(Box::new(data));
-        self
+    /// The count is limited to maximum, but is at least 1.
+    pub fn take(&mut self, maximum: usize) -> usize {
+        let count = min(self.pending().unwrap_or(1), max(maximum, 1));
+        self.clear();
+        count
     }
 
-    ///
-    /// Alternative representation for the data as a kind of Iterator.
-    /// It uses interior iteration, which fits quite nice for this and
-    /// avoids handing out lifetime bound results of the actual iterator.
-    /// Which is a bit nightmarish to get right.
-    ///
-    ///
-    /// Caution: If you can't give the number of rows, the table will iterate over all
-    /// the data. See [Table::no_row_count].
-    ///
-    /// ```rust
-    /// use std::iter::{Enumerate};
-    /// use std::slice::Iter;
-    /// use format_num_pattern::NumberFormat;
-    /// use ratatui::buffer::Buffer;
-    /// use ratatui::layout::{Constraint, Rect};
-    /// use ratatui::prelude::Color;
-    /// use ratatui::style::{Style, Stylize};
-    /// use ratatui::text::Span;
-    /// use ratatui::widgets::{Widget, StatefulWidget};
-    /// use rat_ftable::{Table, TableContext, TableState, TableDataIter};
-    /// use rat_ftable::selection::RowSelection;
-    ///
-    /// struct Data {
-    /// #     table_data: Vec<Sample>
-    /// # }
-    /// #
-    /// # struct Sample {
-    /// #     pub text: String
-    /// # }
-    /// #
-    /// # let data = Data {
-    /// #     table_data: vec![],
-    /// # };
-    /// # let area = Rect::default();
-    /// # let mut buf = Buffer::empty(area);
-    /// # let buf = &mut buf;
-    ///
-    /// struct RowIter1<'a> {
-    ///     iter: Enumerate<Iter<'a, Sample>>,
-    ///     item: Option<(usize, &'a Sample)>,
-    /// }
-    ///
-    /// impl<'a> TableDataIter<'a> for RowIter1<'a> {
-    ///     fn rows(&self) -> Option<usize> {
-    ///         // If you can, give the length. Otherwise,
-    ///         // the table will iterate all to find out a length.
-    ///         None
-    ///         // Some(100_000)
-    ///     }
-    ///
-    ///     /// Select the nth element from the current position.
-    ///     fn nth(&mut self, n: usize) -> bool {
-    ///         self.item = self.iter.nth(n);
-    ///         self.item.is_some()
-    ///     }
-    ///
-    ///     /// Row height.
-    ///     fn row_height(&self) -> u16 {
-    ///         1
-    ///     }
-    ///
-    ///     /// Row style.
-    ///     fn row_style(&self) -> Option<Style> {
-    ///         Some(Style::default())
-    ///     }
-    ///
-    ///     /// Render one cell.
-    ///     fn render_cell(&self,
-    ///                     ctx: &TableContext,
-    ///                     column: usize,
-    ///                     area: Rect,
-    ///                     buf: &mut Buffer)
-    ///     {
-    ///         let row = self.item.expect("data");
-    ///         match column {
-    ///             0 => {
-    ///                 let row_fmt = NumberFormat::new("000000").expect("fmt");
-    ///                 let span = Span::from(row_fmt.fmt_u(row.0));
-    ///                 buf.set_style(area, Style::new().black().bg(Color::from_u32(0xe7c787)));
-    ///                 span.render(area, buf);
-    ///             }
-    ///             1 => {
-    ///                 let span = Span::from(&row.1.text);
-    ///                 span.render(area, buf);
-    ///             }
-    ///             _ => {}
-    ///         }
-    ///     }
-    /// }
-    ///
-    /// let mut rit = RowIter1 {
-    ///     iter: data.table_data.iter().enumerate(),
-    ///     item: None,
-    /// };
-    ///
-    /// let table1 = Table::default()
-    ///     .iter(rit)
-    ///     .widths([
-    ///         Constraint::Length(6),
-    ///         Constraint::Length(20)
-    ///     ]);
-    ///
-    /// let mut table_state_somewhere_else = TableState::<RowSelection>::default();
-    ///
-    /// table1.render(area, buf, &mut table_state_somewhere_else);
-    /// ```
-    ///
-    #[inline]
-    pub fn iter(mut self, data: impl TableDataIter<'a> + 'a) -> Self {
-        #[cfg(debug_assertions)]
-        if data.rows().is_none() {
-            use log::warn;
-            warn!("Table::iter - rows is None, this will be slower");
+    /// Drop the count.
+    pub fn clear(&mut self) {
+        self.count = 0;
+        self.last = None;
+    }
+}
+
+/// History of selection jumps.
+///
+/// Works like the back/forward buttons of a browser.
+/// Jumping somewhere records the row you came from,
+/// going back and forward moves along the history.
+#[derive(Debug, Clone)]
+pub struct JumpList {
+    /// Recorded rows.
+    pub entries: Vec<usize>,
+    /// Current position in the history. Equals entries.len()
+    /// if not navigating the history.
+    pub pos: usize,
+    /// Maximum number of entries.
+    pub limit: usize,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for JumpList {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+            pos: 0,
+            limit: 100,
+            non_exhaustive: NonExhaustive,
         }
-        self.header = data.header();
-        self.footer = data.footer();
-        self.widths = data.widths();
-        self.data = DataRepr::Iter(Box::new(data));
-        self
     }
+}
 
-    /// If you work with an TableDataIter to fill the table, and
-    /// if you don't return a count with rows(), Table will run
-    /// through all your iterator to find the actual number of rows.
-    ///
-    /// This may take its time.
-    ///
-    /// If you set no_row_count(true), this part will be skipped, and
-    /// the row count will be set to an estimate of usize::MAX.
-    /// This will destroy your ability to jump to the end of the data,
-    /// but otherwise it's fine.
-    /// You can still page-down through the data, and if you ever
-    /// reach the end, the correct row-count can be established.
+impl JumpList {
+    /// Record the row before jumping away.
+    /// This drops any forward history.
+    pub fn record(&mut self, row: usize) {
+        self.entries.truncate(self.pos);
+        if self.entries.last() != Some(&row) {
+            self.entries.push(row);
+        }
+        if self.entries.len() > self.limit {
+            let n = self.entries.len() - self.limit;
+            self.entries.drain(0..n);
+        }
+        self.pos = self.entries.len();
+    }
+
+    /// Go back in the history.
     ///
-    /// _Extra info_: This might be only useful if you have a LOT of data.
-    /// In my test it changed from 1.5ms to 150µs for about 100.000 rows.
-    /// And 1.5ms is still not that much ... so you probably want to
-    /// test without this first and then decide.
-    pub fn no_row_count(mut self, no_row_count: bool) -> Self {
-        self.no_row_count = no_row_count;
-        self
+    /// current is the current row, it is recorded when leaving
+    /// the end of the history so forward can return to it.
+    pub fn back(&mut self, current: usize) -> Option<usize> {
+        if self.pos == 0 {
+            return None;
+        }
+        if self.pos == self.entries.len() && self.entries.last() != Some(&current) {
+            self.entries.push(current);
+        }
+        self.pos -= 1;
+        self.entries.get(self.pos).copied()
     }
 
-    /// Set the table-header.
-    #[inline]
+    /// Go forward in the history.
+    pub fn forward(&mut self) -> Option<usize> {
+        if self.pos + 1 >= self.entries.len() {
+            return None;
+        }
+        self.pos += 1;
+        self.entries.get(self.pos).copied()
+    }
+
+    /// Clear the history.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.pos = 0;
+    }
+}
+
+/// Viewport metrics of the table.
+///
+/// See [TableState::viewport].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// First visible row.
+    pub first_row: usize,
+    /// Last visible row, even if it is only partially visible.
+    pub last_row: usize,
+    /// Visible rows.
+    pub visible_rows: usize,
+    /// Fraction of the first row that is visible.
+    pub first_row_fraction: f32,
+    /// Fraction of the last row that is visible.
+    pub last_row_fraction: f32,
+    /// Vertical scroll position as ratio 0.0..=1.0.
+    pub vertical_ratio: f32,
+    /// Horizontal scroll position as ratio 0.0..=1.0.
+    pub horizontal_ratio: f32,
+    /// Fraction of all rows that is visible.
+    pub visible_ratio: f32,
+}
+
+/// Top half of a render split across two frames.
+///
+/// See [Table::progressive].
+#[derive(Debug, Clone)]
+pub struct RenderContinuation {
+    key: u64,
+    badges: Vec<(usize, usize)>,
+    top: Buffer,
+    pending: bool,
+}
+
+/// Header, footer and column widths of a table.
+///
+/// See [Table::iter_with_schema].
+#[derive(Debug, Clone)]
+pub struct TableSchema<'a> {
+    /// Header row.
+    pub header: Option<Row<'a>>,
+    /// Footer row.
+    pub footer: Option<Row<'a>>,
+    /// Column constraints.
+    pub widths: Vec<Constraint>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for TableSchema<'_> {
+    fn default() -> Self {
+        Self {
+            header: Default::default(),
+            footer: Default::default(),
+            widths: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<'a> TableSchema<'a> {
+    /// New schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take header, footer and widths from the iterator.
+    pub fn from_data_iter(data: &dyn TableDataIter<'a>) -> Self {
+        Self {
+            header: data.header(),
+            footer: data.footer(),
+            widths: data.widths(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+
+    /// Header row.
     pub fn header(mut self, header: Row<'a>) -> Self {
         self.header = Some(header);
         self
     }
 
-    /// Set the table-footer.
-    #[inline]
+    /// Footer row.
     pub fn footer(mut self, footer: Row<'a>) -> Self {
         self.footer = Some(footer);
         self
     }
 
-    /// Column widths as Constraints.
+    /// Column constraints.
     pub fn widths<I>(mut self, widths: I) -> Self
     where
         I: IntoIterator,
@@ -631,241 +956,1896 @@ impl<'a, Selection> Table<'a, Selection> {
         self.widths = widths.into_iter().map(|v| v.into()).collect();
         self
     }
+}
 
-    /// Flex for layout.
-    #[inline]
-    pub fn flex(mut self, flex: Flex) -> Self {
-        self.flex = flex;
-        self
-    }
+/// Behaviour when the table gains the focus.
+///
+/// See [TableState::on_focus_gained].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FocusGained {
+    /// Do nothing.
+    #[default]
+    Nothing,
+    /// Scroll the selection into view.
+    ScrollToSelection,
+    /// Select the first visible row if nothing is selected,
+    /// and scroll the selection into view.
+    SelectFirstVisible,
+}
 
-    /// Spacing between columns.
-    #[inline]
-    pub fn column_spacing(mut self, spacing: u16) -> Self {
-        self.column_spacing = spacing;
-        self
-    }
+/// Initial position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StartAt {
+    Row(usize),
+    End,
+}
 
-    /// Overrides the width of the rendering area for layout purposes.
-    /// Layout uses this width, even if it means that some columns are
-    /// not visible.
-    #[inline]
-    pub fn layout_width(mut self, width: u16) -> Self {
-        self.layout_width = Some(width);
-        self
-    }
+/// Failed cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellError {
+    /// Column.
+    pub column: usize,
+    /// Row.
+    pub row: usize,
+    /// Panic message.
+    pub message: String,
+}
 
-    /// Calculates the width from the given column-constraints.
-    /// If a fixed layout_width() is set too, that one will win.
-    ///
-    /// Panic:
-    /// Rendering will panic, if any constraint other than Constraint::Length(),
-    /// Constraint::Min() or Constraint::Max() is used.
-    #[inline]
-    pub fn auto_layout_width(mut self, auto: bool) -> Self {
-        self.auto_layout_width = auto;
-        self
-    }
+/// Misconfiguration of the table.
+///
+/// See [Table::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// A row has more cells than there are column widths.
+    /// The extra cells are not rendered.
+    ColumnCount {
+        /// Number of column widths.
+        widths: usize,
+        /// Number of cells.
+        columns: usize,
+    },
+    /// A select style is set, but the selection model never
+    /// selects that part of the table. Contains the name of
+    /// the style.
+    SelectStyle(&'static str),
+    /// auto_layout_width only supports Length, Min and Max
+    /// constraints. Contains the column.
+    AutoLayoutWidth(usize),
+}
 
-    /// Draws a block around the table widget.
-    #[inline]
-    pub fn block(mut self, block: Block<'a>) -> Self {
-        self.block = Some(block);
-        self.block = self.block.map(|v| v.style(self.style));
-        self
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::ColumnCount { widths, columns } => write!(
+                f,
+                "{} cells but only {} column widths, extra cells are not rendered",
+                columns, widths
+            ),
+            ConfigError::SelectStyle(style) => write!(
+                f,
+                "{} is set, but the selection model doesn't use it",
+                style
+            ),
+            ConfigError::AutoLayoutWidth(column) => write!(
+                f,
+                "auto_layout_width needs Length, Min or Max constraints, column {} has another one",
+                column
+            ),
+        }
     }
+}
 
-    /// Scrollbars
-    pub fn scroll(mut self, scroll: Scroll<'a>) -> Self {
-        self.hscroll = Some(scroll.clone().override_horizontal());
-        self.vscroll = Some(scroll.override_vertical());
-        self
-    }
+impl Error for ConfigError {}
 
-    /// Scrollbars
-    pub fn hscroll(mut self, scroll: Scroll<'a>) -> Self {
-        self.hscroll = Some(scroll.override_horizontal());
-        self
-    }
+/// Problems found while rendering.
+///
+/// See [Table::catch_cell_panics].
+#[derive(Debug, Clone)]
+pub struct RenderDiagnostics {
+    /// Cells where render_cell panicked.
+    pub errors: Vec<CellError>,
 
-    /// Scrollbars
-    pub fn vscroll(mut self, scroll: Scroll<'a>) -> Self {
-        self.vscroll = Some(scroll.override_vertical());
-        self
-    }
+    pub non_exhaustive: NonExhaustive,
+}
 
-    /// Set all styles as a bundle.
-    #[inline]
-    pub fn styles(mut self, styles: TableStyle) -> Self {
-        self.style = styles.style;
-        if styles.header.is_some() {
-            self.header_style = styles.header;
-        }
-        if styles.footer.is_some() {
-            self.footer_style = styles.footer;
-        }
-        if styles.select_row.is_some() {
-            self.select_row_style = styles.select_row;
-        }
-        self.show_row_focus = styles.show_row_focus;
-        if styles.select_column.is_some() {
-            self.select_column_style = styles.select_column;
-        }
-        self.show_column_focus = styles.show_column_focus;
-        if styles.select_cell.is_some() {
-            self.select_cell_style = styles.select_cell;
-        }
-        self.show_cell_focus = styles.show_cell_focus;
-        if styles.select_header.is_some() {
-            self.select_header_style = styles.select_header;
-        }
-        self.show_header_focus = styles.show_header_focus;
-        if styles.select_footer.is_some() {
-            self.select_footer_style = styles.select_footer;
-        }
-        self.show_footer_focus = styles.show_footer_focus;
-        if styles.focus_style.is_some() {
-            self.focus_style = styles.focus_style;
-        }
-        // TODO: add border_style for other XXStyles too.
-        if let Some(border_style) = styles.border_style {
-            self.block = self.block.map(|v| v.border_style(border_style));
-        }
-        self.block = self.block.map(|v| v.style(self.style));
-        if styles.block.is_some() {
-            self.block = styles.block;
-        }
-        if let Some(styles) = styles.scroll {
-            self.hscroll = self.hscroll.map(|v| v.styles(styles.clone()));
-            self.vscroll = self.vscroll.map(|v| v.styles(styles));
+impl Default for RenderDiagnostics {
+    fn default() -> Self {
+        Self {
+            errors: Default::default(),
+            non_exhaustive: NonExhaustive,
         }
-        self
     }
+}
 
-    /// Base style for the table.
-    #[inline]
-    pub fn style(mut self, style: Style) -> Self {
-        self.style = style;
-        self.block = self.block.map(|v| v.style(self.style));
-        self
+impl RenderDiagnostics {
+    /// Any errors?
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
     }
 
-    /// Base style for the table.
-    #[inline]
-    pub fn header_style(mut self, style: Option<Style>) -> Self {
-        self.header_style = style;
-        self
+    /// Remove all errors.
+    pub fn clear(&mut self) {
+        self.errors.clear();
     }
+}
 
-    /// Base style for the table.
-    #[inline]
-    pub fn footer_style(mut self, style: Option<Style>) -> Self {
-        self.footer_style = style;
-        self
+// Collapsed scrollbar as minimized scrollbar.
+fn reserve_scrollbar(scroll: Scroll<'_>) -> Scroll<'_> {
+    if scroll.get_policy() == ScrollbarPolicy::Collapse {
+        scroll.policy(ScrollbarPolicy::Minimize)
+    } else {
+        scroll
     }
+}
 
-    /// Style for a selected row. The chosen selection must support
-    /// row-selection for this to take effect.
-    #[inline]
-    pub fn select_row_style(mut self, select_style: Option<Style>) -> Self {
-        self.select_row_style = select_style;
-        self
-    }
+// Area covered by a cell spanning some columns.
+fn span_area(l_columns: &[Rect], col: usize, span: usize, height: u16) -> Rect {
+    let last = min(col + max(1, span), l_columns.len()) - 1;
+    let x = l_columns[col].x;
+    Rect::new(x, 0, l_columns[last].right() - x, height)
+}
 
-    /// Add the focus-style to the row-style if the table is focused.
-    #[inline]
-    pub fn show_row_focus(mut self, show: bool) -> Self {
-        self.show_row_focus = show;
-        self
-    }
+/// Layout settings as a bundle.
+///
+/// There are some presets for common looks. Use them with
+/// [Table::layout].
+///
+/// ```rust
+/// use rat_ftable::selection::RowSelection;
+/// use rat_ftable::{Table, TableLayout};
+///
+/// let table: Table<RowSelection> = Table::new().layout(TableLayout::spreadsheet());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableLayout {
+    /// See [Table::flex].
+    pub flex: Flex,
+    /// See [Table::column_spacing].
+    pub column_spacing: u16,
+    /// See [Table::fill_space].
+    pub fill_space: bool,
+    /// Column separators in the header.
+    /// See [Table::resize_handles].
+    pub resize_handles: bool,
+    /// See [Table::wrap_header].
+    pub wrap_header: bool,
 
-    /// Style for a selected column. The chosen selection must support
-    /// column-selection for this to take effect.
-    #[inline]
-    pub fn select_column_style(mut self, select_style: Option<Style>) -> Self {
-        self.select_column_style = select_style;
-        self
-    }
+    pub non_exhaustive: NonExhaustive,
+}
 
-    /// Add the focus-style to the column-style if the table is focused.
-    #[inline]
-    pub fn show_column_focus(mut self, show: bool) -> Self {
-        self.show_column_focus = show;
-        self
+impl Default for TableLayout {
+    fn default() -> Self {
+        Self {
+            flex: Default::default(),
+            column_spacing: 0,
+            fill_space: true,
+            resize_handles: false,
+            wrap_header: false,
+            non_exhaustive: NonExhaustive,
+        }
     }
+}
 
-    /// Style for a selected cell. The chosen selection must support
-    /// cell-selection for this to take effect.
-    #[inline]
-    pub fn select_cell_style(mut self, select_style: Option<Style>) -> Self {
-        self.select_cell_style = select_style;
-        self
+impl TableLayout {
+    /// Columns packed to the left with a single space between.
+    pub fn compact() -> Self {
+        Self {
+            flex: Flex::Start,
+            column_spacing: 1,
+            ..Default::default()
+        }
     }
 
-    /// Add the focus-style to the cell-style if the table is focused.
-    #[inline]
-    pub fn show_cell_focus(mut self, show: bool) -> Self {
-        self.show_cell_focus = show;
-        self
+    /// Columns packed to the left with separators in the header
+    /// that can be dragged to resize the columns.
+    pub fn spreadsheet() -> Self {
+        Self {
+            flex: Flex::Start,
+            column_spacing: 1,
+            resize_handles: true,
+            ..Default::default()
+        }
     }
 
-    /// Style for a selected header cell. The chosen selection must
-    /// support column-selection for this to take effect.
-    #[inline]
-    pub fn select_header_style(mut self, select_style: Option<Style>) -> Self {
-        self.select_header_style = select_style;
-        self
+    /// Columns spread over the full width with generous spacing
+    /// and wrapped header titles.
+    pub fn airy() -> Self {
+        Self {
+            flex: Flex::SpaceBetween,
+            column_spacing: 3,
+            wrap_header: true,
+            ..Default::default()
+        }
     }
+}
 
-    /// Add the focus-style to the header-style if the table is focused.
-    #[inline]
-    pub fn show_header_focus(mut self, show: bool) -> Self {
-        self.show_header_focus = show;
-        self
-    }
+/// Combined style.
+#[derive(Debug)]
+pub struct TableStyle {
+    pub style: Style,
+    pub header: Option<Style>,
+    pub footer: Option<Style>,
 
-    /// Style for a selected footer cell. The chosen selection must
-    /// support column-selection for this to take effect.
-    #[inline]
-    pub fn select_footer_style(mut self, select_style: Option<Style>) -> Self {
-        self.select_footer_style = select_style;
-        self
-    }
+    pub select_row: Option<Style>,
+    pub select_column: Option<Style>,
+    pub select_cell: Option<Style>,
+    pub select_header: Option<Style>,
+    pub select_footer: Option<Style>,
 
-    /// Add the footer-style to the table-style if the table is focused.
-    #[inline]
-    pub fn show_footer_focus(mut self, show: bool) -> Self {
-        self.show_footer_focus = show;
-        self
-    }
+    pub show_row_focus: bool,
+    pub show_column_focus: bool,
+    pub show_cell_focus: bool,
+    pub show_header_focus: bool,
+    pub show_footer_focus: bool,
 
-    /// This style will be patched onto the selection to indicate that
-    /// the widget has the input focus.
-    ///
-    /// The selection must support some kind of selection for this to
-    /// be effective.
-    #[inline]
-    pub fn focus_style(mut self, focus_style: Option<Style>) -> Self {
-        self.focus_style = focus_style;
-        self
-    }
+    pub focus_style: Option<Style>,
+    pub highlight: Option<Style>,
+    pub overflow: Option<Style>,
+    pub decoration: Option<Style>,
+    pub row_number: Option<Style>,
+    pub minimap: Option<Style>,
+    pub app_gutter: Option<Style>,
+    pub progress: Option<Style>,
+    pub hover: Option<Style>,
+    pub indicator: Option<Style>,
+    pub column_group: Option<Style>,
+    pub annotation: Option<Style>,
+    pub resize_handle: Option<Style>,
+    pub resize_handle_hover: Option<Style>,
+    pub peek: Option<Style>,
+    pub drag: Option<Style>,
+    pub badge: Option<Style>,
 
-    /// Just some utility to help with debugging. Usually does nothing.
-    pub fn debug(mut self, debug: bool) -> Self {
-        self.debug = debug;
-        self
-    }
+    pub block: Option<Block<'static>>,
+    pub border_style: Option<Style>,
+    pub scroll: Option<ScrollStyle>,
+
+    pub non_exhaustive: NonExhaustive,
 }
 
-impl<Selection> Table<'_, Selection> {
-    // area_width or layout_width
-    #[inline]
-    fn total_width(&self, area_width: u16) -> u16 {
-        if let Some(layout_width) = self.layout_width {
-            layout_width
-        } else if self.auto_layout_width {
+/// Table state.
+#[derive(Debug)]
+pub struct TableState<Selection> {
+    /// Current focus state.
+    pub focus: FocusFlag,
+
+    /// Total area.
+    pub area: Rect,
+    /// Area inside the border and scrollbars
+    pub inner: Rect,
+
+    /// Total header area.
+    pub header_area: Rect,
+    /// Total table area.
+    pub table_area: Rect,
+    /// Area per visible row. The first element is at row_offset.
+    pub row_areas: Vec<Rect>,
+    /// Full height of each visible row. The last row may be
+    /// clipped in row_areas.
+    pub row_heights: Vec<u16>,
+    /// Area for each column plus the following spacer if any.
+    /// Invisible columns have width 0, height is the height of the table_area.
+    pub column_areas: Vec<Rect>,
+    /// Layout areas for each column plus the following spacer if any.
+    /// Positions are 0-based, y and height are 0.
+    pub column_layout: Vec<Rect>,
+    /// Width of each column without the spacer.
+    pub column_widths: Vec<u16>,
+    /// Rule that produced the width of each column.
+    /// See [WidthSource] for the order in which they apply.
+    /// __readonly__. renewed for each render.
+    pub width_sources: Vec<WidthSource>,
+    /// Total footer area.
+    pub footer_area: Rect,
+
+    /// Row count.
+    pub rows: usize,
+    // debug info
+    pub _counted_rows: usize,
+    /// Column count.
+    pub columns: usize,
+
+    /// Row scrolling data.
+    pub vscroll: ScrollState,
+    /// Column scrolling data.
+    pub hscroll: ScrollState,
+
+    /// Selection data.
+    pub selection: Selection,
+
+    /// Number of columns completely hidden beyond the right edge.
+    /// __readonly__. renewed for each render.
+    pub hidden_columns: usize,
+    /// Area of the overflow indicator, if any.
+    /// __readonly__. renewed for each render.
+    pub overflow_area: Rect,
+
+    /// Resize handles are active.
+    /// __readonly__. renewed for each render.
+    pub resize_handles: bool,
+    /// Screen position of the resize handle after each column.
+    /// This is the first cell of the spacer or the last cell of the column
+    /// if there is no spacing. Hidden columns have no handle.
+    /// __readonly__. renewed for each render.
+    pub resize_handle_pos: Vec<Option<u16>>,
+    /// Resize handle currently hovered.
+    pub hover_handle: Option<usize>,
+    /// Column currently resized by dragging the handle.
+    pub resize_drag: Option<usize>,
+    /// Widths set by resizing columns with the mouse.
+    /// These override the constraints given to the table.
+    pub width_overrides: HashMap<usize, u16>,
+
+    /// Area of the row-number gutter.
+    /// __readonly__. renewed for each render.
+    pub gutter_area: Rect,
+
+    /// Pending count prefix for the [Vim](crate::event::Vim) keybindings.
+    pub count_prefix: CountPrefix,
+
+    /// History of selection jumps for back/forward navigation.
+    pub jumps: JumpList,
+
+    /// Area of the minimap.
+    /// __readonly__. renewed for each render.
+    pub minimap_area: Rect,
+
+    /// Row under the mouse. Only tracked with the
+    /// [Activate](crate::event::Activate) keybindings.
+    pub hover_row: Option<usize>,
+
+    /// Widest layout width seen so far, if
+    /// [Table::latch_layout_width] is set.
+    /// Reset with [TableState::reset_latched_width].
+    pub latched_width: u16,
+
+    /// Rows counted so far, if [Table::row_count_budget] is set and
+    /// counting is not finished yet. Counting continues with the next
+    /// render. Set to None to start over.
+    pub counted_rows: Option<usize>,
+
+    /// Problems found during the last render.
+    /// __readonly__. renewed for each render.
+    pub diagnostics: RenderDiagnostics,
+
+    /// Area of the gutter reserved with [Table::app_gutter].
+    /// __readonly__. renewed for each render.
+    pub app_gutter_area: Rect,
+
+    /// Gutter area per visible row. The first element is at row_offset.
+    /// Render your decorations here after rendering the table.
+    /// __readonly__. renewed for each render.
+    pub app_gutter_areas: Vec<Rect>,
+
+    /// The table has been rendered at least once.
+    /// Used for [Table::start_at_row] and [Table::start_at_end].
+    pub rendered: bool,
+
+    /// Behaviour when the table gains the focus.
+    pub on_focus_gained: FocusGained,
+
+    /// on_focus_gained has been applied for the current focus change.
+    pub focus_gained_done: bool,
+
+    /// Cell (column, row) shown in the peek popup.
+    pub peek: Option<(usize, usize)>,
+
+    /// Scroll offset of the peek popup in lines.
+    pub peek_offset: usize,
+
+    /// Number of wrapped lines in the peek popup.
+    /// __readonly__. renewed for each render.
+    pub peek_lines: usize,
+
+    /// Area of the peek popup.
+    /// __readonly__. renewed for each render.
+    pub peek_area: Rect,
+
+    /// Area of the detail pane. Empty if there is no selection.
+    /// __readonly__. renewed for each render.
+    pub detail_area: Rect,
+
+    /// Columns hidden by the user, e.g. with the
+    /// [ColumnChooser](crate::chooser::ColumnChooser).
+    /// They are laid out with width 0 and without spacing.
+    pub collapsed_columns: HashSet<usize>,
+
+    /// Coalesce mouse-wheel scrolling.
+    ///
+    /// The scroll amount is collected and applied once with the
+    /// next render. Only the first event returns Outcome::Changed,
+    /// the following ones return Outcome::Unchanged until the
+    /// next render. This helps if the events arrive faster than
+    /// the table can be rendered, e.g. over a slow connection.
+    pub scroll_coalesce: bool,
+
+    /// Collected (horizontal, vertical) scroll amount
+    /// if [scroll_coalesce](TableState::scroll_coalesce) is set.
+    pub pending_scroll: (isize, isize),
+
+    /// Report triple-clicks with the [DoubleClick](crate::event::DoubleClick) handler.
+    ///
+    /// The double-click is still reported with the second click.
+    pub triple_click: bool,
+
+    /// Clicks counted on the same cell so far.
+    /// __readonly__. used by the [DoubleClick](crate::event::DoubleClick) handler.
+    pub click_count: usize,
+
+    /// Time and cell (column, row) of the last click.
+    /// __readonly__. used by the [DoubleClick](crate::event::DoubleClick) handler.
+    pub last_click: Option<(Instant, (usize, usize))>,
+
+    /// Scroll and selection requests from other threads.
+    /// They are applied with the next render.
+    ///
+    /// Clone this and hand it to a background task. A clone of the
+    /// TableState shares the requests.
+    pub requests: TableRequests,
+
+    /// Cell (column, row) where a mouse drag started.
+    /// __readonly__. used by the event-handler.
+    pub drag_anchor: Option<(usize, usize)>,
+
+    /// Range of cells covered by a mouse drag in progress, as the
+    /// (column, row) of the start and the current cell. The table
+    /// renders a preview of this range.
+    /// __readonly__. used by the event-handler.
+    pub drag_range: Option<((usize, usize), (usize, usize))>,
+
+    /// Visible cells (column, row) with a badge.
+    /// __readonly__. renewed for each render.
+    pub badges: Vec<(usize, usize)>,
+
+    /// Render with the high-contrast variant of the styles.
+    /// See [TableState::set_high_contrast].
+    pub high_contrast: bool,
+
+    /// The configuration has been checked with [Table::validate].
+    /// Only used in debug builds. Reset to check again.
+    pub config_checked: bool,
+
+    /// Follow the end of the data.
+    /// See [TableState::set_follow].
+    pub follow: bool,
+
+    /// Offset set by follow.
+    /// __readonly__. used by render.
+    pub follow_offset: usize,
+
+    /// Clicking the horizontal scrollbar focuses the table.
+    ///
+    /// The scrollbars always work with the mouse, this only
+    /// changes the area given to [HasFocus].
+    pub hscroll_focus: bool,
+
+    /// Clicking the vertical scrollbar focuses the table.
+    /// See [TableState::hscroll_focus].
+    pub vscroll_focus: bool,
+
+    /// Rows kept visible around the selection.
+    /// See [TableState::set_scrolloff].
+    pub scrolloff: usize,
+
+    /// Number of columns pinned to the right edge.
+    /// 0 if pinning is not active. See [Table::pin_right].
+    /// __readonly__. renewed for each render.
+    pub pinned_columns: usize,
+
+    /// Width of the pinned columns.
+    /// __readonly__. renewed for each render.
+    pub pinned_width: u16,
+
+    /// Horizontal offset used for the pinned columns.
+    /// __readonly__. renewed for each render.
+    pub pinned_shift: usize,
+
+    /// Scroll by lines instead of rows.
+    /// See [TableState::set_scroll_lines].
+    pub scroll_lines: bool,
+
+    /// Lines scrolled out at the top for the given row.
+    /// Only used if the row is the first visible row.
+    /// See [TableState::line_offset].
+    pub line_offset: (usize, u16),
+
+    /// Scroll horizontally by columns.
+    /// See [TableState::set_scroll_columns].
+    pub scroll_columns: bool,
+
+    /// Generation of the data as of the last render.
+    /// See [TableData::version].
+    /// __readonly__. renewed for each render.
+    pub data_version: Option<u64>,
+
+    /// The generation of the data changed with this render.
+    /// The latched layout width is reset when this happens.
+    /// __readonly__. renewed for each render.
+    pub data_changed: bool,
+
+    /// Show rows inserted at the first visible row.
+    /// See [TableState::set_show_inserted_at_top].
+    pub show_inserted_at_top: bool,
+
+    /// Estimated number of rows with [Table::no_row_count].
+    /// Doubles whenever the table gets close to it, and is
+    /// exact once the end of the data has been reached.
+    /// __readonly__. used by render.
+    pub estimated_rows: usize,
+
+    /// Touch friendly hit targets.
+    /// See [TableState::set_touch].
+    pub touch: bool,
+
+    /// Last row of a swipe in touch mode.
+    /// __readonly__. used by event-handling.
+    pub touch_swipe: Option<u16>,
+
+    /// Row count found by counting all rows of a TableDataIter.
+    /// Kept as (version, table height, rows, max offset) and
+    /// reused as long as [TableDataIter::version] is the same.
+    /// See [TableState::clear_cached_rows].
+    /// __readonly__. used by render.
+    pub cached_rows: Option<(u64, u16, usize, usize)>,
+
+    /// Highest row count found so far with [Table::no_row_count].
+    /// None if the row count is known.
+    /// __readonly__. used by render.
+    pub known_rows: Option<usize>,
+
+    /// Last mouse position while dragging a selection.
+    /// See [TableState::drag_tick].
+    /// __readonly__. used by event-handling.
+    pub drag_pos: Option<(u16, u16)>,
+
+    /// Rows with a running height animation as
+    /// row -> (current height, target height).
+    /// See [TableState::animate_row_height].
+    pub animated_rows: HashMap<usize, (u16, u16)>,
+
+    /// Distance for PageUp/PageDown.
+    /// See [TableState::set_page_scroll].
+    pub page_scroll: PageScroll,
+
+    /// PageUp/PageDown only scroll the offset.
+    /// See [TableState::set_page_scroll_offset].
+    pub page_scroll_offset: bool,
+
+    /// Indicator row. A second current row independent of the
+    /// selection, e.g. the playback position.
+    pub indicator_row: Option<usize>,
+
+    /// Scroll to the indicator row when it changes.
+    pub follow_indicator: bool,
+
+    /// Column groups as (first, last) column, sorted and disjoint.
+    /// See [TableState::group_columns].
+    pub column_groups: Vec<(usize, usize)>,
+
+    /// Header column where a group drag started.
+    /// __readonly__. used by event-handling.
+    pub group_drag: Option<usize>,
+
+    /// Visible rows (first, last) returned by the last call of
+    /// [TableState::visible_rows_changed].
+    /// __readonly__. used by visible_rows_changed.
+    pub reported_rows: Option<(usize, usize)>,
+
+    /// Notes attached to cells (column, row).
+    /// See [TableState::set_annotations].
+    pub annotations: HashMap<(usize, usize), Text<'static>>,
+
+    /// Cell (column, row) whose note is shown.
+    pub annotation: Option<(usize, usize)>,
+
+    /// Area of the note popup.
+    /// __readonly__. renewed for each render.
+    pub annotation_area: Rect,
+
+    /// Paged presentation. See [Table::paged].
+    /// __readonly__. renewed for each render.
+    pub paged: bool,
+
+    /// Rows per page in paged presentation.
+    /// __readonly__. renewed for each render.
+    pub page_size: usize,
+
+    /// Offset of the page shown by the last render.
+    /// __readonly__. used by render.
+    pub paged_offset: usize,
+
+    /// Rows rendered by the first half of a split render.
+    /// See [Table::progressive].
+    /// __readonly__. used by render.
+    pub render_continuation: Option<RenderContinuation>,
+
+    /// Heights of the last rows counted, enough to fill a page.
+    /// Kept with [TableState::counted_rows] for the next render.
+    /// __readonly__. used by render.
+    pub counted_heights: Vec<u16>,
+
+    /// Data rows for the row positions, if the table uses
+    /// [Table::row_order]. See [TableState::data_row].
+    /// __readonly__. renewed for each render.
+    pub row_order: Option<Vec<usize>>,
+
+    /// Rows per mouse wheel event set with [Table::wheel_scroll_by].
+    /// Only used while handling a wheel event, the scroll_by of
+    /// vscroll is left alone.
+    /// __readonly__. renewed for each render.
+    pub wheel_scroll_by: Option<usize>,
+
+    /// Helper for mouse interactions.
+    pub mouse: MouseFlags,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl<Selection> Default for Table<'_, Selection> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<'a, Selection, Kind: DataKind<'a>> Table<'a, Selection, Kind> {
+    fn empty() -> Self {
+        Self {
+            data: DataRepr::None,
+            no_row_count: Default::default(),
+            paged: Default::default(),
+            progressive: Default::default(),
+            overscroll: Default::default(),
+            row_count_budget: Default::default(),
+            header: Default::default(),
+            footer: Default::default(),
+            widths: Default::default(),
+            flex: Default::default(),
+            column_spacing: Default::default(),
+            layout_width: Default::default(),
+            auto_layout_width: Default::default(),
+            latch_layout_width: Default::default(),
+            block: Default::default(),
+            hscroll: Default::default(),
+            vscroll: Default::default(),
+            header_style: Default::default(),
+            footer_style: Default::default(),
+            style: Default::default(),
+            select_row_style: Default::default(),
+            show_row_focus: true,
+            select_column_style: Default::default(),
+            show_column_focus: Default::default(),
+            select_cell_style: Default::default(),
+            show_cell_focus: Default::default(),
+            select_header_style: Default::default(),
+            show_header_focus: Default::default(),
+            select_footer_style: Default::default(),
+            show_footer_focus: Default::default(),
+            focus_style: Default::default(),
+            highlight: Default::default(),
+            highlight_style: Default::default(),
+            overflow_indicator: Default::default(),
+            overflow_style: Default::default(),
+            resize_handles: Default::default(),
+            wrap_header: Default::default(),
+            header_decorations: Default::default(),
+            decoration_style: Default::default(),
+            row_numbers: Default::default(),
+            row_number_style: Default::default(),
+            minimap: Default::default(),
+            minimap_style: Default::default(),
+            app_gutter: Default::default(),
+            app_gutter_style: Default::default(),
+            progress_style: Default::default(),
+            fill_space: true,
+            anchor_bottom: Default::default(),
+            reserve_scrollbars: Default::default(),
+            start_at: Default::default(),
+            hover_style: Default::default(),
+            indicator_style: Default::default(),
+            column_group_style: Default::default(),
+            annotation_style: Default::default(),
+            resize_handle_style: Default::default(),
+            resize_handle_hover_style: Default::default(),
+            peek_style: Default::default(),
+            drag_style: Default::default(),
+            badge_style: Default::default(),
+            detail_pane: Default::default(),
+            detail_block: Default::default(),
+            catch_cell_panics: Default::default(),
+            list: Default::default(),
+            pin_right: Default::default(),
+            blend: Default::default(),
+            watermark: Default::default(),
+            watermark_style: Default::default(),
+            row_order: Default::default(),
+            wheel_scroll: Default::default(),
+            patch_order: Default::default(),
+            debug: Default::default(),
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<'a, Selection> Table<'a, Selection> {
+    /// New, empty Table.
+    pub fn new() -> Self
+    where
+        Selection: Default,
+    {
+        Self::default()
+    }
+
+    /// Create a new Table with preformatted data. For compatibility
+    /// with ratatui.
+    ///
+    /// Use of [Table::data] is preferred.
+    pub fn new_ratatui<R, C>(rows: R, widths: C) -> Self
+    where
+        R: IntoIterator,
+        R::Item: Into<Row<'a>>,
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+        Selection: Default,
+    {
+        let widths = widths.into_iter().map(|v| v.into()).collect::<Vec<_>>();
+        let data = TextTableData {
+            rows: rows.into_iter().map(|v| v.into()).collect(),
+        };
+        Self {
+            data: DataRepr::Text(data),
+            widths: Cow::Owned(widths),
+            ..Default::default()
+        }
+    }
+
+    /// Set a reference to the TableData facade to your data.
+    ///
+    /// The way to go is to define a small struct that contains just a
+    /// reference to your data. Then implement TableData for this struct.
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::prelude::Style;
+    /// use ratatui::text::Span;
+    /// use ratatui::widgets::{StatefulWidget, Widget};
+    /// use rat_ftable::{Table, TableContext, TableState, TableData};    ///
+    /// #
+    /// use rat_ftable::selection::RowSelection;
+    ///
+    /// struct SampleRow;
+    /// # impl Clone for SampleRow {
+    /// #     fn clone(&self) -> Self {
+    /// #        SampleRow
+    /// #     }
+    /// # }
+    /// # let area = Rect::default();
+    /// # let mut buf = Buffer::empty(area);
+    /// # let buf = &mut buf;
+    ///
+    /// struct Data1<'a>(&'a [SampleRow]);
+    ///
+    /// impl<'a> TableData<'a> for Data1<'a> {
+    ///     fn rows(&self) -> usize {
+    ///         self.0.len()
+    ///     }
+    ///
+    ///     fn row_height(&self, row: usize) -> u16 {
+    ///         // to some calculations ...
+    ///         1
+    ///     }
+    ///
+    ///     fn row_style(&self, row: usize) -> Option<Style> {
+    ///         // to some calculations ...
+    ///         None
+    ///     }
+    ///
+    ///     fn render_cell(&self, ctx: &TableContext, column: usize, row: usize, area: Rect, buf: &mut Buffer) {
+    ///         if let Some(data) = self.0.get(row) {
+    ///             let rend = match column {
+    ///                 0 => Span::from("column1"),
+    ///                 1 => Span::from("column2"),
+    ///                 2 => Span::from("column3"),
+    ///                 _ => return
+    ///             };
+    ///             rend.render(area, buf);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // When you are creating the table widget you hand over a reference
+    /// // to the facade struct.
+    ///
+    /// let my_data_somewhere_else = vec![SampleRow;999999];
+    /// let mut table_state_somewhere_else = TableState::<RowSelection>::default();
+    ///
+    /// // ...
+    ///
+    /// let table1 = Table::default().data(Data1(&my_data_somewhere_else));
+    /// table1.render(area, buf, &mut table_state_somewhere_else);
+    /// ```
+    #[inline]
+    pub fn data(mut self, data: impl TableData<'a> + 'a) -> Self {
+        self.widths = Cow::Owned(data.widths());
+        self.header = data.header().map(Cow::Owned);
+        self.footer = data.footer().map(Cow::Owned);
+        let data: Box<dyn TableData<'a> + 'a> = Box::new(data);
+        self.data = DataRepr::Data(data);
+        self
+    }
+
+    ///
+    /// Alternative representation for the data as a kind of Iterator.
+    /// It uses interior iteration, which fits quite nice for this and
+    /// avoids handing out lifetime bound results of the actual iterator.
+    /// Which is a bit nightmarish to get right.
+    ///
+    ///
+    /// Caution: If you can't give the number of rows, the table will iterate over all
+    /// the data. See [Table::no_row_count].
+    ///
+    /// ```rust
+    /// use std::iter::{Enumerate};
+    /// use std::slice::Iter;
+    /// use format_num_pattern::NumberFormat;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Constraint, Rect};
+    /// use ratatui::prelude::Color;
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::text::Span;
+    /// use ratatui::widgets::{Widget, StatefulWidget};
+    /// use rat_ftable::{Table, TableContext, TableState, TableDataIter};
+    /// use rat_ftable::selection::RowSelection;
+    ///
+    /// struct Data {
+    /// #     table_data: Vec<Sample>
+    /// # }
+    /// #
+    /// # struct Sample {
+    /// #     pub text: String
+    /// # }
+    /// #
+    /// # let data = Data {
+    /// #     table_data: vec![],
+    /// # };
+    /// # let area = Rect::default();
+    /// # let mut buf = Buffer::empty(area);
+    /// # let buf = &mut buf;
+    ///
+    /// struct RowIter1<'a> {
+    ///     iter: Enumerate<Iter<'a, Sample>>,
+    ///     item: Option<(usize, &'a Sample)>,
+    /// }
+    ///
+    /// impl<'a> TableDataIter<'a> for RowIter1<'a> {
+    ///     fn rows(&self) -> Option<usize> {
+    ///         // If you can, give the length. Otherwise,
+    ///         // the table will iterate all to find out a length.
+    ///         None
+    ///         // Some(100_000)
+    ///     }
+    ///
+    ///     /// Select the nth element from the current position.
+    ///     fn nth(&mut self, n: usize) -> bool {
+    ///         self.item = self.iter.nth(n);
+    ///         self.item.is_some()
+    ///     }
+    ///
+    ///     /// Row height.
+    ///     fn row_height(&self) -> u16 {
+    ///         1
+    ///     }
+    ///
+    ///     /// Row style.
+    ///     fn row_style(&self) -> Option<Style> {
+    ///         Some(Style::default())
+    ///     }
+    ///
+    ///     /// Render one cell.
+    ///     fn render_cell(&self,
+    ///                     ctx: &TableContext,
+    ///                     column: usize,
+    ///                     area: Rect,
+    ///                     buf: &mut Buffer)
+    ///     {
+    ///         let row = self.item.expect("data");
+    ///         match column {
+    ///             0 => {
+    ///                 let row_fmt = NumberFormat::new("000000").expect("fmt");
+    ///                 let span = Span::from(row_fmt.fmt_u(row.0));
+    ///                 buf.set_style(area, Style::new().black().bg(Color::from_u32(0xe7c787)));
+    ///                 span.render(area, buf);
+    ///             }
+    ///             1 => {
+    ///                 let span = Span::from(&row.1.text);
+    ///                 span.render(area, buf);
+    ///             }
+    ///             _ => {}
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut rit = RowIter1 {
+    ///     iter: data.table_data.iter().enumerate(),
+    ///     item: None,
+    /// };
+    ///
+    /// let table1 = Table::default()
+    ///     .iter(rit)
+    ///     .widths([
+    ///         Constraint::Length(6),
+    ///         Constraint::Length(20)
+    ///     ]);
+    ///
+    /// let mut table_state_somewhere_else = TableState::<RowSelection>::default();
+    ///
+    /// table1.render(area, buf, &mut table_state_somewhere_else);
+    /// ```
+    ///
+    #[inline]
+    pub fn iter(mut self, data: impl TableDataIter<'a> + 'a) -> Self {
+        #[cfg(debug_assertions)]
+        if data.rows().is_none() {
+            use log::warn;
+            warn!("Table::iter - rows is None, this will be slower");
+        }
+        self.header = data.header().map(Cow::Owned);
+        self.footer = data.footer().map(Cow::Owned);
+        self.widths = Cow::Owned(data.widths());
+        let data: Box<dyn TableDataIter<'a> + 'a> = Box::new(data);
+        self.data = DataRepr::Iter(data);
+        self
+    }
+
+    /// Set the table data as TableDataIter, but take header, footer and
+    /// widths from the schema instead of the iterator.
+    ///
+    /// The schema can be kept across renders, so the iterator doesn't need
+    /// to build the header and footer rows each time. It is borrowed,
+    /// not copied.
+    #[inline]
+    pub fn iter_with_schema(
+        mut self,
+        data: impl TableDataIter<'a> + 'a,
+        schema: &'a TableSchema<'a>,
+    ) -> Self {
+        #[cfg(debug_assertions)]
+        if data.rows().is_none() {
+            use log::warn;
+            warn!("Table::iter - rows is None, this will be slower");
+        }
+        self.header = schema.header.as_ref().map(Cow::Borrowed);
+        self.footer = schema.footer.as_ref().map(Cow::Borrowed);
+        self.widths = Cow::Borrowed(&schema.widths);
+        let data: Box<dyn TableDataIter<'a> + 'a> = Box::new(data);
+        self.data = DataRepr::Iter(data);
+        self
+    }
+
+    /// Any widget as watermark, rendered beneath the rows.
+    ///
+    /// The function gets the table area and renders there, for
+    /// example `|area, buf| logo.render(area, buf)`. See
+    /// [Table::watermark].
+    #[inline]
+    pub fn watermark_widget(mut self, render: impl Fn(Rect, &mut Buffer) + 'a) -> Self {
+        let render: Box<<LocalData as DataKind<'a>>::Render> = Box::new(render);
+        self.watermark = Some(Watermark::Render(render));
+        self
+    }
+}
+
+impl<'a, Selection> Table<'a, Selection, SendData> {
+    /// New, empty Table that only takes data that is Send.
+    /// This makes the Table Send too. See [SendData].
+    pub fn new_send() -> Self
+    where
+        Selection: Default,
+    {
+        Self::empty()
+    }
+
+    /// Set the TableData. See [Table::data](Table#method.data).
+    #[inline]
+    pub fn data(mut self, data: impl TableData<'a> + Send + 'a) -> Self {
+        self.widths = Cow::Owned(data.widths());
+        self.header = data.header().map(Cow::Owned);
+        self.footer = data.footer().map(Cow::Owned);
+        let data: Box<dyn TableData<'a> + Send + 'a> = Box::new(data);
+        self.data = DataRepr::Data(data);
+        self
+    }
+
+    /// Set the TableDataIter. See [Table::iter](Table#method.iter).
+    #[inline]
+    pub fn iter(mut self, data: impl TableDataIter<'a> + Send + 'a) -> Self {
+        #[cfg(debug_assertions)]
+        if data.rows().is_none() {
+            use log::warn;
+            warn!("Table::iter - rows is None, this will be slower");
+        }
+        self.header = data.header().map(Cow::Owned);
+        self.footer = data.footer().map(Cow::Owned);
+        self.widths = Cow::Owned(data.widths());
+        let data: Box<dyn TableDataIter<'a> + Send + 'a> = Box::new(data);
+        self.data = DataRepr::Iter(data);
+        self
+    }
+
+    /// Set the TableDataIter with a schema.
+    /// See [Table::iter_with_schema](Table#method.iter_with_schema).
+    #[inline]
+    pub fn iter_with_schema(
+        mut self,
+        data: impl TableDataIter<'a> + Send + 'a,
+        schema: &'a TableSchema<'a>,
+    ) -> Self {
+        #[cfg(debug_assertions)]
+        if data.rows().is_none() {
+            use log::warn;
+            warn!("Table::iter - rows is None, this will be slower");
+        }
+        self.header = schema.header.as_ref().map(Cow::Borrowed);
+        self.footer = schema.footer.as_ref().map(Cow::Borrowed);
+        self.widths = Cow::Borrowed(&schema.widths);
+        let data: Box<dyn TableDataIter<'a> + Send + 'a> = Box::new(data);
+        self.data = DataRepr::Iter(data);
+        self
+    }
+
+    /// Any widget as watermark.
+    /// See [Table::watermark_widget](Table#method.watermark_widget).
+    #[inline]
+    pub fn watermark_widget(mut self, render: impl Fn(Rect, &mut Buffer) + Send + 'a) -> Self {
+        let render: Box<<SendData as DataKind<'a>>::Render> = Box::new(render);
+        self.watermark = Some(Watermark::Render(render));
+        self
+    }
+}
+
+impl<'a, Selection, Kind: DataKind<'a>> Table<'a, Selection, Kind> {
+    /// Set preformatted row-data. For compatibility with ratatui.
+    ///
+    /// Use of [Table::data] is preferred.
+    pub fn rows<T>(mut self, rows: T) -> Self
+    where
+        T: IntoIterator<Item = Row<'a>>,
+    {
+        let rows = rows.into_iter().collect();
+        self.data = DataRepr::Text(TextTableData { rows });
+        self
+    }
+
+    /// If you work with an TableDataIter to fill the table, and
+    /// if you don't return a count with rows(), Table will run
+    /// through all your iterator to find the actual number of rows.
+    ///
+    /// This may take its time.
+    ///
+    /// If you set no_row_count(true), this part will be skipped, and
+    /// the row count will be set to an estimate of usize::MAX.
+    /// This will destroy your ability to jump to the end of the data,
+    /// but otherwise it's fine.
+    /// You can still page-down through the data, and if you ever
+    /// reach the end, the correct row-count can be established.
+    ///
+    /// The scrollbar uses [TableState::estimated_rows] instead,
+    /// which grows while you page through the data.
+    ///
+    /// _Extra info_: This might be only useful if you have a LOT of data.
+    /// In my test it changed from 1.5ms to 150µs for about 100.000 rows.
+    /// And 1.5ms is still not that much ... so you probably want to
+    /// test without this first and then decide.
+    pub fn no_row_count(mut self, no_row_count: bool) -> Self {
+        self.no_row_count = no_row_count;
+        self
+    }
+
+    /// Show the data as discrete pages instead of scrolling
+    /// continuously.
+    ///
+    /// The offset always snaps to the start of a page, PageUp/PageDown
+    /// flip whole pages and the last page may be only partially filled.
+    /// See [TableState::page], [TableState::set_page] and
+    /// [TableState::page_count].
+    ///
+    /// Caveat: The page size is taken from the height of the first
+    /// visible row, so this works best with equal row-heights.
+    #[inline]
+    pub fn paged(mut self, paged: bool) -> Self {
+        self.paged = paged;
+        self
+    }
+
+    /// Split rendering the rows across two frames, if the table
+    /// is taller than the given height.
+    ///
+    /// When the view changes (offset, data version, columns,
+    /// selection, ...) the first frame renders the top half and
+    /// leaves the rest empty. The next frame renders the bottom half
+    /// and reuses the top half, if nothing changed in between.
+    /// Otherwise, and for all following frames, everything is
+    /// rendered. This keeps the frames short while scrolling through
+    /// very tall tables with slow data.
+    ///
+    /// Check [TableState::is_render_pending] after rendering to
+    /// schedule the second frame.
+    #[inline]
+    pub fn progressive(mut self, height: Option<u16>) -> Self {
+        self.progressive = height;
+        self
+    }
+
+    /// Allow scrolling n rows past the last row.
+    ///
+    /// Usually the last page is clamped to fill the table. With
+    /// this the max_offset is extended by n rows, at most until
+    /// the last row reaches the top of the table.
+    #[inline]
+    pub fn overscroll(mut self, rows: usize) -> Self {
+        self.overscroll = rows;
+        self
+    }
+
+    /// Show the rows in the given order.
+    ///
+    /// Each entry is the row of the data shown at this position,
+    /// rows that are not listed are not shown. Use this if the
+    /// ordering is computed elsewhere anyway, instead of sorting
+    /// the data itself.
+    ///
+    /// The selection and all other row positions of the
+    /// TableState are positions in this order, use
+    /// [TableState::data_row] to get the row of the data.
+    /// Rows that don't exist in the data are left out.
+    ///
+    /// This works with [Table::rows] and [Table::data], not with
+    /// [Table::iter] and not with StatefulWidgetRef.
+    pub fn row_order(mut self, order: &'a [usize]) -> Self {
+        self.row_order = Some(order);
+        self
+    }
+
+    /// Which of row, cell and selection style wins.
+    ///
+    /// The cell style is applied by render_cell() via
+    /// [TableContext::apply_cell_style].
+    #[inline]
+    pub fn patch_order(mut self, order: PatchOrder) -> Self {
+        self.patch_order = order;
+        self
+    }
+
+    /// Rows scrolled per mouse wheel event.
+    ///
+    /// This overrides the scroll_by of the vertical scrollbar
+    /// for wheel events.
+    #[inline]
+    pub fn wheel_scroll_by(mut self, wheel_scroll: WheelScroll) -> Self {
+        self.wheel_scroll = wheel_scroll;
+        self
+    }
+
+    /// If you work with an TableDataIter and don't return a count
+    /// with rows(), this limits the number of rows counted per render.
+    ///
+    /// The partial count is kept in [TableState::counted_rows] and
+    /// counting continues with the next render. Until the count is
+    /// finished, the row count is what has been counted so far, but
+    /// never less than the last complete count.
+    ///
+    /// Use [TableState::is_counting_rows] to check if another render
+    /// is needed.
+    ///
+    /// Each render skips to the last counted row with a single
+    /// [TableDataIter::nth]. This limits the time per render only if
+    /// your iterator implements nth() without stepping through all the
+    /// skipped rows, e.g. with an index into the data.
+    ///
+    /// This is ignored if no_row_count(true) is set.
+    pub fn row_count_budget(mut self, budget: usize) -> Self {
+        self.row_count_budget = Some(budget);
+        self
+    }
+
+    /// Set the table-header.
+    #[inline]
+    pub fn header(mut self, header: Row<'a>) -> Self {
+        self.header = Some(Cow::Owned(header));
+        self
+    }
+
+    /// Set the table-footer.
+    #[inline]
+    pub fn footer(mut self, footer: Row<'a>) -> Self {
+        self.footer = Some(Cow::Owned(footer));
+        self
+    }
+
+    /// Column widths as Constraints.
+    pub fn widths<I>(mut self, widths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        self.widths = Cow::Owned(widths.into_iter().map(|v| v.into()).collect());
+        self
+    }
+
+    /// Flex for layout.
+    #[inline]
+    pub fn flex(mut self, flex: Flex) -> Self {
+        self.flex = flex;
+        self
+    }
+
+    /// Spacing between columns.
+    #[inline]
+    pub fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Overrides the width of the rendering area for layout purposes.
+    /// Layout uses this width, even if it means that some columns are
+    /// not visible.
+    #[inline]
+    pub fn layout_width(mut self, width: u16) -> Self {
+        self.layout_width = Some(width);
+        self
+    }
+
+    /// Calculates the width from the given column-constraints.
+    /// If a fixed layout_width() is set too, that one will win.
+    ///
+    /// Panic:
+    /// Rendering will panic, if any constraint other than Constraint::Length(),
+    /// Constraint::Min() or Constraint::Max() is used.
+    #[inline]
+    pub fn auto_layout_width(mut self, auto: bool) -> Self {
+        self.auto_layout_width = auto;
+        self
+    }
+
+    /// Keeps the widest layout width seen so far.
+    ///
+    /// With Min constraints and Flex the calculated width can
+    /// change when scrolling through the data, which makes the
+    /// horizontal scrollbar jitter. This keeps it stable until
+    /// [TableState::reset_latched_width] is called.
+    #[inline]
+    pub fn latch_layout_width(mut self, latch: bool) -> Self {
+        self.latch_layout_width = latch;
+        self
+    }
+
+    /// Draws a block around the table widget.
+    #[inline]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Scrollbars
+    pub fn scroll(mut self, scroll: Scroll<'a>) -> Self {
+        self.hscroll = Some(scroll.clone().override_horizontal());
+        self.vscroll = Some(scroll.override_vertical());
+        self
+    }
+
+    /// Scrollbars
+    pub fn hscroll(mut self, scroll: Scroll<'a>) -> Self {
+        self.hscroll = Some(scroll.override_horizontal());
+        self
+    }
+
+    /// Scrollbars
+    pub fn vscroll(mut self, scroll: Scroll<'a>) -> Self {
+        self.vscroll = Some(scroll.override_vertical());
+        self
+    }
+
+    /// Set all layout settings as a bundle.
+    /// See [TableLayout] for some presets.
+    #[inline]
+    pub fn layout(mut self, layout: TableLayout) -> Self {
+        self.flex = layout.flex;
+        self.column_spacing = layout.column_spacing;
+        self.fill_space = layout.fill_space;
+        self.resize_handles = layout.resize_handles;
+        self.wrap_header = layout.wrap_header;
+        self
+    }
+
+    /// Set all styles as a bundle.
+    #[inline]
+    pub fn styles(mut self, styles: TableStyle) -> Self {
+        self.style = styles.style;
+        if styles.header.is_some() {
+            self.header_style = styles.header;
+        }
+        if styles.footer.is_some() {
+            self.footer_style = styles.footer;
+        }
+        if styles.select_row.is_some() {
+            self.select_row_style = styles.select_row;
+        }
+        self.show_row_focus = styles.show_row_focus;
+        if styles.select_column.is_some() {
+            self.select_column_style = styles.select_column;
+        }
+        self.show_column_focus = styles.show_column_focus;
+        if styles.select_cell.is_some() {
+            self.select_cell_style = styles.select_cell;
+        }
+        self.show_cell_focus = styles.show_cell_focus;
+        if styles.select_header.is_some() {
+            self.select_header_style = styles.select_header;
+        }
+        self.show_header_focus = styles.show_header_focus;
+        if styles.select_footer.is_some() {
+            self.select_footer_style = styles.select_footer;
+        }
+        self.show_footer_focus = styles.show_footer_focus;
+        if styles.focus_style.is_some() {
+            self.focus_style = styles.focus_style;
+        }
+        if styles.highlight.is_some() {
+            self.highlight_style = styles.highlight;
+        }
+        if styles.overflow.is_some() {
+            self.overflow_style = styles.overflow;
+        }
+        if styles.decoration.is_some() {
+            self.decoration_style = styles.decoration;
+        }
+        if styles.row_number.is_some() {
+            self.row_number_style = styles.row_number;
+        }
+        if styles.minimap.is_some() {
+            self.minimap_style = styles.minimap;
+        }
+        if styles.app_gutter.is_some() {
+            self.app_gutter_style = styles.app_gutter;
+        }
+        if styles.progress.is_some() {
+            self.progress_style = styles.progress;
+        }
+        if styles.hover.is_some() {
+            self.hover_style = styles.hover;
+        }
+        if styles.indicator.is_some() {
+            self.indicator_style = styles.indicator;
+        }
+        if styles.column_group.is_some() {
+            self.column_group_style = styles.column_group;
+        }
+        if styles.annotation.is_some() {
+            self.annotation_style = styles.annotation;
+        }
+        if styles.resize_handle.is_some() {
+            self.resize_handle_style = styles.resize_handle;
+        }
+        if styles.resize_handle_hover.is_some() {
+            self.resize_handle_hover_style = styles.resize_handle_hover;
+        }
+        if styles.peek.is_some() {
+            self.peek_style = styles.peek;
+        }
+        if styles.drag.is_some() {
+            self.drag_style = styles.drag;
+        }
+        if styles.badge.is_some() {
+            self.badge_style = styles.badge;
+        }
+        // TODO: add border_style for other XXStyles too.
+        if let Some(border_style) = styles.border_style {
+            self.block = self.block.map(|v| v.border_style(border_style));
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        if let Some(styles) = styles.scroll {
+            self.hscroll = self.hscroll.map(|v| v.styles(styles.clone()));
+            self.vscroll = self.vscroll.map(|v| v.styles(styles));
+        }
+        self
+    }
+
+    /// Base style for the table.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style for the table.
+    #[inline]
+    pub fn header_style(mut self, style: Option<Style>) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Base style for the table.
+    #[inline]
+    pub fn footer_style(mut self, style: Option<Style>) -> Self {
+        self.footer_style = style;
+        self
+    }
+
+    /// Style for a selected row. The chosen selection must support
+    /// row-selection for this to take effect.
+    #[inline]
+    pub fn select_row_style(mut self, select_style: Option<Style>) -> Self {
+        self.select_row_style = select_style;
+        self
+    }
+
+    /// Add the focus-style to the row-style if the table is focused.
+    #[inline]
+    pub fn show_row_focus(mut self, show: bool) -> Self {
+        self.show_row_focus = show;
+        self
+    }
+
+    /// Style for a selected column. The chosen selection must support
+    /// column-selection for this to take effect.
+    #[inline]
+    pub fn select_column_style(mut self, select_style: Option<Style>) -> Self {
+        self.select_column_style = select_style;
+        self
+    }
+
+    /// Add the focus-style to the column-style if the table is focused.
+    #[inline]
+    pub fn show_column_focus(mut self, show: bool) -> Self {
+        self.show_column_focus = show;
+        self
+    }
+
+    /// Style for a selected cell. The chosen selection must support
+    /// cell-selection for this to take effect.
+    #[inline]
+    pub fn select_cell_style(mut self, select_style: Option<Style>) -> Self {
+        self.select_cell_style = select_style;
+        self
+    }
+
+    /// Add the focus-style to the cell-style if the table is focused.
+    #[inline]
+    pub fn show_cell_focus(mut self, show: bool) -> Self {
+        self.show_cell_focus = show;
+        self
+    }
+
+    /// Style for a selected header cell. The chosen selection must
+    /// support column-selection for this to take effect.
+    #[inline]
+    pub fn select_header_style(mut self, select_style: Option<Style>) -> Self {
+        self.select_header_style = select_style;
+        self
+    }
+
+    /// Add the focus-style to the header-style if the table is focused.
+    #[inline]
+    pub fn show_header_focus(mut self, show: bool) -> Self {
+        self.show_header_focus = show;
+        self
+    }
+
+    /// Style for a selected footer cell. The chosen selection must
+    /// support column-selection for this to take effect.
+    #[inline]
+    pub fn select_footer_style(mut self, select_style: Option<Style>) -> Self {
+        self.select_footer_style = select_style;
+        self
+    }
+
+    /// Add the footer-style to the table-style if the table is focused.
+    #[inline]
+    pub fn show_footer_focus(mut self, show: bool) -> Self {
+        self.show_footer_focus = show;
+        self
+    }
+
+    /// This style will be patched onto the selection to indicate that
+    /// the widget has the input focus.
+    ///
+    /// The selection must support some kind of selection for this to
+    /// be effective.
+    #[inline]
+    pub fn focus_style(mut self, focus_style: Option<Style>) -> Self {
+        self.focus_style = focus_style;
+        self
+    }
+
+    /// Matches from a [Matcher](crate::matcher::Matcher) search.
+    ///
+    /// The byte-ranges of the matches for each cell are given to
+    /// render_cell() via [TableContext::highlight]. The rows must be
+    /// sorted, which is the order Matcher returns them.
+    ///
+    /// After rendering, the highlight style is patched onto the
+    /// cell where its cell_text() is found.
+    #[inline]
+    pub fn highlight(mut self, matches: &'a [RowMatch]) -> Self {
+        self.highlight = Some(matches);
+        self
+    }
+
+    /// Style for highlighted matches.
+    #[inline]
+    pub fn highlight_style(mut self, style: Option<Style>) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Show a marker "+n ▸" at the right edge, if there are columns
+    /// completely hidden beyond the right edge of the table.
+    ///
+    /// Clicking the marker scrolls the first hidden column into view.
+    #[inline]
+    pub fn overflow_indicator(mut self, show: bool) -> Self {
+        self.overflow_indicator = show;
+        self
+    }
+
+    /// Style for the overflow indicator.
+    #[inline]
+    pub fn overflow_style(mut self, style: Option<Style>) -> Self {
+        self.overflow_style = style;
+        self
+    }
+
+    /// Show resize handles "│" between the columns of the header.
+    ///
+    /// The handles can be dragged with the mouse to change the width
+    /// of the column to their left. The new widths are stored in
+    /// [TableState::width_overrides].
+    #[inline]
+    pub fn resize_handles(mut self, show: bool) -> Self {
+        self.resize_handles = show;
+        self
+    }
+
+    /// Word-wrap the header cells to the width of their columns.
+    ///
+    /// The header grows as needed to show the wrapped titles,
+    /// but it is never lower than the height given to the header row.
+    /// Use [Cell::alignment](crate::textdata::Cell::alignment) to align
+    /// the wrapped lines.
+    #[inline]
+    pub fn wrap_header(mut self, wrap: bool) -> Self {
+        self.wrap_header = wrap;
+        self
+    }
+
+    /// Show an indicator at the right edge of the header cell
+    /// for the given column. Replaces any previous decoration
+    /// for this column.
+    ///
+    /// The header cell loses one cell of width for the indicator.
+    /// For a cell that spans columns it is shown at the end of
+    /// the span.
+    #[inline]
+    pub fn header_decorations(mut self, col: usize, decoration: Decoration) -> Self {
+        self.header_decorations.retain(|(c, _)| *c != col);
+        self.header_decorations.push((col, decoration));
+        self
+    }
+
+    /// Style for the header decorations.
+    #[inline]
+    pub fn decoration_style(mut self, style: Option<Style>) -> Self {
+        self.decoration_style = style;
+        self
+    }
+
+    /// Show a row-number gutter left of the table.
+    ///
+    /// The gutter doesn't scroll horizontally.
+    #[inline]
+    pub fn row_numbers(mut self, row_numbers: RowNumbers) -> Self {
+        self.row_numbers = row_numbers;
+        self
+    }
+
+    /// Style for the row-number gutter.
+    #[inline]
+    pub fn row_number_style(mut self, style: Option<Style>) -> Self {
+        self.row_number_style = style;
+        self
+    }
+
+    /// Show a minimap of the whole table with the given width
+    /// (1 or 2 columns) next to the vertical scrollbar.
+    ///
+    /// The minimap uses [TableData::row_summary_style] to show
+    /// markers for the rows. If more than one row maps to a line
+    /// the rows are sampled. The visible part of the table is
+    /// marked too. Clicking the minimap jumps to that part of
+    /// the table.
+    ///
+    /// This doesn't work with [TableDataIter], only the visible
+    /// part is shown then.
+    #[inline]
+    pub fn minimap(mut self, width: u16) -> Self {
+        self.minimap = width;
+        self
+    }
+
+    /// Style for the minimap.
+    #[inline]
+    pub fn minimap_style(mut self, style: Option<Style>) -> Self {
+        self.minimap_style = style;
+        self
+    }
+
+    /// Reserve a gutter for the application with the given width
+    /// left of the columns.
+    ///
+    /// The table only fills it with the app gutter style. The areas
+    /// for each visible row are in [TableState::app_gutter_areas],
+    /// use them to render your own decorations after the table.
+    #[inline]
+    pub fn app_gutter(mut self, width: u16) -> Self {
+        self.app_gutter = width;
+        self
+    }
+
+    /// Style for the app gutter.
+    #[inline]
+    pub fn app_gutter_style(mut self, style: Option<Style>) -> Self {
+        self.app_gutter_style = style;
+        self
+    }
+
+    /// Style for the row progress.
+    ///
+    /// If [TableData::row_progress] returns a value, this
+    /// style is used for the first part of the row, behind
+    /// the cell content.
+    #[inline]
+    pub fn progress_style(mut self, style: Option<Style>) -> Self {
+        self.progress_style = style;
+        self
+    }
+
+    /// Paint the spacer after each cell with the row and selection
+    /// style after the cell has been rendered. This avoids gaps in
+    /// the selection bar if render_cell paints its own background.
+    ///
+    /// Defaults to true. Set to false if your render_cell wants to
+    /// paint the [space_area](crate::TableContext::space_area) itself.
+    #[inline]
+    pub fn fill_space(mut self, fill: bool) -> Self {
+        self.fill_space = fill;
+        self
+    }
+
+    /// Always reserve the space for the scrollbars.
+    ///
+    /// With [ScrollbarPolicy::Collapse] the scrollbars only take
+    /// space when they are needed, and the columns are laid out
+    /// again whenever a scrollbar appears or disappears. This
+    /// renders collapsed scrollbars as minimized instead, which
+    /// keeps the layout stable.
+    #[inline]
+    pub fn reserve_scrollbars(mut self, reserve: bool) -> Self {
+        self.reserve_scrollbars = reserve;
+        self
+    }
+
+    /// Show this row at the top for the first render.
+    ///
+    /// This is ignored once the table has been rendered.
+    #[inline]
+    pub fn start_at_row(mut self, row: usize) -> Self {
+        self.start_at = Some(StartAt::Row(row));
+        self
+    }
+
+    /// Show the last page for the first render.
+    ///
+    /// This is ignored once the table has been rendered.
+    /// If the data doesn't know its row count, the first render
+    /// still starts at the top, the next one shows the end.
+    /// This does nothing with [Table::no_row_count].
+    #[inline]
+    pub fn start_at_end(mut self) -> Self {
+        self.start_at = Some(StartAt::End);
+        self
+    }
+
+    /// If the rows don't fill the table area, render them at
+    /// the bottom and leave the empty space above. Like a chat.
+    #[inline]
+    pub fn anchor_bottom(mut self, anchor: bool) -> Self {
+        self.anchor_bottom = anchor;
+        self
+    }
+
+    /// Style for the row under the mouse.
+    ///
+    /// The row under the mouse is only tracked by the
+    /// [Activate](crate::event::Activate) keybindings.
+    #[inline]
+    pub fn hover_style(mut self, style: Option<Style>) -> Self {
+        self.hover_style = style;
+        self
+    }
+
+    /// Style for the [indicator row](TableState::set_indicator_row).
+    #[inline]
+    pub fn indicator_style(mut self, style: Option<Style>) -> Self {
+        self.indicator_style = style;
+        self
+    }
+
+    /// Style for the header of [column groups](TableState::group_columns).
+    #[inline]
+    pub fn column_group_style(mut self, style: Option<Style>) -> Self {
+        self.column_group_style = style;
+        self
+    }
+
+    /// Style for the marker and the popup of cell notes.
+    /// See [TableState::set_annotations].
+    #[inline]
+    pub fn annotation_style(mut self, style: Option<Style>) -> Self {
+        self.annotation_style = style;
+        self
+    }
+
+    /// Style for the resize handles.
+    #[inline]
+    pub fn resize_handle_style(mut self, style: Option<Style>) -> Self {
+        self.resize_handle_style = style;
+        self
+    }
+
+    /// Style for the resize handle under the mouse.
+    #[inline]
+    pub fn resize_handle_hover_style(mut self, style: Option<Style>) -> Self {
+        self.resize_handle_hover_style = style;
+        self
+    }
+
+    /// Style for the peek popup.
+    ///
+    /// The popup shows the full content of a cell, see
+    /// [TableState::peek_cell].
+    #[inline]
+    pub fn peek_style(mut self, style: Option<Style>) -> Self {
+        self.peek_style = style;
+        self
+    }
+
+    /// Style for the preview of the range covered by a mouse drag.
+    /// Defaults to reversed.
+    #[inline]
+    pub fn drag_style(mut self, style: Option<Style>) -> Self {
+        self.drag_style = style;
+        self
+    }
+
+    /// Style for the cell badges. This is patched over the
+    /// style of the badge itself. See [TableData::cell_badge].
+    #[inline]
+    pub fn badge_style(mut self, style: Option<Style>) -> Self {
+        self.badge_style = style;
+        self
+    }
+
+    /// Pin the last n columns to the right edge of the table.
+    ///
+    /// They stay visible while the other columns scroll
+    /// horizontally. This only kicks in if the columns don't fit
+    /// the table area, and the pinned columns take less than the
+    /// full width.
+    #[inline]
+    pub fn pin_right(mut self, n: usize) -> Self {
+        self.pin_right = n;
+        self
+    }
+
+    /// Reserve an area for the details of the selected row.
+    ///
+    /// The table is rendered in the remaining area, and
+    /// [TableData::render_detail] is called with the lead of
+    /// the selection. Without a selection the pane is hidden
+    /// and the table uses the full area.
+    ///
+    /// This doesn't work with [TableDataIter].
+    #[inline]
+    pub fn detail_pane(mut self, detail_pane: DetailPane) -> Self {
+        self.detail_pane = detail_pane;
+        self
+    }
+
+    /// Block for the detail pane.
+    #[inline]
+    pub fn detail_block(mut self, block: Block<'a>) -> Self {
+        self.detail_block = Some(block);
+        self
+    }
+
+    /// Catch panics in render_cell.
+    ///
+    /// The failed cell shows an error marker, the error is recorded
+    /// in [TableState::diagnostics] and rendering continues with the
+    /// next cell.
+    ///
+    /// The panic hook still runs, you might want to install one that
+    /// doesn't write to the terminal.
+    #[inline]
+    pub fn catch_cell_panics(mut self, catch: bool) -> Self {
+        self.catch_cell_panics = catch;
+        self
+    }
+
+    /// List mode.
+    ///
+    /// Renders only the first column with the full width of the table.
+    /// There is no column layout and no header or footer. Row styles,
+    /// selection and the event-handling work as usual.
+    ///
+    /// This makes the Table a replacement for a List.
+    #[inline]
+    pub fn as_list(mut self, list: bool) -> Self {
+        self.list = list;
+        self
+    }
+
+    /// Blend the table over the existing content of the buffer.
+    ///
+    /// Blank cells without any style don't overwrite the buffer.
+    /// This way the table can be rendered over some background
+    /// art or a watermark. Set no style for the table for this
+    /// to have any effect.
+    #[inline]
+    pub fn blend(mut self, blend: bool) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    /// Text rendered beneath the rows, centered in the table area.
+    ///
+    /// It shows where there are no rows, e.g. a logo or some
+    /// keyboard hints for an empty table. With [Table::blend] it
+    /// shows through blank cells too.
+    #[inline]
+    pub fn watermark(mut self, text: impl Into<Text<'a>>) -> Self {
+        self.watermark = Some(Watermark::Text(text.into()));
+        self
+    }
+
+    /// Style for the watermark.
+    #[inline]
+    pub fn watermark_style(mut self, style: Option<Style>) -> Self {
+        self.watermark_style = style;
+        self
+    }
+
+    /// Just some utility to help with debugging. Usually does nothing.
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+}
+
+impl<'a, Selection, Kind: DataKind<'a>> Table<'a, Selection, Kind> {
+    // area_width or layout_width
+    #[inline]
+    fn total_width(&self, widths: &[Constraint], area_width: u16) -> u16 {
+        if let Some(layout_width) = self.layout_width {
+            layout_width
+        } else if self.auto_layout_width {
             let mut width = 0;
-            for w in &self.widths {
+            for w in widths {
                 match w {
                     Constraint::Min(v) => width += *v + self.column_spacing,
                     Constraint::Max(v) => width += *v + self.column_spacing,
@@ -873,1067 +2853,4314 @@ impl<Selection> Table<'_, Selection> {
                     _ => unimplemented!("Invalid layout constraint."),
                 }
             }
-            width
+            width
+        } else {
+            area_width
+        }
+    }
+
+    // Do the column-layout. Fill in missing columns, if necessary.
+    //
+    // The width of each column is decided by the rules in the order
+    // given for WidthSource. Sources are returned for each column.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn layout_columns(
+        &self,
+        width: u16,
+        overrides: &HashMap<usize, u16>,
+        collapsed: &HashSet<usize>,
+        latched_width: u16,
+    ) -> (u16, Rc<[Rect]>, Rc<[Rect]>, Vec<WidthSource>) {
+        // one column with the full width.
+        if self.list {
+            return (
+                width,
+                Rc::new([Rect::new(0, 0, width, 0)]),
+                Rc::new([Rect::new(0, 0, 0, 0), Rect::new(width, 0, 0, 0)]),
+                vec![WidthSource::Constraint],
+            );
+        }
+
+        // widths set by resizing replace the constraints.
+        // collapsed columns are left out.
+        let widths = self
+            .widths
+            .iter()
+            .enumerate()
+            .filter(|(col, _)| !collapsed.contains(col))
+            .map(|(col, w)| match overrides.get(&col) {
+                Some(v) => Constraint::Length(*v),
+                None => *w,
+            })
+            .collect::<Vec<_>>();
+
+        let mut width = self.total_width(&widths, width);
+        let latched = self.latch_layout_width && latched_width > width;
+        if latched {
+            width = latched_width;
+        }
+
+        let sources = (0..self.widths.len())
+            .map(|col| {
+                if collapsed.contains(&col) {
+                    WidthSource::Collapsed
+                } else if overrides.contains_key(&col) {
+                    WidthSource::Override
+                } else if latched && !matches!(self.widths[col], Constraint::Length(_)) {
+                    WidthSource::Latched
+                } else {
+                    WidthSource::Constraint
+                }
+            })
+            .collect::<Vec<_>>();
+        let area = Rect::new(0, 0, width, 0);
+
+        let (layout, spacers) = Layout::horizontal(&widths)
+            .flex(self.flex)
+            .spacing(self.column_spacing)
+            .split_with_spacers(area);
+
+        if widths.len() == self.widths.len() {
+            return (width, layout, spacers, sources);
+        }
+
+        // put the collapsed columns back in with width 0.
+        let mut l_columns = Vec::with_capacity(self.widths.len());
+        let mut l_spacers = Vec::with_capacity(self.widths.len() + 1);
+        l_spacers.push(spacers[0]);
+        let mut idx = 0;
+        for col in 0..self.widths.len() {
+            if collapsed.contains(&col) {
+                let x = spacers[idx].right();
+                l_columns.push(Rect::new(x, 0, 0, 0));
+                l_spacers.push(Rect::new(x, 0, 0, 0));
+            } else {
+                l_columns.push(layout[idx]);
+                l_spacers.push(spacers[idx + 1]);
+                idx += 1;
+            }
+        }
+
+        (width, l_columns.into(), l_spacers.into(), sources)
+    }
+
+    // Height of the header. Takes care of wrapping the header cells.
+    fn header_height(&self, l_columns: &[Rect]) -> u16 {
+        if self.list {
+            return 0;
+        }
+        let Some(header) = &self.header else {
+            return 0;
+        };
+        if !self.wrap_header || header.fixed_height {
+            return header.content_height();
+        }
+        header
+            .column_spans(l_columns.len())
+            .into_iter()
+            .enumerate()
+            .filter_map(|(col, span)| span.map(|(idx, span)| (col, idx, span)))
+            .map(|(col, idx, span)| {
+                let area = span_area(l_columns, col, span, 0);
+                let width = if self.span_decoration(col, span).is_some() {
+                    area.width.saturating_sub(1)
+                } else {
+                    area.width
+                };
+                wrap_text(&header.cells[idx].content, width).height() as u16
+            })
+            .fold(header.content_height(), max)
+    }
+
+    // Decoration for the header cell.
+    fn decoration(&self, col: usize) -> Option<Decoration> {
+        self.header_decorations
+            .iter()
+            .find(|(c, _)| *c == col)
+            .map(|(_, d)| *d)
+    }
+
+    // Decoration for a header cell spanning columns. Any of the
+    // spanned columns can have it, the last one wins.
+    fn span_decoration(&self, col: usize, span: usize) -> Option<Decoration> {
+        (col..col + span).rev().find_map(|c| self.decoration(c))
+    }
+
+    // Layout header/table/footer
+    #[inline]
+    fn layout_areas(&self, area: Rect, header_height: u16) -> Rc<[Rect]> {
+        let heights = vec![
+            Constraint::Length(header_height),
+            Constraint::Fill(1),
+            Constraint::Length(match &self.footer {
+                Some(footer) if !self.list => footer.content_height(),
+                _ => 0,
+            }),
+        ];
+
+        Layout::vertical(heights).split(area)
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl<'a, Selection, Kind: DataKind<'a>> StatefulWidgetRef for Table<'a, Selection, Kind>
+where
+    Selection: TableSelection,
+{
+    type State = TableState<Selection>;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let iter = self.data.iter();
+        self.render_iter(iter, area, buf, state);
+    }
+}
+
+impl<'a, Selection, Kind: DataKind<'a>> Table<'a, Selection, Kind>
+where
+    Selection: TableSelection,
+{
+    /// Render the table to a string with ANSI escape codes.
+    ///
+    /// With a height this renders the region that is visible with
+    /// the current state. Without a height all rows are rendered,
+    /// starting with the first row.
+    /// TableDataIter needs a row count for this, otherwise only
+    /// one row per counted row is assumed.
+    ///
+    /// This renders with a copy of the state, the given state
+    /// is left as is.
+    ///
+    /// See [export](crate::export).
+    pub fn to_ansi(self, width: u16, height: Option<u16>, state: &TableState<Selection>) -> String
+    where
+        Selection: Clone,
+    {
+        let mut state = state.clone();
+        let height = match height {
+            Some(height) => height,
+            None => {
+                state.vscroll.set_offset(0);
+                state.line_offset = (0, 0);
+                self.full_height(width, &mut state)
+            }
+        };
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        self.render(area, &mut buf, &mut state);
+        buffer_to_ansi(&buf)
+    }
+
+    // Height needed to show all rows.
+    fn full_height(&self, width: u16, state: &mut TableState<Selection>) -> u16 {
+        let (rows, height) = match &self.data {
+            DataRepr::None => (0, 0),
+            DataRepr::Text(v) => (
+                v.rows(),
+                (0..v.rows()).map(|r| v.row_height(r) as usize).sum(),
+            ),
+            DataRepr::Data(v) => (
+                v.rows(),
+                (0..v.rows()).map(|r| v.row_height(r) as usize).sum(),
+            ),
+            DataRepr::Iter(v) => {
+                let rows = v.rows().unwrap_or_default();
+                (rows, rows)
+            }
+        };
+        state.rows = rows;
+        state.columns = if self.list { 1 } else { self.widths.len() };
+
+        // block and scrollbars.
+        let area = Rect::new(0, 0, width, u16::MAX / 2);
+        let inner = ScrollArea::new()
+            .block(self.block.as_ref())
+            .h_scroll(self.hscroll.as_ref())
+            .v_scroll(self.vscroll.as_ref())
+            .inner(area, Some(&state.hscroll), Some(&state.vscroll));
+        state.inner = Rect::new(inner.x, inner.y, inner.width, 0);
+
+        // gutters narrow the columns, which matters for a wrapped header.
+        let gutter_width = self.gutter_width(state);
+        let gutter_width =
+            gutter_width + min(self.app_gutter, inner.width.saturating_sub(gutter_width));
+        let minimap_width = min(self.minimap, inner.width.saturating_sub(gutter_width));
+        let content_width = inner.width.saturating_sub(gutter_width + minimap_width);
+        let (_, l_columns, _, _) = self.layout_columns(
+            content_width,
+            &state.width_overrides,
+            &state.collapsed_columns,
+            state.latched_width,
+        );
+
+        let header = self.header_height(l_columns.as_ref());
+        let footer = match &self.footer {
+            Some(footer) if !self.list => footer.content_height(),
+            _ => 0,
+        };
+        let detail = match self.detail_pane {
+            DetailPane::Bottom(height)
+                if state
+                    .selection
+                    .lead_selection()
+                    .is_some_and(|(_, row)| row < rows) =>
+            {
+                height
+            }
+            _ => 0,
+        };
+        let decoration = area.height - inner.height;
+
+        (height + header as usize + footer as usize + detail as usize + decoration as usize)
+            .min(u16::MAX as usize) as u16
+    }
+}
+
+impl<'a, Selection, Kind: DataKind<'a>> Table<'a, Selection, Kind>
+where
+    Selection: TableSelection,
+{
+    /// Checks for common misconfigurations.
+    ///
+    /// * rows with more cells than there are column widths.
+    /// * select styles the selection model never uses.
+    /// * auto_layout_width with unsupported constraints.
+    ///
+    /// In debug builds this is run once with the first render
+    /// and the findings are logged.
+    pub fn validate(&self) -> Vec<ConfigError> {
+        let mut result = Vec::new();
+
+        let widths = self.widths.len();
+        let mut columns = 0;
+        for row in [&self.header, &self.footer].into_iter().flatten() {
+            columns = max(columns, row.cells.iter().map(|v| max(v.span, 1)).sum());
+        }
+        if let DataRepr::Text(data) = &self.data {
+            for row in &data.rows {
+                columns = max(columns, row.cells.len());
+            }
+        }
+        if !self.list && columns > widths {
+            result.push(ConfigError::ColumnCount { widths, columns });
+        }
+
+        if !Selection::selects_rows() && self.select_row_style.is_some() {
+            result.push(ConfigError::SelectStyle("select_row_style"));
+        }
+        if !Selection::selects_cells() {
+            for (name, style) in [
+                ("select_column_style", self.select_column_style),
+                ("select_cell_style", self.select_cell_style),
+                ("select_header_style", self.select_header_style),
+                ("select_footer_style", self.select_footer_style),
+            ] {
+                if style.is_some() {
+                    result.push(ConfigError::SelectStyle(name));
+                }
+            }
+        }
+
+        if self.auto_layout_width && self.layout_width.is_none() {
+            for (col, width) in self.widths.iter().enumerate() {
+                if !matches!(
+                    width,
+                    Constraint::Length(_) | Constraint::Min(_) | Constraint::Max(_)
+                ) {
+                    result.push(ConfigError::AutoLayoutWidth(col));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the table if [validate](Table::validate) finds no
+    /// problems, otherwise the first problem.
+    pub fn try_build(self) -> Result<Self, ConfigError> {
+        match self.validate().into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(self),
+        }
+    }
+}
+
+impl<'a, Selection, Kind: DataKind<'a>> StatefulWidget for Table<'a, Selection, Kind>
+where
+    Selection: TableSelection,
+{
+    type State = TableState<Selection>;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if state.high_contrast {
+            self.apply_high_contrast();
+        }
+        let iter = mem::replace(&mut self.data, DataRepr::None).into_iter();
+        self.render_iter(iter, area, buf, state);
+    }
+}
+
+impl<'a, Selection, Kind: DataKind<'a>> Table<'a, Selection, Kind>
+where
+    Selection: TableSelection,
+{
+    /// Everything that changes the visible rows for a
+    /// split render. See [Table::progressive].
+    fn render_key(&self, state: &TableState<Selection>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        state.vscroll.offset().hash(&mut hasher);
+        state.line_offset.1.hash(&mut hasher);
+        state.hscroll.offset().hash(&mut hasher);
+        state.data_version.hash(&mut hasher);
+        state.table_area.hash(&mut hasher);
+        state.column_layout.hash(&mut hasher);
+        state.hover_row.hash(&mut hasher);
+        state.indicator_row.hash(&mut hasher);
+        state.focus.get().hash(&mut hasher);
+        state.drag_range.hash(&mut hasher);
+        state.annotations.len().hash(&mut hasher);
+        self.row_order.hash(&mut hasher);
+        self.highlight
+            .map(|v| (v.as_ptr() as usize, v.len()))
+            .hash(&mut hasher);
+        // selection as shown.
+        let offset = state.vscroll.offset();
+        let end = min(offset + state.table_area.height as usize, state.rows);
+        for col in 0..state.columns {
+            state.selection.is_selected_column(col).hash(&mut hasher);
+        }
+        for row in offset..end {
+            state.selection.is_selected_row(row).hash(&mut hasher);
+            for col in 0..state.columns {
+                state.selection.is_selected_cell(col, row).hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Render an Iterator over TableRowData.
+    ///
+    /// rows: If the row number is known, this can help.
+    ///
+    fn render_iter<'b>(
+        &self,
+        mut data: DataReprIter<'a, 'b>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        state.row_order = None;
+        if let Some(order) = self.row_order {
+            (data, state.row_order) = data.ordered(order);
+        }
+        if let Some(rows) = data.rows() {
+            state.rows = rows;
+        }
+        let version = data.version();
+        state.data_changed = version != state.data_version;
+        state.data_version = version;
+        if state.data_changed && state.rendered {
+            state.latched_width = 0;
+        }
+        state.apply_requests();
+        state.apply_pending_scroll();
+        #[cfg(debug_assertions)]
+        if !state.config_checked {
+            state.config_checked = true;
+            for err in self.validate() {
+                log::warn!("Table: {}", err);
+            }
+        }
+        state.columns = if self.list { 1 } else { self.widths.len() };
+        state.area = area;
+
+        // detail pane
+        let (area, detail_area) = self.layout_detail(area, state);
+        state.detail_area = detail_area;
+
+        // keep the space for collapsed scrollbars.
+        let (hscroll, vscroll) = if self.reserve_scrollbars {
+            (
+                self.hscroll.clone().map(reserve_scrollbar),
+                self.vscroll.clone().map(reserve_scrollbar),
+            )
+        } else {
+            (None, None)
+        };
+        let sa = ScrollArea::new()
+            .style(self.style)
+            .block(self.block.as_ref())
+            .h_scroll(hscroll.as_ref().or(self.hscroll.as_ref()))
+            .v_scroll(vscroll.as_ref().or(self.vscroll.as_ref()));
+        state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
+
+        // row-number gutter
+        let gutter_width = self.gutter_width(state);
+        state.gutter_area = Rect::new(
+            state.inner.x,
+            state.inner.y,
+            gutter_width,
+            state.inner.height,
+        );
+        // app gutter
+        let app_gutter_width = min(self.app_gutter, state.inner.width - gutter_width);
+        state.app_gutter_area = Rect::new(
+            state.inner.x + gutter_width,
+            state.inner.y,
+            app_gutter_width,
+            state.inner.height,
+        );
+        let gutter_width = gutter_width + app_gutter_width;
+        // minimap
+        let minimap_width = min(self.minimap, state.inner.width.saturating_sub(gutter_width));
+        state.minimap_area = Rect::new(
+            state.inner.right() - minimap_width,
+            state.inner.y,
+            minimap_width,
+            state.inner.height,
+        );
+        let content = Rect::new(
+            state.inner.x + gutter_width,
+            state.inner.y,
+            state.inner.width - gutter_width - minimap_width,
+            state.inner.height,
+        );
+
+        // horizontal layout
+        let (width, l_columns, l_spacers, width_sources) = self.layout_columns(
+            content.width,
+            &state.width_overrides,
+            &state.collapsed_columns,
+            state.latched_width,
+        );
+        state.width_sources = width_sources;
+        if self.latch_layout_width {
+            state.latched_width = width;
+        }
+
+        let header_height = self.header_height(l_columns.as_ref());
+        let l_rows = self.layout_areas(content, header_height);
+        state.header_area = l_rows[0];
+        state.table_area = l_rows[1];
+        state.footer_area = l_rows[2];
+
+        // columns pinned to the right.
+        state.pinned_columns = 0;
+        state.pinned_width = 0;
+        state.pinned_shift = 0;
+        if self.pin_right > 0 && !self.list && state.columns > self.pin_right {
+            let pin_x = l_columns[state.columns - self.pin_right].x;
+            let pinned_width = width.saturating_sub(pin_x);
+            if width > state.table_area.width && pinned_width < state.table_area.width {
+                state.pinned_columns = self.pin_right;
+                state.pinned_width = pinned_width;
+                state.pinned_shift = (width - state.table_area.width) as usize;
+            }
+        }
+
+        self.calculate_column_areas(state.columns, l_columns.as_ref(), l_spacers.as_ref(), state);
+
+        // render block+scroll
+        sa.render(
+            area,
+            buf,
+            &mut ScrollAreaState::new()
+                .h_scroll(&mut state.hscroll)
+                .v_scroll(&mut state.vscroll),
+        );
+
+        // render header & footer
+        self.render_header(
+            state.columns,
+            width,
+            l_columns.as_ref(),
+            l_spacers.as_ref(),
+            state.header_area,
+            buf,
+            state,
+        );
+        self.render_footer(
+            state.columns,
+            width,
+            l_columns.as_ref(),
+            l_spacers.as_ref(),
+            state.footer_area,
+            buf,
+            state,
+        );
+
+        self.render_watermark(buf, state);
+
+        // render table
+        state.row_areas.clear();
+        state.badges.clear();
+        state.app_gutter_areas.clear();
+        state.row_heights.clear();
+        state.diagnostics.clear();
+        state.vscroll.set_page_len(0);
+        state
+            .hscroll
+            .set_page_len(area.width.saturating_sub(state.pinned_width) as usize);
+
+        let mut row_buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        // each cell renders to a scratch buffer and only the cell area
+        // is copied back. this clips anything written outside the cell.
+        let mut cell_buf = Buffer::empty(Rect::default());
+        let mut row = None;
+        let mut row_y = state.table_area.y;
+        let mut row_heights = Vec::new();
+        #[cfg(debug_assertions)]
+        let mut insane_offset = false;
+        let mut data_end = false;
+        let mut peek_text = None;
+
+        // split the render across two frames?
+        let split_y = self
+            .progressive
+            .filter(|v| state.table_area.height > *v)
+            .map(|_| state.table_area.y + state.table_area.height / 2);
+        let mut split = false;
+
+        let mut ctx = TableContext {
+            focus: state.focus.get(),
+            selected_cell: false,
+            selected_row: false,
+            selected_column: false,
+            style: self.style,
+            row_style: None,
+            select_style: None,
+            composed_style: self.style,
+            space_area: Default::default(),
+            row_area: Default::default(),
+            highlight: Default::default(),
+            highlight_style: self.highlight_style,
+            patch_order: self.patch_order,
+            non_exhaustive: NonExhaustive,
+        };
+
+        // selection styles are the same for the whole render.
+        let select_cell_style = self.patch_select(
+            self.select_cell_style,
+            state.focus.get(),
+            self.show_cell_focus,
+        );
+        // use a fallback if no row-selected style is set.
+        let select_row_style = if self.select_row_style.is_some() {
+            self.patch_select(
+                self.select_row_style,
+                state.focus.get(),
+                self.show_row_focus,
+            )
+        } else {
+            self.patch_select(Some(self.style), state.focus.get(), self.show_row_focus)
+        };
+        let select_column_style = self.patch_select(
+            self.select_column_style,
+            state.focus.get(),
+            self.show_column_focus,
+        );
+
+        // initial position
+        if !state.rendered {
+            match self.start_at {
+                Some(StartAt::Row(row)) => {
+                    state.vscroll.set_offset(row);
+                }
+                Some(StartAt::End) => {
+                    if let Some(rows) = data.rows() {
+                        // guess with row-height 1, fixed below.
+                        state
+                            .vscroll
+                            .set_offset(rows.saturating_sub(state.table_area.height as usize));
+                    }
+                }
+                None => {}
+            }
+        }
+
+        // snap to the start of a page.
+        state.paged = self.paged;
+        if self.paged {
+            let mut offset = state.align_page_offset(state.vscroll.offset());
+            if state.rendered {
+                offset = min(offset, state.vscroll.max_offset());
+            }
+            state.vscroll.set_offset(offset);
+            state.paged_offset = offset;
+        }
+
+        // keep the last row visible.
+        if state.follow {
+            if state.vscroll.offset() < state.follow_offset {
+                // scrolled up.
+                state.follow = false;
+            } else if let Some(rows) = data.rows() {
+                let offset = self.tail_offset(&data, rows, state.table_area.height);
+                state.vscroll.set_offset(offset);
+                state.follow_offset = offset;
+            }
+        }
+
+        // the top half is only reused for the frame right after
+        // the split. a split only happens if the view changed,
+        // otherwise everything is rendered.
+        let key = split_y.map(|_| self.render_key(state));
+        let (continued, unchanged) = match state.render_continuation.take() {
+            Some(v) if v.pending && Some(v.key) == key && !state.is_animating() => (Some(v), true),
+            Some(v) => (None, Some(v.key) == key),
+            None => (None, false),
+        };
+        let splitting = split_y.is_some() && continued.is_none() && !unchanged;
+        if let Some(continued) = &continued {
+            state.badges.extend(continued.badges.iter().copied());
+        }
+
+        let mut formatted = data.format_rows(
+            state.vscroll.offset()..state.vscroll.offset() + state.table_area.height as usize,
+        );
+
+        if data.nth(state.vscroll.offset()) {
+            row = Some(state.vscroll.offset());
+            loop {
+                let mut formatted_row = formatted
+                    .iter_mut()
+                    .find(|v| Some(v.row) == row)
+                    .map(|v| v.cells.as_mut_slice());
+                ctx.row_style = data.row_style();
+                if let Some(hover_style) = self.hover_style {
+                    if state.hover_row == row {
+                        ctx.row_style =
+                            Some(ctx.row_style.unwrap_or(self.style).patch(hover_style));
+                    }
+                }
+                if let Some(indicator_style) = self.indicator_style {
+                    if state.indicator_row.is_some() && state.indicator_row == row {
+                        ctx.row_style =
+                            Some(ctx.row_style.unwrap_or(self.style).patch(indicator_style));
+                    }
+                }
+                // We render each row to a temporary buffer.
+                // For ease of use we start each row at 0,0.
+                // We still only render at least partially visible cells.
+                let row_height = match row.and_then(|v| state.animated_rows.get(&v)) {
+                    Some((height, _)) => *height,
+                    None => data.row_height(),
+                };
+                let render_row_area = Rect::new(0, 0, width, row_height);
+                // lines of the first row scrolled out at the top.
+                let skip = if row == Some(state.vscroll.offset())
+                    && state.line_offset.0 == state.vscroll.offset()
+                {
+                    let skip = min(
+                        state.line_offset.1,
+                        render_row_area.height.saturating_sub(1),
+                    );
+                    state.line_offset.1 = skip;
+                    skip
+                } else {
+                    0
+                };
+                ctx.row_area = render_row_area;
+                row_buf.resize(render_row_area);
+                cell_buf.resize(render_row_area);
+                if let Some(row_style) = ctx.row_style {
+                    row_buf.set_style(render_row_area, row_style);
+                } else {
+                    row_buf.set_style(render_row_area, self.style);
+                }
+                if let Some(progress_style) = self.progress_style {
+                    if let Some(progress) = data.row_progress() {
+                        let progress = progress.clamp(0.0, 1.0);
+                        let progress_width =
+                            (render_row_area.width as f32 * progress).round() as u16;
+                        row_buf.set_style(
+                            Rect::new(0, 0, progress_width, render_row_area.height),
+                            progress_style,
+                        );
+                    }
+                }
+                row_heights.push(render_row_area.height);
+
+                // composed styles for this row.
+                let row_base_style = match ctx.row_style {
+                    Some(row_style) => self.style.patch(row_style),
+                    None => self.style,
+                };
+                let row_style = ctx.row_style;
+                let compose = |select_style: Option<Style>| match (select_style, row_style) {
+                    (Some(select_style), Some(row_style))
+                        if self.patch_order == PatchOrder::RowLast =>
+                    {
+                        self.style.patch(select_style).patch(row_style)
+                    }
+                    (Some(select_style), _) => row_base_style.patch(select_style),
+                    (None, _) => row_base_style,
+                };
+                let composed_cell_style = compose(select_cell_style);
+                let composed_row_style = compose(select_row_style);
+                let composed_column_style = compose(select_column_style);
+
+                // Target area for the finished row.
+                let visible_row_area = Rect::new(
+                    state.table_area.x,
+                    row_y,
+                    state.table_area.width,
+                    render_row_area.height - skip,
+                )
+                .intersection(state.table_area);
+                state.row_areas.push(visible_row_area);
+                state.app_gutter_areas.push(Rect::new(
+                    state.app_gutter_area.x,
+                    visible_row_area.y,
+                    state.app_gutter_area.width,
+                    visible_row_area.height,
+                ));
+                state.row_heights.push(render_row_area.height);
+                // only count fully visible rows.
+                if render_row_area.height - skip == visible_row_area.height {
+                    state.vscroll.set_page_len(state.vscroll.page_len() + 1);
+                }
+
+                if let Some((peek_col, peek_row)) = state.peek {
+                    if row == Some(peek_row) {
+                        peek_text = data.cell_text(peek_col).map(|v| v.into_owned());
+                    }
+                }
+
+                let row_matches = self.highlight.zip(row).and_then(|(v, row)| {
+                    v.binary_search_by_key(&row, |m| m.row)
+                        .ok()
+                        .map(|idx| &v[idx])
+                });
+
+                // can skip this entirely
+                let cached = match (&continued, split_y) {
+                    (Some(_), Some(split_y)) => visible_row_area.bottom() <= split_y,
+                    _ => false,
+                };
+                if render_row_area.height > 0 && !cached {
+                    let mut col = 0;
+                    loop {
+                        if col >= state.columns {
+                            break;
+                        }
+
+                        let render_cell_area = Rect::new(
+                            l_columns[col].x,
+                            0,
+                            l_columns[col].width,
+                            render_row_area.height,
+                        );
+                        ctx.space_area = Rect::new(
+                            l_spacers[col + 1].x,
+                            0,
+                            l_spacers[col + 1].width,
+                            render_row_area.height,
+                        );
+
+                        if state.selection.is_selected_cell(col, row.expect("row")) {
+                            ctx.selected_cell = true;
+                            ctx.selected_row = false;
+                            ctx.selected_column = false;
+                            ctx.select_style = select_cell_style;
+                            ctx.composed_style = composed_cell_style;
+                        } else if state.selection.is_selected_row(row.expect("row")) {
+                            ctx.selected_cell = false;
+                            ctx.selected_row = true;
+                            ctx.selected_column = false;
+                            ctx.select_style = select_row_style;
+                            ctx.composed_style = composed_row_style;
+                        } else if state.selection.is_selected_column(col) {
+                            ctx.selected_cell = false;
+                            ctx.selected_row = false;
+                            ctx.selected_column = true;
+                            ctx.select_style = select_column_style;
+                            ctx.composed_style = composed_column_style;
+                        } else {
+                            ctx.selected_cell = false;
+                            ctx.selected_row = false;
+                            ctx.selected_column = false;
+                            ctx.select_style = None;
+                            ctx.composed_style = row_base_style;
+                        }
+
+                        // partially visible?
+                        if render_cell_area.right() > state.hscroll.offset as u16
+                            || render_cell_area.left() < state.hscroll.offset as u16 + area.width
+                        {
+                            if let Some(select_style) = ctx.select_style {
+                                row_buf.set_style(render_cell_area, select_style);
+                                if self.fill_space {
+                                    row_buf.set_style(ctx.space_area, select_style);
+                                }
+                                if let Some(row_style) =
+                                    row_style.filter(|_| self.patch_order == PatchOrder::RowLast)
+                                {
+                                    row_buf.set_style(render_cell_area, row_style);
+                                    if self.fill_space {
+                                        row_buf.set_style(ctx.space_area, row_style);
+                                    }
+                                }
+                            }
+                            ctx.highlight.clear();
+                            if let Some(row_matches) = row_matches {
+                                if let Some(cell_match) =
+                                    row_matches.cells.iter().find(|v| v.column == col)
+                                {
+                                    ctx.highlight.extend(cell_match.spans.iter().cloned());
+                                }
+                            }
+
+                            let clip_area = if ctx.space_area.is_empty() {
+                                render_cell_area
+                            } else {
+                                render_cell_area.union(ctx.space_area)
+                            };
+                            copy_area(&row_buf, clip_area, &mut cell_buf);
+                            if let Some(text) = formatted_row.as_mut().and_then(|v| v.get_mut(col))
+                            {
+                                mem::take(text).render(render_cell_area, &mut cell_buf);
+                            } else if self.catch_cell_panics {
+                                let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                                    data.render_cell(&ctx, col, render_cell_area, &mut cell_buf);
+                                }));
+                                if let Err(err) = res {
+                                    let message = if let Some(msg) = err.downcast_ref::<&str>() {
+                                        msg.to_string()
+                                    } else if let Some(msg) = err.downcast_ref::<String>() {
+                                        msg.clone()
+                                    } else {
+                                        "render_cell panicked".to_string()
+                                    };
+                                    // the cell might be half done.
+                                    copy_area(&row_buf, clip_area, &mut cell_buf);
+                                    cell_buf
+                                        .set_style(render_cell_area, Style::new().white().on_red());
+                                    cell_buf.set_stringn(
+                                        render_cell_area.x,
+                                        render_cell_area.y,
+                                        format!("! {}", message),
+                                        render_cell_area.width as usize,
+                                        Style::new().white().on_red(),
+                                    );
+                                    state.diagnostics.errors.push(CellError {
+                                        column: col,
+                                        row: row.expect("row"),
+                                        message,
+                                    });
+                                }
+                            } else {
+                                data.render_cell(&ctx, col, render_cell_area, &mut cell_buf);
+                            }
+                            if !ctx.highlight.is_empty() {
+                                if let Some(text) = data.cell_text(col) {
+                                    patch_ranges(
+                                        &mut cell_buf,
+                                        render_cell_area,
+                                        text.as_ref(),
+                                        &ctx.highlight,
+                                        self.highlight_style
+                                            .unwrap_or_else(|| Style::new().reversed()),
+                                    );
+                                }
+                            }
+                            copy_area(&cell_buf, clip_area, &mut row_buf);
+                            if self.fill_space && !ctx.space_area.is_empty() {
+                                ctx.apply_cell_style(&mut row_buf, ctx.space_area, None);
+                            }
+                            if let Some(badge) = data.cell_badge(col) {
+                                self.render_badge(badge, render_cell_area, &mut row_buf);
+                                state.badges.push((col, row.expect("row")));
+                            }
+                            if state.annotations.contains_key(&(col, row.expect("row"))) {
+                                self.render_annotation_marker(render_cell_area, &mut row_buf);
+                            }
+                        }
+
+                        col += 1;
+                    }
+
+                    if skip > 0 {
+                        let skip_cells = skip as usize * row_buf.area.width as usize;
+                        row_buf.content.drain(0..skip_cells);
+                        row_buf.area.height -= skip;
+                    }
+
+                    // render shifted and clipped row.
+                    self.transfer_row(&mut row_buf, visible_row_area, buf, state);
+                }
+
+                if visible_row_area.bottom() >= state.table_area.bottom() {
+                    break;
+                }
+                if let (true, Some(split_y)) = (splitting, split_y) {
+                    if visible_row_area.bottom() >= split_y {
+                        split = true;
+                        break;
+                    }
+                }
+                if !data.nth(0) {
+                    data_end = true;
+                    break;
+                }
+                row = Some(row.expect("row").saturating_add(1));
+                row_y += render_row_area.height - skip;
+            }
+
+            if let (Some(continued), Some(split_y)) = (&continued, split_y) {
+                let top = continued.top.area;
+                let cached_area = state
+                    .row_areas
+                    .iter()
+                    .filter(|v| v.bottom() <= split_y)
+                    .fold(Rect::new(top.x, top.y, top.width, 0), |a, v| a.union(*v));
+                copy_area(&continued.top, cached_area.intersection(top), buf);
+            }
+            if let (true, Some(split_y)) = (split, split_y) {
+                // the top half is reused by the next render.
+                let top = Rect::new(
+                    state.table_area.x,
+                    state.table_area.y,
+                    state.table_area.width,
+                    split_y - state.table_area.y,
+                );
+                let mut top_buf = Buffer::empty(top);
+                copy_area(buf, top, &mut top_buf);
+                state.render_continuation = Some(RenderContinuation {
+                    key: key.expect("key"),
+                    badges: state
+                        .badges
+                        .iter()
+                        .copied()
+                        .filter(|(_, row)| {
+                            state
+                                .row_areas
+                                .get(row - state.vscroll.offset())
+                                .is_some_and(|v| v.bottom() <= split_y)
+                        })
+                        .collect(),
+                    top: top_buf,
+                    pending: true,
+                });
+            } else if let Some(key) = key {
+                state.render_continuation = Some(RenderContinuation {
+                    key,
+                    badges: Vec::new(),
+                    top: Buffer::default(),
+                    pending: false,
+                });
+            }
+
+            if self.anchor_bottom && data_end {
+                if let Some(last_area) = state.row_areas.last() {
+                    let gap = state.table_area.bottom().saturating_sub(last_area.bottom());
+                    if gap > 0 {
+                        shift_down(buf, state.table_area, gap, self.style);
+                        for area in state.row_areas.iter_mut() {
+                            area.y += gap;
+                        }
+                        for area in state.app_gutter_areas.iter_mut() {
+                            area.y += gap;
+                        }
+                    }
+                }
+            }
+        } else {
+            // can only guess whether the skip failed completely or partially.
+            // so don't alter row here.
+
+            // if this first skip fails all bets are off.
+            if data.rows().is_none() || data.rows() == Some(0) {
+                // this is ok
+            } else {
+                #[cfg(debug_assertions)]
+                {
+                    insane_offset = true;
+                }
+            }
+        }
+
+        self.render_row_numbers(buf, state);
+        if !state.app_gutter_area.is_empty() {
+            buf.set_style(
+                state.app_gutter_area,
+                self.app_gutter_style.unwrap_or(self.style),
+            );
+        }
+        self.render_minimap(&data, buf, state);
+        self.render_resize_handles(l_columns.as_ref(), l_spacers.as_ref(), buf, state);
+        self.render_column_groups(buf, state);
+        self.render_drag_range(buf, state);
+        self.render_overflow(buf, state);
+        self.render_peek(peek_text, buf, state);
+        self.render_annotation(buf, state);
+        self.render_detail(&data, buf, state);
+
+        // maximum offsets
+        let known_rows = state.known_rows.take();
+        #[allow(unused_variables)]
+        let algorithm;
+        #[allow(unused_assignments)]
+        {
+            if let Some(rows) = data.rows() {
+                algorithm = 0;
+                // skip to a guess for the last page.
+                // the guess uses row-height is 1, which may read a few more lines than
+                // absolutely necessary.
+                let skip_rows = rows
+                    .saturating_sub(row.map_or(0, |v| v + 1))
+                    .saturating_sub(state.table_area.height as usize);
+                // if we can still skip some rows, then the data so far is useless.
+                if skip_rows > 0 {
+                    row_heights.clear();
+                }
+                let nth_row = skip_rows;
+                // walk back from the end if possible.
+                if skip_rows > 0 && data.nth_back(0) {
+                    let mut sum_height = 0;
+                    loop {
+                        let row_height = data.row_height();
+                        row_heights.push(row_height);
+                        sum_height += row_height;
+                        if sum_height >= state.table_area.height
+                            || row_heights.len() >= rows
+                            || !data.nth_back(0)
+                        {
+                            break;
+                        }
+                    }
+                    row_heights.reverse();
+                    row = Some(rows - 1);
+                } else if data.nth(nth_row) {
+                    // collect the remaining row-heights.
+                    let mut sum_height = row_heights.iter().sum::<u16>();
+                    row = Some(row.map_or(nth_row, |row| row + nth_row + 1));
+                    loop {
+                        let row_height = data.row_height();
+                        row_heights.push(row_height);
+
+                        // Keep a rolling sum of the heights and drop unnecessary info.
+                        // We don't need more info, and there will be a lot more otherwise.
+                        sum_height += row_height;
+                        if sum_height
+                            .saturating_sub(row_heights.first().copied().unwrap_or_default())
+                            > state.table_area.height
+                        {
+                            let lost_height = row_heights.remove(0);
+                            sum_height -= lost_height;
+                        }
+
+                        if !data.nth(0) {
+                            break;
+                        }
+
+                        row = Some(row.expect("row") + 1);
+                        // if the given number of rows is too small, we would overshoot here.
+                        if row.expect("row") > rows {
+                            break;
+                        }
+                    }
+                    // we break before to have an accurate last page.
+                    // but we still want to report an error, if the count is off.
+                    while data.nth(0) {
+                        row = Some(row.expect("row") + 1);
+                    }
+                } else {
+                    // skip failed, maybe again?
+                    // leave everything as is and report later.
+                }
+
+                state.rows = rows;
+                state._counted_rows = row.map_or(0, |v| v + 1);
+
+                // have we got a page worth of data?
+                if let Some(last_page) = state.calc_last_page(row_heights) {
+                    state.vscroll.set_max_offset(state.rows - last_page);
+                } else {
+                    // we don't have enough data to establish the last page.
+                    // either there are not enough rows or the given row-count
+                    // was off. make a guess.
+                    state.vscroll.set_max_offset(
+                        state.rows.saturating_sub(state.table_area.height as usize),
+                    );
+                }
+            } else if self.no_row_count {
+                algorithm = 1;
+
+                let mut known_rows = known_rows;
+                if state.data_changed {
+                    state.estimated_rows = 0;
+                    known_rows = None;
+                }
+
+                // We need to feel out a bit beyond the page, otherwise
+                // we can't really stabilize the row count and the
+                // display starts flickering.
+                let mut at_end = true;
+                if row.is_some() {
+                    if data.nth(0) {
+                        // try one past page
+                        row = Some(row.expect("row").saturating_add(1));
+                        if data.nth(0) {
+                            // have an unknown number of rows left.
+                            at_end = false;
+                        }
+                    }
+                }
+                let seen = row.map_or(0, |v| v + 1);
+
+                let page = state.table_area.height as usize;
+                if at_end {
+                    state.rows = seen;
+                    state.estimated_rows = seen;
+                    state.vscroll.set_max_offset(max(
+                        state.rows.saturating_sub(page),
+                        min(state.vscroll.offset(), state.rows.saturating_sub(1)),
+                    ));
+                } else {
+                    state.rows = usize::MAX;
+                    state.known_rows = Some(max(known_rows.unwrap_or_default(), seen));
+                    // grow the estimate whenever we get close to it.
+                    // this converges with the number of doublings.
+                    if state.estimated_rows <= seen.saturating_add(page) {
+                        state.estimated_rows = max(
+                            state.estimated_rows.saturating_mul(2),
+                            seen.saturating_add(page).saturating_mul(2),
+                        );
+                    }
+                    state
+                        .vscroll
+                        .set_max_offset(state.estimated_rows.saturating_sub(page));
+                }
+                state._counted_rows = seen;
+                if state.vscroll.page_len() == 0 {
+                    state.vscroll.set_page_len(page);
+                }
+            } else if let Some(mut budget) = self.row_count_budget {
+                algorithm = 3;
+
+                // Continue where the last render stopped.
+                let next_row = row.map_or(0, |v| v + 1);
+                if let Some(counted) = state.counted_rows {
+                    if counted > next_row {
+                        // a single skip. this is only cheap if the
+                        // iterator implements nth() without stepping
+                        // through all the rows.
+                        if data.nth(counted - next_row - 1) {
+                            row = Some(counted - 1);
+                            row_heights = mem::take(&mut state.counted_heights);
+                            if row_heights.is_empty() {
+                                row_heights.push(data.row_height());
+                            }
+                        } else {
+                            // data has changed. leave everything as is
+                            // and start over with the next render.
+                            state.counted_rows = None;
+                        }
+                    }
+                }
+
+                let mut sum_height = row_heights.iter().sum::<u16>();
+                let mut finished = true;
+                loop {
+                    if budget == 0 {
+                        finished = false;
+                        break;
+                    }
+                    if !data.nth(0) {
+                        break;
+                    }
+                    budget -= 1;
+
+                    let row_height = data.row_height();
+                    row_heights.push(row_height);
+
+                    sum_height += row_height;
+                    if sum_height.saturating_sub(row_heights.first().copied().unwrap_or_default())
+                        > state.table_area.height
+                    {
+                        let lost_height = row_heights.remove(0);
+                        sum_height -= lost_height;
+                    }
+                    row = Some(row.map_or(0, |v| v + 1));
+                }
+
+                let counted = row.map_or(0, |v| v + 1);
+                if finished {
+                    state.counted_rows = None;
+                    state.counted_heights.clear();
+                    state.rows = counted;
+                    state._counted_rows = counted;
+
+                    if let Some(last_page) = state.calc_last_page(row_heights) {
+                        state.vscroll.set_max_offset(state.rows - last_page);
+                    } else {
+                        state.vscroll.set_max_offset(
+                            state.rows.saturating_sub(state.table_area.height as usize),
+                        );
+                    }
+                } else {
+                    state.counted_rows = Some(counted);
+                    state.counted_heights = row_heights;
+                    state.rows = max(state.rows, counted);
+                    state._counted_rows = state.rows;
+
+                    // not finished. make a guess.
+                    state.vscroll.set_max_offset(
+                        state.rows.saturating_sub(state.table_area.height as usize),
+                    );
+                }
+            } else if let Some((_, _, rows, max_offset)) =
+                state.cached_rows.filter(|(version, height, rows, _)| {
+                    Some(*version) == data.version()
+                        && *height == state.table_area.height
+                        && row.map_or(0, |v| v + 1) <= *rows
+                })
+            {
+                algorithm = 4;
+
+                // unchanged data, no need to count again.
+                state.rows = rows;
+                state._counted_rows = rows;
+                state.vscroll.set_max_offset(max_offset);
+            } else {
+                algorithm = 2;
+
+                // Read all the rest to establish the exact row-count.
+                let mut sum_height = row_heights.iter().sum::<u16>();
+                while data.nth(0) {
+                    let row_height = data.row_height();
+                    row_heights.push(row_height);
+
+                    // Keep a rolling sum of the heights and drop unnecessary info.
+                    // We don't need more info, and there will be a lot more otherwise.
+                    sum_height += row_height;
+                    if sum_height.saturating_sub(row_heights.first().copied().unwrap_or_default())
+                        > state.table_area.height
+                    {
+                        let lost_height = row_heights.remove(0);
+                        sum_height -= lost_height;
+                    }
+                    row = Some(row.map_or(0, |v| v + 1));
+                }
+
+                state.rows = row.map_or(0, |v| v + 1);
+                state._counted_rows = row.map_or(0, |v| v + 1);
+
+                // have we got a page worth of data?
+                if let Some(last_page) = state.calc_last_page(row_heights) {
+                    state.vscroll.set_max_offset(state.rows - last_page);
+                } else {
+                    state.vscroll.set_max_offset(0);
+                }
+
+                state.cached_rows = data.version().map(|version| {
+                    (
+                        version,
+                        state.table_area.height,
+                        state.rows,
+                        state.vscroll.max_offset(),
+                    )
+                });
+            }
+        }
+        {
+            state
+                .hscroll
+                .set_max_offset(width.saturating_sub(state.table_area.width) as usize);
+        }
+        if self.paged {
+            let row_height = state.row_heights.first().copied().unwrap_or(1);
+            state.page_size = max(1, state.table_area.height / max(1, row_height)) as usize;
+            if state.rows > 0 && state.rows != usize::MAX {
+                state
+                    .vscroll
+                    .set_max_offset((state.rows - 1) / state.page_size * state.page_size);
+            }
+        }
+        state.wheel_scroll_by = match self.wheel_scroll {
+            WheelScroll::Default => None,
+            WheelScroll::Rows(n) => Some(n),
+            WheelScroll::PagePercent(p) => {
+                let n = state.vscroll.page_len() * p as usize / 100;
+                Some(max(1, n))
+            }
+        };
+
+        if !state.rendered {
+            // exact last page for the next render.
+            // not possible without a row count.
+            if self.start_at == Some(StartAt::End) && !self.no_row_count {
+                state.vscroll.set_offset(state.vscroll.max_offset());
+            }
+            state.rendered = true;
+        }
+
+        if self.overscroll > 0 && state.rows > 0 && state.rows != usize::MAX {
+            let max_offset = min(
+                state.vscroll.max_offset().saturating_add(self.overscroll),
+                state.rows - 1,
+            );
+            state.vscroll.set_max_offset(max_offset);
+        }
+
+        #[cfg(debug_assertions)]
+        {
+            use std::fmt::Write;
+            let mut msg = String::new();
+            if insane_offset {
+                _= write!(msg,
+                          "Table::render:\n        offset {}\n        rows {}\n        iter-rows {}max\n    don't match up\nCode X{}X\n",
+                          state.vscroll.offset(), state.rows, state._counted_rows, algorithm
+                );
+            }
+            if state.rows != state._counted_rows {
+                _ = write!(
+                    msg,
+                    "Table::render:\n    rows {} don't match\n    iterated rows {}\nCode X{}X\n",
+                    state.rows, state._counted_rows, algorithm
+                );
+            }
+            if !msg.is_empty() {
+                use log::warn;
+                use ratatui::style::Stylize;
+                use ratatui::text::Text;
+
+                warn!("{}", &msg);
+                Text::from(msg)
+                    .white()
+                    .on_red()
+                    .render(state.table_area, buf);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn render_footer(
+        &self,
+        columns: usize,
+        width: u16,
+        l_columns: &[Rect],
+        l_spacers: &[Rect],
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        if self.list {
+            return;
+        }
+        if let Some(footer) = &self.footer {
+            let render_row_area = Rect::new(0, 0, width, area.height);
+            let mut row_buf = Buffer::empty(render_row_area);
+
+            row_buf.set_style(render_row_area, self.style);
+            if let Some(footer_style) = footer.style {
+                row_buf.set_style(render_row_area, footer_style);
+            } else if let Some(footer_style) = self.footer_style {
+                row_buf.set_style(render_row_area, footer_style);
+            }
+
+            let spans = footer.column_spans(columns);
+
+            let mut col = 0;
+            loop {
+                if col >= columns {
+                    break;
+                }
+
+                let render_cell_area =
+                    Rect::new(l_columns[col].x, 0, l_columns[col].width, area.height);
+                let render_space_area = Rect::new(
+                    l_spacers[col + 1].x,
+                    0,
+                    l_spacers[col + 1].width,
+                    area.height,
+                );
+
+                if state.selection.is_selected_column(col) {
+                    if let Some(selected_style) = self.patch_select(
+                        self.select_footer_style,
+                        state.focus.get(),
+                        self.show_footer_focus,
+                    ) {
+                        row_buf.set_style(render_cell_area, selected_style);
+                        row_buf.set_style(render_space_area, selected_style);
+                    }
+                };
+
+                // partially visible?
+                if render_cell_area.right() > state.hscroll.offset as u16
+                    || render_cell_area.left() < state.hscroll.offset as u16 + area.width
+                {
+                    if let Some((idx, span)) = spans[col] {
+                        let cell = &footer.cells[idx];
+                        let render_cell_area = span_area(l_columns, col, span, area.height);
+                        if let Some(cell_style) = cell.style {
+                            row_buf.set_style(render_cell_area, cell_style);
+                        }
+                        cell.content.clone().render(render_cell_area, &mut row_buf);
+                    }
+                }
+
+                col += 1;
+            }
+
+            // render shifted and clipped row.
+            self.transfer_row(&mut row_buf, area, buf, state);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn render_header(
+        &self,
+        columns: usize,
+        width: u16,
+        l_columns: &[Rect],
+        l_spacers: &[Rect],
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        if self.list {
+            return;
+        }
+        if let Some(header) = &self.header {
+            let render_row_area = Rect::new(0, 0, width, area.height);
+            let mut row_buf = Buffer::empty(render_row_area);
+
+            row_buf.set_style(render_row_area, self.style);
+            if let Some(header_style) = header.style {
+                row_buf.set_style(render_row_area, header_style);
+            } else if let Some(header_style) = self.header_style {
+                row_buf.set_style(render_row_area, header_style);
+            }
+
+            let spans = header.column_spans(columns);
+            // columns inside a spanning cell. their decoration is
+            // shown at the end of the span.
+            let mut covered = vec![false; columns];
+            for (col, span) in spans.iter().enumerate() {
+                if let Some((_, span)) = span {
+                    covered[col + 1..col + span].fill(true);
+                }
+            }
+
+            let mut col = 0;
+            loop {
+                if col >= columns {
+                    break;
+                }
+
+                let render_cell_area =
+                    Rect::new(l_columns[col].x, 0, l_columns[col].width, area.height);
+                let render_space_area = Rect::new(
+                    l_spacers[col + 1].x,
+                    0,
+                    l_spacers[col + 1].width,
+                    area.height,
+                );
+
+                if state.selection.is_selected_column(col) {
+                    if let Some(selected_style) = self.patch_select(
+                        self.select_header_style,
+                        state.focus.get(),
+                        self.show_header_focus,
+                    ) {
+                        row_buf.set_style(render_cell_area, selected_style);
+                        row_buf.set_style(render_space_area, selected_style);
+                    }
+                };
+
+                // partially visible?
+                if render_cell_area.right() > state.hscroll.offset as u16
+                    || render_cell_area.left() < state.hscroll.offset as u16 + area.width
+                {
+                    let mut decoration = None;
+                    let mut decoration_area = render_cell_area;
+                    if let Some((idx, span)) = spans[col] {
+                        let cell = &header.cells[idx];
+                        let render_cell_area = span_area(l_columns, col, span, area.height);
+                        if let Some(cell_style) = cell.style {
+                            row_buf.set_style(render_cell_area, cell_style);
+                        }
+                        decoration = self.span_decoration(col, span);
+                        decoration_area = render_cell_area;
+                        let mut content_area = render_cell_area;
+                        if decoration.is_some() {
+                            content_area.width = content_area.width.saturating_sub(1);
+                        }
+                        if self.wrap_header {
+                            wrap_text(&cell.content, content_area.width)
+                                .render(content_area, &mut row_buf);
+                        } else {
+                            cell.content.clone().render(content_area, &mut row_buf);
+                        }
+                    } else if !covered[col] {
+                        decoration = self.decoration(col);
+                    }
+                    if let Some(decoration) = decoration {
+                        if decoration_area.width > 0 {
+                            let x = decoration_area.right() - 1;
+                            if let Some(cell) = row_buf.cell_mut((x, 0)) {
+                                cell.set_char(decoration.symbol());
+                                if let Some(style) = self.decoration_style {
+                                    cell.set_style(style);
+                                }
+                            }
+                        }
+                    }
+                }
+
+                col += 1;
+            }
+
+            // render shifted and clipped row.
+            self.transfer_row(&mut row_buf, area, buf, state);
+        }
+    }
+
+    // Offset that shows the last row completely.
+    // Falls back to row-height 1 for TableDataIter.
+    fn tail_offset(&self, data: &DataReprIter<'_, '_>, rows: usize, height: u16) -> usize {
+        let mut sum = 0;
+        let mut offset = rows;
+        while offset > 0 {
+            let Some(h) = data.row_height_at(offset - 1) else {
+                return rows.saturating_sub(height as usize);
+            };
+            if sum + h > height && offset < rows {
+                break;
+            }
+            sum += h;
+            offset -= 1;
+        }
+        offset
+    }
+
+    // Width of the row-number gutter including one space.
+    fn gutter_width(&self, state: &TableState<Selection>) -> u16 {
+        if self.row_numbers == RowNumbers::None {
+            return 0;
+        }
+        // rows found so far if the row count is unknown.
+        let rows = state.known_rows.unwrap_or(state.rows);
+        let max_row = max(
+            rows,
+            state
+                .vscroll
+                .offset()
+                .saturating_add(state.inner.height as usize),
+        );
+        let width = max_row.max(1).ilog10() as u16 + 2;
+        min(width, state.inner.width)
+    }
+
+    fn render_row_numbers(&self, buf: &mut Buffer, state: &mut TableState<Selection>) {
+        if state.gutter_area.width == 0 {
+            return;
+        }
+
+        let style = self.row_number_style.unwrap_or(self.style);
+        buf.set_style(state.gutter_area, style);
+
+        let lead = match self.row_numbers {
+            RowNumbers::Relative => state.selection.lead_selection().map(|v| v.1),
+            _ => None,
+        };
+        let width = state.gutter_area.width.saturating_sub(1) as usize;
+        for (idx, row_area) in state.row_areas.iter().enumerate() {
+            let row = state.vscroll.offset() + idx;
+            let nr = match lead {
+                Some(lead) => row.abs_diff(lead),
+                None => row + 1,
+            };
+            buf.set_stringn(
+                state.gutter_area.x,
+                row_area.y,
+                format!("{:>width$}", nr, width = width),
+                width,
+                style,
+            );
+        }
+    }
+
+    fn render_badge(&self, badge: Badge, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let style = match self.badge_style {
+            Some(badge_style) => badge.style().patch(badge_style),
+            None => badge.style(),
+        };
+        if let Some(cell) = buf.cell_mut((area.right() - 1, area.y)) {
+            cell.set_char(badge.symbol());
+            cell.set_style(style);
+        }
+    }
+
+    fn render_annotation_marker(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let style = self
+            .annotation_style
+            .unwrap_or(Style::new().fg(ratatui::style::Color::Yellow));
+        if let Some(cell) = buf.cell_mut((area.x, area.y)) {
+            cell.set_char('◤');
+            cell.set_style(style);
+        }
+    }
+
+    fn render_column_groups(&self, buf: &mut Buffer, state: &TableState<Selection>) {
+        let Some(style) = self.column_group_style else {
+            return;
+        };
+        let header = state.header_area;
+        for (first, last) in state.column_groups.iter().copied() {
+            let mut area: Option<Rect> = None;
+            for col in first..=last {
+                if let Some(col_area) = state.column_areas.get(col) {
+                    if col_area.width > 0 {
+                        area = Some(area.map_or(*col_area, |v| v.union(*col_area)));
+                    }
+                }
+            }
+            if let Some(area) = area {
+                let area = Rect::new(area.x, header.y, area.width, header.height);
+                buf.set_style(area.intersection(header), style);
+            }
+        }
+    }
+
+    fn render_drag_range(&self, buf: &mut Buffer, state: &TableState<Selection>) {
+        let Some(((c0, r0), (c1, r1))) = state.drag_range else {
+            return;
+        };
+        let (c0, c1) = (min(c0, c1), max(c0, c1));
+        let (r0, r1) = (min(r0, r1), max(r0, r1));
+
+        // only visible cells.
+        let offset = state.vscroll.offset();
+        let r0 = max(r0, offset);
+        let r1 = min(r1, offset + state.row_areas.len().saturating_sub(1));
+        let mut area: Option<Rect> = None;
+        for row in r0..=r1 {
+            for col in c0..=c1 {
+                if let Some(cell_area) = state.cell_area(col, row) {
+                    area = Some(area.map_or(cell_area, |v| v.union(cell_area)));
+                }
+            }
+        }
+
+        if let Some(area) = area {
+            let style = self.drag_style.unwrap_or(Style::new().reversed());
+            buf.set_style(area, style);
+        }
+    }
+
+    fn render_watermark(&self, buf: &mut Buffer, state: &TableState<Selection>) {
+        let watermark = match &self.watermark {
+            None => return,
+            Some(Watermark::Render(render)) => {
+                render(state.table_area, buf);
+                return;
+            }
+            Some(Watermark::Text(text)) => text,
+        };
+        let area = state.table_area;
+        let height = min(watermark.height() as u16, area.height);
+        let area = Rect::new(
+            area.x,
+            area.y + (area.height - height) / 2,
+            area.width,
+            height,
+        );
+        let mut text = watermark.clone().centered();
+        if let Some(style) = self.watermark_style {
+            text = text.patch_style(style);
+        }
+        text.render(area, buf);
+    }
+
+    fn layout_detail(&self, area: Rect, state: &TableState<Selection>) -> (Rect, Rect) {
+        let selected = state
+            .selection
+            .lead_selection()
+            .is_some_and(|(_, row)| row < state.rows);
+        if !selected {
+            return (area, Rect::new(area.x, area.y, 0, 0));
+        }
+        match self.detail_pane {
+            DetailPane::None => (area, Rect::new(area.x, area.y, 0, 0)),
+            DetailPane::Right(width) => {
+                let width = min(width, area.width);
+                (
+                    Rect::new(area.x, area.y, area.width - width, area.height),
+                    Rect::new(area.right() - width, area.y, width, area.height),
+                )
+            }
+            DetailPane::Bottom(height) => {
+                let height = min(height, area.height);
+                (
+                    Rect::new(area.x, area.y, area.width, area.height - height),
+                    Rect::new(area.x, area.bottom() - height, area.width, height),
+                )
+            }
+        }
+    }
+
+    fn render_detail(
+        &self,
+        data: &DataReprIter<'_, '_>,
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        let area = state.detail_area;
+        if area.is_empty() {
+            return;
+        }
+        let Some((_, row)) = state.selection.lead_selection() else {
+            return;
+        };
+
+        buf.set_style(area, self.style);
+        let inner = if let Some(block) = &self.detail_block {
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
         } else {
-            area_width
+            area
+        };
+        data.render_detail(row, inner, buf);
+    }
+
+    fn render_minimap(
+        &self,
+        data: &DataReprIter<'_, '_>,
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        let area = state.minimap_area;
+        if area.is_empty() {
+            return;
+        }
+
+        let style = self.minimap_style.unwrap_or(self.style);
+        buf.set_style(area, style);
+
+        // scale like the scrollbar if the row count is unknown.
+        let rows = match state.known_rows {
+            Some(_) => state.estimated_rows,
+            None => state.rows,
+        };
+        let height = area.height as usize;
+        let first_visible = state.vscroll.offset();
+        let last_visible = first_visible.saturating_add(state.row_areas.len());
+
+        for line in 0..height {
+            let start = line.saturating_mul(rows) / height;
+            let end = max(
+                start.saturating_add(1),
+                (line + 1).saturating_mul(rows) / height,
+            );
+            if start >= rows {
+                break;
+            }
+
+            // sample a few rows per line.
+            let step = max(1, (end - start) / 8);
+            let summary = (start..end)
+                .step_by(step)
+                .find_map(|row| data.row_summary_style(row));
+
+            let visible = start < last_visible && end > first_visible;
+            let y = area.y + line as u16;
+            for x in area.left()..area.right() {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    if x == area.left() && visible {
+                        cell.set_symbol("┃");
+                    } else {
+                        cell.set_symbol(" ");
+                    }
+                    if let Some(summary) = summary {
+                        cell.set_style(style.patch(summary));
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_resize_handles(
+        &self,
+        l_columns: &[Rect],
+        l_spacers: &[Rect],
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        state.resize_handles = self.resize_handles;
+        state.resize_handle_pos.clear();
+        if !self.resize_handles {
+            return;
+        }
+
+        let area = state.header_area;
+        for col in 0..state.columns {
+            let (shift, view_x1, view_x2) = state.column_view(col);
+            let offset = shift as usize;
+            // the handle sits on the spacer, or on the last cell
+            // of the column if there is no spacer.
+            let (x, visible) = if l_spacers[col + 1].width > 0 {
+                (l_spacers[col + 1].x as usize, true)
+            } else {
+                ((l_columns[col].right() as usize).saturating_sub(1), false)
+            };
+
+            if x < offset + view_x1 as usize || x >= offset + view_x2 as usize {
+                state.resize_handle_pos.push(None);
+                continue;
+            }
+            let x = area.x + (x - offset) as u16;
+            state.resize_handle_pos.push(Some(x));
+
+            if !visible {
+                continue;
+            }
+            let style = if state.hover_handle == Some(col) || state.resize_drag == Some(col) {
+                self.resize_handle_hover_style
+                    .or_else(|| Some(revert_style(self.header_style.unwrap_or(self.style))))
+            } else {
+                self.resize_handle_style
+            };
+            for y in area.top()..area.bottom() {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_symbol("│");
+                    if let Some(style) = style {
+                        cell.set_style(style);
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_peek(
+        &self,
+        text: Option<String>,
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        state.peek_area = Rect::default();
+        state.peek_lines = 0;
+        let (Some((col, row)), Some(text)) = (state.peek, text) else {
+            return;
+        };
+        let Some((area, wrapped)) = Self::cell_popup(state, col, row, Text::from(text)) else {
+            return;
+        };
+        state.peek_lines = wrapped.lines.len();
+        state.peek_area = area;
+
+        let style = match self.peek_style {
+            Some(peek_style) => self.style.patch(peek_style),
+            None => self.style,
+        };
+        Clear.render(area, buf);
+        let block = Block::bordered().style(style);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        state.peek_offset = min(
+            state.peek_offset,
+            state.peek_lines.saturating_sub(inner.height as usize),
+        );
+        for (i, line) in wrapped
+            .lines
+            .iter()
+            .skip(state.peek_offset)
+            .take(inner.height as usize)
+            .enumerate()
+        {
+            let line_area = Rect::new(inner.x, inner.y + i as u16, inner.width, 1);
+            line.render(line_area, buf);
+        }
+    }
+
+    fn render_annotation(&self, buf: &mut Buffer, state: &mut TableState<Selection>) {
+        state.annotation_area = Rect::default();
+        let Some((col, row)) = state.annotation else {
+            return;
+        };
+        let Some(text) = state.annotations.get(&(col, row)).cloned() else {
+            return;
+        };
+        let Some((area, wrapped)) = Self::cell_popup(state, col, row, text) else {
+            return;
+        };
+        state.annotation_area = area;
+
+        let style = match self.annotation_style {
+            Some(annotation_style) => self.style.patch(annotation_style),
+            None => self.style,
+        };
+        Clear.render(area, buf);
+        let block = Block::bordered().style(style);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        wrapped.render(inner, buf);
+    }
+
+    /// Area for a popup below or above the given cell,
+    /// and the text wrapped to fit. None if the cell is not visible.
+    fn cell_popup<'t>(
+        state: &TableState<Selection>,
+        col: usize,
+        row: usize,
+        text: Text<'t>,
+    ) -> Option<(Rect, Text<'t>)> {
+        let row_area = row
+            .checked_sub(state.vscroll.offset())
+            .and_then(|v| state.row_areas.get(v))?;
+        let col_area = state.column_areas.get(col)?;
+        let table_area = state.table_area;
+        if table_area.width < 3 || table_area.height < 3 {
+            return None;
+        }
+
+        let width = min(
+            table_area.width,
+            max(col_area.width, table_area.width / 2).max(3),
+        );
+        let wrapped = wrap_text(&text, width - 2);
+        let height = min(table_area.height, wrapped.lines.len() as u16 + 2);
+
+        let x = min(col_area.x, table_area.right() - width);
+        let y = if row_area.bottom() + height <= table_area.bottom() {
+            row_area.bottom()
+        } else if row_area.y >= table_area.y + height {
+            row_area.y - height
+        } else {
+            table_area.y
+        };
+        Some((Rect::new(x, y, width, height), wrapped))
+    }
+
+    fn render_overflow(&self, buf: &mut Buffer, state: &mut TableState<Selection>) {
+        state.overflow_area = Rect::default();
+        if !self.overflow_indicator || state.hidden_columns == 0 {
+            return;
+        }
+
+        let text = format!("+{} ▸", state.hidden_columns);
+        let width = min(text.chars().count() as u16, state.inner.width);
+        // indicator goes into the header if possible.
+        let line = if state.header_area.height > 0 {
+            state.header_area
+        } else {
+            state.table_area
+        };
+        if line.height == 0 {
+            return;
+        }
+        state.overflow_area = Rect::new(line.right().saturating_sub(width), line.y, width, 1);
+
+        let style = self
+            .overflow_style
+            .unwrap_or_else(|| revert_style(self.header_style.unwrap_or(self.style)));
+        buf.set_stringn(
+            state.overflow_area.x,
+            state.overflow_area.y,
+            text,
+            width as usize,
+            style,
+        );
+    }
+
+    // Transfer a rendered row. The pinned columns go first,
+    // transfer_buffer resets the tmp-buffer.
+    fn transfer_row(
+        &self,
+        row_buf: &mut Buffer,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &TableState<Selection>,
+    ) {
+        let body_width = area.width.saturating_sub(state.pinned_width);
+        if state.pinned_columns > 0 {
+            let pinned_area = Rect::new(
+                area.x + body_width,
+                area.y,
+                area.width - body_width,
+                area.height,
+            );
+            transfer_columns(
+                row_buf,
+                state.pinned_shift as u16,
+                pinned_area,
+                body_width,
+                self.blend,
+                buf,
+            );
+        }
+        transfer_buffer(
+            row_buf,
+            state.hscroll.offset() as u16,
+            Rect::new(area.x, area.y, body_width, area.height),
+            self.blend,
+            buf,
+        );
+    }
+
+    fn calculate_column_areas(
+        &self,
+        columns: usize,
+        l_columns: &[Rect],
+        l_spacers: &[Rect],
+        state: &mut TableState<Selection>,
+    ) {
+        state.column_areas.clear();
+        state.column_layout.clear();
+        state.column_widths.clear();
+        state.hidden_columns = 0;
+
+        let mut col = 0;
+        loop {
+            if col >= columns {
+                break;
+            }
+            let (shift, view_x1, view_x2) = state.column_view(col);
+
+            state.column_widths.push(l_columns[col].width);
+            state.column_layout.push(Rect::new(
+                l_columns[col].x,
+                0,
+                l_columns[col].width + l_spacers[col + 1].width,
+                0,
+            ));
+
+            let cell_x1 = l_columns[col].x as isize;
+            let cell_x2 =
+                (l_columns[col].x + l_columns[col].width + l_spacers[col + 1].width) as isize;
+
+            let squish_x1 = cell_x1.saturating_sub(shift);
+            let squish_x2 = cell_x2.saturating_sub(shift);
+
+            let abs_x1 = max(0, squish_x1) as u16;
+            let abs_x2 = max(0, squish_x2) as u16;
+
+            let v_area = Rect::new(
+                state.table_area.x + abs_x1,
+                state.table_area.y,
+                abs_x2 - abs_x1,
+                state.table_area.height,
+            );
+            let view_area = Rect::new(
+                state.table_area.x + view_x1,
+                state.table_area.y,
+                view_x2 - view_x1,
+                state.table_area.height,
+            );
+            state.column_areas.push(v_area.intersection(view_area));
+
+            if squish_x1 >= view_x2 as isize {
+                state.hidden_columns += 1;
+            }
+
+            col += 1;
+        }
+    }
+
+    // Replace all styles with their high-contrast variant.
+    fn apply_high_contrast(&mut self) {
+        let hc = |v: Option<Style>| v.map(high_contrast_style);
+        let select = |v: Option<Style>| v.map(|v| high_contrast_style(v).bold());
+
+        self.style = high_contrast_style(self.style);
+        self.header_style = Some(
+            high_contrast_style(self.header_style.unwrap_or(self.style))
+                .bold()
+                .underlined(),
+        );
+        self.footer_style = hc(self.footer_style);
+
+        self.select_row_style = select(self.select_row_style);
+        self.select_column_style = select(self.select_column_style);
+        self.select_cell_style = select(self.select_cell_style);
+        self.select_header_style = select(self.select_header_style);
+        self.select_footer_style = select(self.select_footer_style);
+        self.focus_style = select(self.focus_style);
+
+        self.highlight_style = hc(self.highlight_style);
+        self.overflow_style = hc(self.overflow_style);
+        self.decoration_style = hc(self.decoration_style);
+        self.row_number_style = hc(self.row_number_style);
+        self.minimap_style = hc(self.minimap_style);
+        self.app_gutter_style = hc(self.app_gutter_style);
+        self.progress_style = hc(self.progress_style);
+        self.hover_style = hc(self.hover_style);
+        self.indicator_style = hc(self.indicator_style);
+        self.column_group_style = hc(self.column_group_style);
+        self.annotation_style = hc(self.annotation_style);
+        self.resize_handle_style = hc(self.resize_handle_style);
+        self.resize_handle_hover_style = hc(self.resize_handle_hover_style);
+        self.peek_style = hc(self.peek_style);
+        self.drag_style = hc(self.drag_style);
+        self.badge_style = hc(self.badge_style);
+        self.watermark_style = hc(self.watermark_style);
+        self.block = self.block.take().map(|v| v.style(self.style));
+    }
+
+    #[expect(clippy::collapsible_else_if)]
+    fn patch_select(&self, style: Option<Style>, focus: bool, show: bool) -> Option<Style> {
+        if let Some(style) = style {
+            if let Some(focus_style) = self.focus_style {
+                if focus && show {
+                    Some(style.patch(focus_style))
+                } else {
+                    Some(fallback_select_style(style))
+                }
+            } else {
+                if focus && show {
+                    Some(revert_style(style))
+                } else {
+                    Some(fallback_select_style(style))
+                }
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            header: None,
+            footer: None,
+            select_row: None,
+            select_column: None,
+            select_cell: None,
+            select_header: None,
+            select_footer: None,
+            show_row_focus: true, // non standard
+            show_column_focus: false,
+            show_cell_focus: false,
+            show_header_focus: false,
+            show_footer_focus: false,
+            focus_style: None,
+            highlight: None,
+            overflow: None,
+            decoration: None,
+            row_number: None,
+            minimap: None,
+            app_gutter: None,
+            progress: None,
+            hover: None,
+            indicator: None,
+            column_group: None,
+            annotation: None,
+            resize_handle: None,
+            resize_handle_hover: None,
+            peek: None,
+            drag: None,
+            badge: None,
+            block: None,
+            border_style: None,
+            scroll: None,
+            non_exhaustive: NonExhaustive,
         }
     }
+}
 
-    // Do the column-layout. Fill in missing columns, if necessary.
-    #[inline]
-    fn layout_columns(&self, width: u16) -> (u16, Rc<[Rect]>, Rc<[Rect]>) {
-        let width = self.total_width(width);
-        let area = Rect::new(0, 0, width, 0);
+impl<Selection: Clone> Clone for TableState<Selection> {
+    fn clone(&self) -> Self {
+        Self {
+            focus: FocusFlag::named(self.focus.name()),
+            area: self.area,
+            inner: self.inner,
+            header_area: self.header_area,
+            table_area: self.table_area,
+            row_areas: self.row_areas.clone(),
+            column_areas: self.column_areas.clone(),
+            column_layout: self.column_layout.clone(),
+            column_widths: self.column_widths.clone(),
+            width_sources: self.width_sources.clone(),
+            footer_area: self.footer_area,
+            rows: self.rows,
+            _counted_rows: self._counted_rows,
+            columns: self.columns,
+            vscroll: self.vscroll.clone(),
+            hscroll: self.hscroll.clone(),
+            selection: self.selection.clone(),
+            hidden_columns: self.hidden_columns,
+            overflow_area: self.overflow_area,
+            resize_handles: self.resize_handles,
+            resize_handle_pos: self.resize_handle_pos.clone(),
+            hover_handle: self.hover_handle,
+            resize_drag: Default::default(),
+            width_overrides: self.width_overrides.clone(),
+            gutter_area: self.gutter_area,
+            count_prefix: self.count_prefix.clone(),
+            jumps: self.jumps.clone(),
+            row_heights: self.row_heights.clone(),
+            minimap_area: self.minimap_area,
+            hover_row: self.hover_row,
+            latched_width: self.latched_width,
+            counted_rows: self.counted_rows,
+            diagnostics: self.diagnostics.clone(),
+            app_gutter_area: self.app_gutter_area,
+            app_gutter_areas: self.app_gutter_areas.clone(),
+            rendered: self.rendered,
+            on_focus_gained: self.on_focus_gained,
+            focus_gained_done: self.focus_gained_done,
+            peek: self.peek,
+            peek_offset: self.peek_offset,
+            peek_lines: self.peek_lines,
+            peek_area: self.peek_area,
+            detail_area: self.detail_area,
+            collapsed_columns: self.collapsed_columns.clone(),
+            scroll_coalesce: self.scroll_coalesce,
+            pending_scroll: self.pending_scroll,
+            triple_click: self.triple_click,
+            click_count: self.click_count,
+            last_click: self.last_click,
+            requests: self.requests.clone(),
+            drag_anchor: self.drag_anchor,
+            drag_range: self.drag_range,
+            badges: self.badges.clone(),
+            high_contrast: self.high_contrast,
+            config_checked: self.config_checked,
+            follow: self.follow,
+            follow_offset: self.follow_offset,
+            hscroll_focus: self.hscroll_focus,
+            vscroll_focus: self.vscroll_focus,
+            scrolloff: self.scrolloff,
+            pinned_columns: self.pinned_columns,
+            pinned_width: self.pinned_width,
+            pinned_shift: self.pinned_shift,
+            scroll_lines: self.scroll_lines,
+            line_offset: self.line_offset,
+            scroll_columns: self.scroll_columns,
+            data_version: self.data_version,
+            data_changed: self.data_changed,
+            show_inserted_at_top: self.show_inserted_at_top,
+            estimated_rows: self.estimated_rows,
+            touch: self.touch,
+            touch_swipe: self.touch_swipe,
+            cached_rows: self.cached_rows,
+            known_rows: self.known_rows,
+            drag_pos: self.drag_pos,
+            animated_rows: self.animated_rows.clone(),
+            page_scroll: self.page_scroll,
+            page_scroll_offset: self.page_scroll_offset,
+            indicator_row: self.indicator_row,
+            follow_indicator: self.follow_indicator,
+            column_groups: self.column_groups.clone(),
+            group_drag: None,
+            reported_rows: self.reported_rows,
+            annotations: self.annotations.clone(),
+            annotation: self.annotation,
+            annotation_area: self.annotation_area,
+            paged: self.paged,
+            page_size: self.page_size,
+            paged_offset: self.paged_offset,
+            render_continuation: self.render_continuation.clone(),
+            counted_heights: self.counted_heights.clone(),
+            row_order: self.row_order.clone(),
+            wheel_scroll_by: self.wheel_scroll_by,
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
 
-        let (layout, spacers) = Layout::horizontal(&self.widths)
-            .flex(self.flex)
-            .spacing(self.column_spacing)
-            .split_with_spacers(area);
+impl<Selection: Default> Default for TableState<Selection> {
+    fn default() -> Self {
+        Self {
+            focus: Default::default(),
+            area: Default::default(),
+            inner: Default::default(),
+            header_area: Default::default(),
+            table_area: Default::default(),
+            row_areas: Default::default(),
+            column_areas: Default::default(),
+            column_layout: Default::default(),
+            column_widths: Default::default(),
+            width_sources: Default::default(),
+            footer_area: Default::default(),
+            rows: Default::default(),
+            _counted_rows: Default::default(),
+            columns: Default::default(),
+            vscroll: Default::default(),
+            hscroll: Default::default(),
+            selection: Default::default(),
+            hidden_columns: Default::default(),
+            overflow_area: Default::default(),
+            resize_handles: Default::default(),
+            resize_handle_pos: Default::default(),
+            hover_handle: Default::default(),
+            resize_drag: Default::default(),
+            width_overrides: Default::default(),
+            gutter_area: Default::default(),
+            count_prefix: Default::default(),
+            jumps: Default::default(),
+            row_heights: Default::default(),
+            minimap_area: Default::default(),
+            hover_row: Default::default(),
+            latched_width: Default::default(),
+            counted_rows: Default::default(),
+            diagnostics: Default::default(),
+            app_gutter_area: Default::default(),
+            app_gutter_areas: Default::default(),
+            rendered: Default::default(),
+            on_focus_gained: Default::default(),
+            focus_gained_done: Default::default(),
+            peek: Default::default(),
+            peek_offset: Default::default(),
+            peek_lines: Default::default(),
+            peek_area: Default::default(),
+            detail_area: Default::default(),
+            collapsed_columns: Default::default(),
+            scroll_coalesce: Default::default(),
+            pending_scroll: Default::default(),
+            triple_click: Default::default(),
+            click_count: Default::default(),
+            last_click: Default::default(),
+            requests: Default::default(),
+            drag_anchor: Default::default(),
+            drag_range: Default::default(),
+            badges: Default::default(),
+            high_contrast: false,
+            config_checked: false,
+            follow: false,
+            follow_offset: 0,
+            hscroll_focus: true,
+            vscroll_focus: true,
+            scrolloff: 0,
+            pinned_columns: 0,
+            pinned_width: 0,
+            pinned_shift: 0,
+            scroll_lines: false,
+            line_offset: (0, 0),
+            scroll_columns: false,
+            data_version: None,
+            data_changed: false,
+            show_inserted_at_top: false,
+            estimated_rows: 0,
+            touch: false,
+            touch_swipe: None,
+            cached_rows: None,
+            known_rows: None,
+            drag_pos: None,
+            animated_rows: Default::default(),
+            page_scroll: Default::default(),
+            page_scroll_offset: false,
+            indicator_row: Default::default(),
+            follow_indicator: Default::default(),
+            column_groups: Default::default(),
+            group_drag: Default::default(),
+            reported_rows: Default::default(),
+            annotations: Default::default(),
+            annotation: Default::default(),
+            annotation_area: Default::default(),
+            paged: Default::default(),
+            page_size: Default::default(),
+            paged_offset: Default::default(),
+            render_continuation: Default::default(),
+            counted_heights: Default::default(),
+            row_order: Default::default(),
+            wheel_scroll_by: None,
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
 
-        (width, layout, spacers)
+impl<Selection> HasFocus for TableState<Selection> {
+    #[inline]
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
     }
 
-    // Layout header/table/footer
+    /// The area of the table without the scrollbars that
+    /// don't take the focus. See [TableState::hscroll_focus].
     #[inline]
-    fn layout_areas(&self, area: Rect) -> Rc<[Rect]> {
-        let heights = vec![
-            Constraint::Length(self.header.as_ref().map(|v| v.height).unwrap_or(0)),
-            Constraint::Fill(1),
-            Constraint::Length(self.footer.as_ref().map(|v| v.height).unwrap_or(0)),
-        ];
+    fn area(&self) -> Rect {
+        let mut area = self.area;
+        let vscroll = self.vscroll.area;
+        if !self.vscroll_focus && !vscroll.is_empty() {
+            if vscroll.x > area.x + area.width / 2 {
+                area.width = vscroll.x.saturating_sub(area.x);
+            } else {
+                let right = area.right();
+                area.x = min(vscroll.right(), right);
+                area.width = right - area.x;
+            }
+        }
+        let hscroll = self.hscroll.area;
+        if !self.hscroll_focus && !hscroll.is_empty() {
+            if hscroll.y > area.y + area.height / 2 {
+                area.height = hscroll.y.saturating_sub(area.y);
+            } else {
+                let bottom = area.bottom();
+                area.y = min(hscroll.bottom(), bottom);
+                area.height = bottom - area.y;
+            }
+        }
+        area
+    }
+}
 
-        Layout::vertical(heights).split(area)
+impl<Selection> RelocatableState for TableState<Selection> {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        // before header_area is relocated.
+        for x in self.resize_handle_pos.iter_mut() {
+            if let Some(v) = x {
+                let pos = relocate_area(Rect::new(*v, self.header_area.y, 1, 1), shift, clip);
+                *x = if pos.is_empty() { None } else { Some(pos.x) };
+            }
+        }
+
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        self.table_area = relocate_area(self.table_area, shift, clip);
+        self.footer_area = relocate_area(self.footer_area, shift, clip);
+        self.header_area = relocate_area(self.header_area, shift, clip);
+        self.overflow_area = relocate_area(self.overflow_area, shift, clip);
+        self.gutter_area = relocate_area(self.gutter_area, shift, clip);
+        self.minimap_area = relocate_area(self.minimap_area, shift, clip);
+        self.peek_area = relocate_area(self.peek_area, shift, clip);
+        self.annotation_area = relocate_area(self.annotation_area, shift, clip);
+        self.detail_area = relocate_area(self.detail_area, shift, clip);
+        self.app_gutter_area = relocate_area(self.app_gutter_area, shift, clip);
+
+        relocate_areas(self.row_areas.as_mut_slice(), shift, clip);
+        relocate_areas(self.app_gutter_areas.as_mut_slice(), shift, clip);
+        relocate_areas(self.column_areas.as_mut_slice(), shift, clip);
+        relocate_areas(self.column_layout.as_mut_slice(), shift, clip);
+
+        self.hscroll.relocate(shift, clip);
+        self.vscroll.relocate(shift, clip);
     }
 }
 
-#[cfg(feature = "unstable-widget-ref")]
-impl<'a, Selection> StatefulWidgetRef for Table<'a, Selection>
-where
-    Selection: TableSelection,
-{
-    type State = TableState<Selection>;
+impl<Selection> TableState<Selection> {
+    fn calc_last_page(&self, mut row_heights: Vec<u16>) -> Option<usize> {
+        let mut sum_heights = 0;
+        let mut n_rows = 0;
+        while let Some(h) = row_heights.pop() {
+            sum_heights += h;
+            n_rows += 1;
+            if sum_heights >= self.table_area.height {
+                break;
+            }
+        }
 
-    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let iter = self.data.iter();
-        self.render_iter(iter, area, buf, state);
+        if sum_heights < self.table_area.height {
+            None
+        } else {
+            Some(n_rows)
+        }
     }
 }
 
-impl<Selection> StatefulWidget for Table<'_, Selection>
+// Baseline
+impl<Selection> TableState<Selection>
 where
-    Selection: TableSelection,
+    Selection: Default,
 {
-    type State = TableState<Selection>;
+    /// New state with the default selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let iter = mem::take(&mut self.data).into_iter();
-        self.render_iter(iter, area, buf, state);
+    /// New state with a named focus flag.
+    ///
+    /// The name shows up when debugging focus traversal.
+    pub fn named(name: &str) -> Self {
+        Self {
+            focus: FocusFlag::named(name),
+            ..TableState::default()
+        }
     }
 }
 
-impl<'a, Selection> Table<'a, Selection>
-where
-    Selection: TableSelection,
-{
-    /// Render an Iterator over TableRowData.
+// Baseline
+impl<Selection> TableState<Selection> {
+    /// Replace the selection model.
     ///
-    /// rows: If the row number is known, this can help.
+    /// ```rust
+    /// use rat_ftable::selection::RowSelection;
+    /// use rat_ftable::TableState;
     ///
-    fn render_iter<'b>(
-        &self,
-        mut data: DataReprIter<'a, 'b>,
-        area: Rect,
-        buf: &mut Buffer,
-        state: &mut TableState<Selection>,
-    ) {
-        if let Some(rows) = data.rows() {
-            state.rows = rows;
+    /// let mut selection = RowSelection::new();
+    /// selection.set_scroll_selected(true);
+    ///
+    /// let state = TableState::named("files").with_selection(selection);
+    /// assert!(state.selection.scroll_selected());
+    /// ```
+    #[inline]
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns.
+    #[inline]
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+}
+
+// Table areas
+impl<Selection> TableState<Selection> {
+    /// Returns the whole row-area and the cell-areas for the
+    /// given row, if it is visible.
+    ///
+    /// Attention: These areas might be 0-length if the column is scrolled
+    /// beyond the table-area.
+    pub fn row_cells(&self, row: usize) -> Option<(Rect, Vec<Rect>)> {
+        if row < self.vscroll.offset() || row >= self.vscroll.offset() + self.vscroll.page_len() {
+            return None;
         }
-        state.columns = self.widths.len();
-        state.area = area;
 
-        let sa = ScrollArea::new()
-            .style(self.style)
-            .block(self.block.as_ref())
-            .h_scroll(self.hscroll.as_ref())
-            .v_scroll(self.vscroll.as_ref());
-        state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
+        let mut areas = Vec::new();
 
-        let l_rows = self.layout_areas(state.inner);
-        state.header_area = l_rows[0];
-        state.table_area = l_rows[1];
-        state.footer_area = l_rows[2];
+        let r = self.row_areas[row - self.vscroll.offset()];
+        for c in &self.column_areas {
+            areas.push(Rect::new(c.x, r.y, c.width, r.height));
+        }
+
+        Some((r, areas))
+    }
+
+    /// Cell at given position.
+    pub fn cell_at_clicked(&self, pos: (u16, u16)) -> Option<(usize, usize)> {
+        let col = self.column_at_clicked(pos);
+        let row = self.row_at_clicked(pos);
+
+        match (col, row) {
+            (Some(col), Some(row)) => Some((col, row)),
+            _ => None,
+        }
+    }
+
+    /// Follow the end of the data.
+    ///
+    /// Each render scrolls to the last row, so rows appended to
+    /// the data are always visible. Scrolling up turns this off.
+    #[inline]
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+        self.follow_offset = 0;
+    }
+
+    /// Following the end of the data.
+    #[inline]
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Show rows that are inserted at the first visible row.
+    ///
+    /// items_added() always keeps the visible rows in place when
+    /// rows are inserted above them. By default this includes rows
+    /// inserted at the first visible row, so they end up just
+    /// above the view. With this they are shown at the top
+    /// instead. Use this for newest-first feeds: the new rows show
+    /// up while the table is scrolled to the top.
+    #[inline]
+    pub fn set_show_inserted_at_top(&mut self, show: bool) {
+        self.show_inserted_at_top = show;
+    }
+
+    /// Show rows that are inserted at the first visible row.
+    #[inline]
+    pub fn is_show_inserted_at_top(&self) -> bool {
+        self.show_inserted_at_top
+    }
+
+    /// Indicator row.
+    #[inline]
+    pub fn indicator_row(&self) -> Option<usize> {
+        self.indicator_row
+    }
+
+    /// Scroll to the indicator row whenever it is set.
+    #[inline]
+    pub fn set_follow_indicator(&mut self, follow: bool) {
+        self.follow_indicator = follow;
+    }
+
+    /// Scroll to the indicator row whenever it is set.
+    #[inline]
+    pub fn is_follow_indicator(&self) -> bool {
+        self.follow_indicator
+    }
+
+    /// Distance for PageUp/PageDown.
+    #[inline]
+    pub fn set_page_scroll(&mut self, page_scroll: PageScroll) {
+        self.page_scroll = page_scroll;
+    }
+
+    /// Distance for PageUp/PageDown.
+    #[inline]
+    pub fn page_scroll(&self) -> PageScroll {
+        self.page_scroll
+    }
+
+    /// PageUp/PageDown only scroll the offset and leave
+    /// the selection alone.
+    #[inline]
+    pub fn set_page_scroll_offset(&mut self, offset: bool) {
+        self.page_scroll_offset = offset;
+    }
+
+    /// PageUp/PageDown only scroll the offset.
+    #[inline]
+    pub fn is_page_scroll_offset(&self) -> bool {
+        self.page_scroll_offset
+    }
+
+    /// Count the rows of a TableDataIter again with the next render.
+    ///
+    /// The count is kept as long as [TableDataIter::version] doesn't
+    /// change. Use this if the data changes without a new version.
+    #[inline]
+    pub fn clear_cached_rows(&mut self) {
+        self.cached_rows = None;
+    }
+
+    /// Touch friendly hit targets.
+    ///
+    /// The scrollbars and resize handles accept clicks one more
+    /// cell in each direction, and a vertical drag in the
+    /// body scrolls the table instead of extending the selection.
+    #[inline]
+    pub fn set_touch(&mut self, touch: bool) {
+        self.touch = touch;
+    }
+
+    /// Touch friendly hit targets.
+    #[inline]
+    pub fn is_touch(&self) -> bool {
+        self.touch
+    }
+
+    /// Adjust the offset for added rows.
+    fn offset_items_added(&mut self, pos: usize, n: usize) {
+        if self.show_inserted_at_top {
+            let offset = self.vscroll.offset();
+            if pos < offset {
+                self.vscroll.set_offset(offset + n);
+                if self.line_offset.0 == offset {
+                    self.line_offset.0 = offset + n;
+                }
+            }
+            self.vscroll.set_max_offset(self.vscroll.max_offset() + n);
+        } else {
+            self.vscroll.items_added(pos, n);
+        }
+    }
 
-        // horizontal layout
-        let (width, l_columns, l_spacers) = self.layout_columns(state.table_area.width);
-        self.calculate_column_areas(state.columns, l_columns.as_ref(), l_spacers.as_ref(), state);
+    /// Move running height animations with the rows.
+    fn animated_items_added(&mut self, pos: usize, n: usize) {
+        if !self.animated_rows.is_empty() {
+            self.animated_rows = mem::take(&mut self.animated_rows)
+                .into_iter()
+                .map(|(row, v)| if row >= pos { (row + n, v) } else { (row, v) })
+                .collect();
+        }
+    }
 
-        // render block+scroll
-        sa.render(
-            area,
-            buf,
-            &mut ScrollAreaState::new()
-                .h_scroll(&mut state.hscroll)
-                .v_scroll(&mut state.vscroll),
-        );
+    /// Move running height animations with the rows, and
+    /// drop the ones for removed rows.
+    fn animated_items_removed(&mut self, pos: usize, n: usize) {
+        if !self.animated_rows.is_empty() {
+            self.animated_rows = mem::take(&mut self.animated_rows)
+                .into_iter()
+                .filter(|(row, _)| *row < pos || *row >= pos + n)
+                .map(|(row, v)| if row >= pos { (row - n, v) } else { (row, v) })
+                .collect();
+        }
+    }
 
-        // render header & footer
-        self.render_header(
-            state.columns,
-            width,
-            l_columns.as_ref(),
-            l_spacers.as_ref(),
-            state.header_area,
-            buf,
-            state,
-        );
-        self.render_footer(
-            state.columns,
-            width,
-            l_columns.as_ref(),
-            l_spacers.as_ref(),
-            state.footer_area,
-            buf,
-            state,
-        );
+    /// Switch to the high-contrast variant of the styles.
+    ///
+    /// Selections are bold, headers are underlined and dim
+    /// colors are replaced. This works with the styles given to
+    /// the Table, so it can be toggled at runtime.
+    ///
+    /// Rendering with StatefulWidgetRef ignores this.
+    #[inline]
+    pub fn set_high_contrast(&mut self, high_contrast: bool) {
+        self.high_contrast = high_contrast;
+    }
 
-        // render table
-        state.row_areas.clear();
-        state.vscroll.set_page_len(0);
-        state.hscroll.set_page_len(area.width as usize);
+    /// High-contrast styles active.
+    #[inline]
+    pub fn is_high_contrast(&self) -> bool {
+        self.high_contrast
+    }
 
-        let mut row_buf = Buffer::empty(Rect::new(0, 0, width, 1));
-        let mut row = None;
-        let mut row_y = state.table_area.y;
-        let mut row_heights = Vec::new();
-        #[cfg(debug_assertions)]
-        let mut insane_offset = false;
+    // Horizontal shift and the visible x-range of a column,
+    // relative to the table area. Columns pinned to the right
+    // have their own shift.
+    fn column_view(&self, column: usize) -> (isize, u16, u16) {
+        let body_width = self.table_area.width.saturating_sub(self.pinned_width);
+        if self.pinned_columns > 0 && column >= self.columns - self.pinned_columns {
+            (
+                self.pinned_shift as isize,
+                body_width,
+                self.table_area.width,
+            )
+        } else {
+            (self.hscroll.offset() as isize, 0, body_width)
+        }
+    }
 
-        let mut ctx = TableContext {
-            focus: state.focus.get(),
-            selected_cell: false,
-            selected_row: false,
-            selected_column: false,
-            style: self.style,
-            row_style: None,
-            select_style: None,
-            space_area: Default::default(),
-            row_area: Default::default(),
-            non_exhaustive: NonExhaustive,
-        };
+    /// Screen area of the cell, without the spacer.
+    ///
+    /// Returns None if the cell is not visible. Partially visible
+    /// cells are clipped to the table area. Uses the layout of the
+    /// last render.
+    ///
+    /// This can be used to place popups or a cursor at a cell.
+    pub fn cell_area(&self, column: usize, row: usize) -> Option<Rect> {
+        let row_area = row
+            .checked_sub(self.vscroll.offset())
+            .and_then(|v| self.row_areas.get(v))?;
+        let layout = self.column_layout.get(column)?;
+        let width = *self.column_widths.get(column)?;
+
+        let (shift, view_x1, view_x2) = self.column_view(column);
+        let x1 = max(view_x1 as isize, layout.x as isize - shift) as usize;
+        let x2 = max(view_x1 as isize, (layout.x + width) as isize - shift) as usize;
+        let x1 = min(x1, view_x2 as usize) as u16;
+        let x2 = min(x2, view_x2 as usize) as u16;
+        if x1 == x2 {
+            return None;
+        }
 
-        if data.nth(state.vscroll.offset()) {
-            row = Some(state.vscroll.offset());
-            loop {
-                ctx.row_style = data.row_style();
-                // We render each row to a temporary buffer.
-                // For ease of use we start each row at 0,0.
-                // We still only render at least partially visible cells.
-                let render_row_area = Rect::new(0, 0, width, data.row_height());
-                ctx.row_area = render_row_area;
-                row_buf.resize(render_row_area);
-                if let Some(row_style) = ctx.row_style {
-                    row_buf.set_style(render_row_area, row_style);
-                } else {
-                    row_buf.set_style(render_row_area, self.style);
-                }
-                row_heights.push(render_row_area.height);
+        Some(Rect::new(
+            self.table_area.x + x1,
+            row_area.y,
+            x2 - x1,
+            row_area.height,
+        ))
+    }
 
-                // Target area for the finished row.
-                let visible_row_area = Rect::new(
-                    state.table_area.x,
-                    row_y,
-                    state.table_area.width,
-                    render_row_area.height,
-                )
-                .intersection(state.table_area);
-                state.row_areas.push(visible_row_area);
-                // only count fully visible rows.
-                if render_row_area.height == visible_row_area.height {
-                    state.vscroll.set_page_len(state.vscroll.page_len() + 1);
-                }
+    /// Group of the given column as (first, last) column.
+    pub fn column_group(&self, col: usize) -> Option<(usize, usize)> {
+        self.column_groups
+            .iter()
+            .copied()
+            .find(|(first, last)| *first <= col && col <= *last)
+    }
 
-                // can skip this entirely
-                if render_row_area.height > 0 {
-                    let mut col = 0;
-                    loop {
-                        if col >= state.columns {
-                            break;
-                        }
+    /// Put the columns a and b in one group.
+    ///
+    /// The group covers all columns in between and the existing
+    /// groups of both columns. Overlapping groups are merged.
+    /// Returns the resulting group as (first, last) column.
+    pub fn group_columns(&mut self, a: usize, b: usize) -> (usize, usize) {
+        let (a, b) = (min(a, b), max(a, b));
+        let first = self.column_group(a).map(|v| v.0).unwrap_or(a);
+        let last = self.column_group(b).map(|v| v.1).unwrap_or(b);
+        self.column_groups.retain(|(f, l)| *l < first || *f > last);
+        let pos = self
+            .column_groups
+            .iter()
+            .position(|(f, _)| *f > last)
+            .unwrap_or(self.column_groups.len());
+        self.column_groups.insert(pos, (first, last));
+        (first, last)
+    }
 
-                        let render_cell_area = Rect::new(
-                            l_columns[col].x,
-                            0,
-                            l_columns[col].width,
-                            render_row_area.height,
-                        );
-                        ctx.space_area = Rect::new(
-                            l_spacers[col + 1].x,
-                            0,
-                            l_spacers[col + 1].width,
-                            render_row_area.height,
-                        );
+    /// Remove the group of the given column.
+    pub fn ungroup_column(&mut self, col: usize) -> bool {
+        let len = self.column_groups.len();
+        self.column_groups
+            .retain(|(first, last)| col < *first || col > *last);
+        len != self.column_groups.len()
+    }
 
-                        if state.selection.is_selected_cell(col, row.expect("row")) {
-                            ctx.selected_cell = true;
-                            ctx.selected_row = false;
-                            ctx.selected_column = false;
-                            ctx.select_style = self.patch_select(
-                                self.select_cell_style,
-                                state.focus.get(),
-                                self.show_cell_focus,
-                            );
-                        } else if state.selection.is_selected_row(row.expect("row")) {
-                            ctx.selected_cell = false;
-                            ctx.selected_row = true;
-                            ctx.selected_column = false;
-                            // use a fallback if no row-selected style is set.
-                            ctx.select_style = if self.select_row_style.is_some() {
-                                self.patch_select(
-                                    self.select_row_style,
-                                    state.focus.get(),
-                                    self.show_row_focus,
-                                )
-                            } else {
-                                self.patch_select(
-                                    Some(self.style),
-                                    state.focus.get(),
-                                    self.show_row_focus,
-                                )
-                            };
-                        } else if state.selection.is_selected_column(col) {
-                            ctx.selected_cell = false;
-                            ctx.selected_row = false;
-                            ctx.selected_column = true;
-                            ctx.select_style = self.patch_select(
-                                self.select_column_style,
-                                state.focus.get(),
-                                self.show_column_focus,
-                            );
-                        } else {
-                            ctx.selected_cell = false;
-                            ctx.selected_row = false;
-                            ctx.selected_column = false;
-                            ctx.select_style = None;
-                        }
+    /// Cell (column, row) whose badge is at the given position.
+    /// See [TableData::cell_badge].
+    pub fn badge_at(&self, pos: (u16, u16)) -> Option<(usize, usize)> {
+        self.badges.iter().copied().find(|(col, row)| {
+            let Some(layout) = self.column_layout.get(*col) else {
+                return false;
+            };
+            let Some(width) = self.column_widths.get(*col).filter(|v| **v > 0) else {
+                return false;
+            };
+            let Some(row_area) = row
+                .checked_sub(self.vscroll.offset())
+                .and_then(|v| self.row_areas.get(v))
+            else {
+                return false;
+            };
+            let (shift, view_x1, view_x2) = self.column_view(*col);
+            let x = (layout.x + width - 1) as isize - shift;
+            x >= view_x1 as isize
+                && x < view_x2 as isize
+                && self.table_area.x + x as u16 == pos.0
+                && row_area.y == pos.1
+        })
+    }
 
-                        // partially visible?
-                        if render_cell_area.right() > state.hscroll.offset as u16
-                            || render_cell_area.left() < state.hscroll.offset as u16 + area.width
-                        {
-                            if let Some(select_style) = ctx.select_style {
-                                row_buf.set_style(render_cell_area, select_style);
-                                row_buf.set_style(ctx.space_area, select_style);
-                            }
-                            data.render_cell(&ctx, col, render_cell_area, &mut row_buf);
-                        }
+    /// Row of the data for the given row position.
+    ///
+    /// This is the same row unless [Table::row_order] is used.
+    /// Returns None if the row is not in the order.
+    pub fn data_row(&self, row: usize) -> Option<usize> {
+        match &self.row_order {
+            Some(order) => order.get(row).copied(),
+            None => Some(row),
+        }
+    }
 
-                        col += 1;
-                    }
+    /// Column at given position.
+    pub fn column_at_clicked(&self, pos: (u16, u16)) -> Option<usize> {
+        self.mouse.column_at(&self.column_areas, pos.0)
+    }
 
-                    // render shifted and clipped row.
-                    transfer_buffer(
-                        &mut row_buf,
-                        state.hscroll.offset() as u16,
-                        visible_row_area,
-                        buf,
-                    );
-                }
+    /// Row at given position.
+    pub fn row_at_clicked(&self, pos: (u16, u16)) -> Option<usize> {
+        self.mouse
+            .row_at(&self.row_areas, pos.1)
+            .map(|v| self.vscroll.offset() + v)
+    }
 
-                if visible_row_area.bottom() >= state.table_area.bottom() {
-                    break;
-                }
-                if !data.nth(0) {
-                    break;
+    /// Plain text of the visible rows and columns as of the
+    /// last render.
+    ///
+    /// Each cell uses [TableData::cell_text] and is cut to the
+    /// rendered column width and padded with the spacer, so the
+    /// columns line up like on screen. Trailing whitespace is removed from every line.
+    pub fn visible_to_text<'a>(&self, data: &dyn TableData<'a>) -> String {
+        let first_row = self.vscroll.offset();
+        let rows = min(self.row_areas.len(), data.rows().saturating_sub(first_row));
+
+        let mut text = String::new();
+        for row in first_row..first_row + rows {
+            let mut line = String::new();
+            for (col, area) in self.column_areas.iter().enumerate() {
+                if area.width == 0 {
+                    continue;
                 }
-                row = Some(row.expect("row").saturating_add(1));
-                row_y += render_row_area.height;
+                let width = self.column_widths.get(col).copied().unwrap_or(area.width);
+                let cell = data.cell_text(col, row).unwrap_or_default();
+                let cell = fit_text(cell.as_ref(), min(width, area.width) as usize);
+                line.push_str(&fit_text(&cell, area.width as usize));
             }
-        } else {
-            // can only guess whether the skip failed completely or partially.
-            // so don't alter row here.
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+        text
+    }
 
-            // if this first skip fails all bets are off.
-            if data.rows().is_none() || data.rows() == Some(0) {
-                // this is ok
+    /// Viewport metrics as of the last render.
+    ///
+    /// Useful for "45%" indicators or a minimap.
+    pub fn viewport(&self) -> Viewport {
+        let first_row = self.vscroll.offset();
+        let visible_rows = self.row_areas.len();
+        let last_row = first_row + visible_rows.saturating_sub(1);
+
+        let fraction = |idx: usize| match (self.row_areas.get(idx), self.row_heights.get(idx)) {
+            (Some(area), Some(height)) if *height > 0 => area.height as f32 / *height as f32,
+            _ => 0.0,
+        };
+        let ratio = |offset: usize, max_offset: usize| {
+            if max_offset > 0 {
+                (offset as f32 / max_offset as f32).clamp(0.0, 1.0)
             } else {
-                #[cfg(debug_assertions)]
-                {
-                    insane_offset = true;
-                }
+                0.0
             }
+        };
+
+        Viewport {
+            first_row,
+            last_row,
+            visible_rows,
+            first_row_fraction: fraction(0),
+            last_row_fraction: fraction(visible_rows.saturating_sub(1)),
+            vertical_ratio: ratio(self.vscroll.offset(), self.vscroll.max_offset()),
+            horizontal_ratio: ratio(self.hscroll.offset(), self.hscroll.max_offset()),
+            visible_ratio: if self.rows > 0 {
+                (visible_rows as f32 / self.rows as f32).min(1.0)
+            } else {
+                1.0
+            },
         }
+    }
 
-        // maximum offsets
-        #[allow(unused_variables)]
-        let algorithm;
-        #[allow(unused_assignments)]
-        {
-            if let Some(rows) = data.rows() {
-                algorithm = 0;
-                // skip to a guess for the last page.
-                // the guess uses row-height is 1, which may read a few more lines than
-                // absolutely necessary.
-                let skip_rows = rows
-                    .saturating_sub(row.map_or(0, |v| v + 1))
-                    .saturating_sub(state.table_area.height as usize);
-                // if we can still skip some rows, then the data so far is useless.
-                if skip_rows > 0 {
-                    row_heights.clear();
-                }
-                let nth_row = skip_rows;
-                // collect the remaining row-heights.
-                if data.nth(nth_row) {
-                    let mut sum_height = row_heights.iter().sum::<u16>();
-                    row = Some(row.map_or(nth_row, |row| row + nth_row + 1));
-                    loop {
-                        let row_height = data.row_height();
-                        row_heights.push(row_height);
+    /// Screen y of the first line of the given row,
+    /// if the row is visible.
+    ///
+    /// This uses the row-areas of the last render, so it works
+    /// with variable row-heights too.
+    pub fn row_screen_y(&self, row: usize) -> Option<u16> {
+        let idx = row.checked_sub(self.vscroll.offset())?;
+        self.row_areas.get(idx).map(|v| v.y)
+    }
 
-                        // Keep a rolling sum of the heights and drop unnecessary info.
-                        // We don't need more info, and there will be a lot more otherwise.
-                        sum_height += row_height;
-                        if sum_height
-                            .saturating_sub(row_heights.first().copied().unwrap_or_default())
-                            > state.table_area.height
-                        {
-                            let lost_height = row_heights.remove(0);
-                            sum_height -= lost_height;
-                        }
+    /// Row that covers the given screen y.
+    ///
+    /// Same as [row_at_clicked](TableState::row_at_clicked) but
+    /// only uses the y-position.
+    pub fn screen_y_to_row(&self, y: u16) -> Option<usize> {
+        self.row_areas
+            .iter()
+            .position(|v| y >= v.top() && y < v.bottom())
+            .map(|v| self.vscroll.offset() + v)
+    }
+
+    /// Cell when dragging. Position can be outside the table area.
+    /// See [row_at_drag](TableState::row_at_drag), [col_at_drag](TableState::column_at_drag)
+    pub fn cell_at_drag(&self, pos: (u16, u16)) -> (usize, usize) {
+        let col = self.column_at_drag(pos);
+        let row = self.row_at_drag(pos);
+
+        (col, row)
+    }
+
+    /// Row when dragging. Position can be outside the table area.
+    /// If the position is above the table-area this returns offset - #rows.
+    /// If the position is below the table-area this returns offset + page_len + #rows.
+    ///
+    /// This doesn't account for the row-height of the actual rows outside
+    /// the table area, just assumes '1'.
+    pub fn row_at_drag(&self, pos: (u16, u16)) -> usize {
+        match self
+            .mouse
+            .row_at_drag(self.table_area, &self.row_areas, pos.1)
+        {
+            Ok(v) => self.vscroll.offset() + v,
+            Err(v) if v <= 0 => self.vscroll.offset().saturating_sub((-v) as usize),
+            Err(v) => self.vscroll.offset() + self.row_areas.len() + v as usize,
+        }
+    }
 
-                        if !data.nth(0) {
-                            break;
-                        }
+    /// Column when dragging. Position can be outside the table area.
+    /// If the position is left of the table area this returns offset - 1.
+    /// If the position is right of the table area this returns offset + page_width + 1.
+    pub fn column_at_drag(&self, pos: (u16, u16)) -> usize {
+        match self
+            .mouse
+            .column_at_drag(self.table_area, &self.column_areas, pos.0)
+        {
+            Ok(v) => v,
+            Err(v) if v <= 0 => self.hscroll.offset().saturating_sub((-v) as usize),
+            Err(v) => self.hscroll.offset() + self.hscroll.page_len() + v as usize,
+        }
+    }
+}
 
-                        row = Some(row.expect("row") + 1);
-                        // if the given number of rows is too small, we would overshoot here.
-                        if row.expect("row") > rows {
-                            break;
-                        }
-                    }
-                    // we break before to have an accurate last page.
-                    // but we still want to report an error, if the count is off.
-                    while data.nth(0) {
-                        row = Some(row.expect("row") + 1);
-                    }
+// Offset related.
+impl<Selection: TableSelection> TableState<Selection> {
+    /// Handles a click on the overflow indicator.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_overflow(&mut self, event: &crossterm::event::Event) -> Outcome {
+        match event {
+            ct_event!(mouse down Left for column, row)
+                if self.overflow_area.contains((*column, *row).into()) =>
+            {
+                let first_hidden = self.columns.saturating_sub(self.hidden_columns);
+                self.scroll_to_col(first_hidden);
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+
+    /// Collects the digits of a count prefix.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_count_prefix(&mut self, event: &crossterm::event::Event) -> Outcome {
+        match event {
+            ct_event!(key press c @ '0'..='9') => {
+                if self.count_prefix.push_digit(*c) {
+                    Outcome::Unchanged
                 } else {
-                    // skip failed, maybe again?
-                    // leave everything as is and report later.
+                    Outcome::Continue
                 }
+            }
+            _ => Outcome::Continue,
+        }
+    }
 
-                state.rows = rows;
-                state._counted_rows = row.map_or(0, |v| v + 1);
-
-                // have we got a page worth of data?
-                if let Some(last_page) = state.calc_last_page(row_heights) {
-                    state.vscroll.set_max_offset(state.rows - last_page);
+    /// Shows cell notes.
+    ///
+    /// F4 toggles the note of the selected cell, Esc closes it.
+    /// Moving the mouse over a cell with a note shows it, moving
+    /// it elsewhere hides it again.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_annotation(&mut self, event: &crossterm::event::Event) -> Outcome {
+        if self.annotations.is_empty() {
+            return Outcome::Continue;
+        }
+        match event {
+            ct_event!(keycode press F(4)) if self.is_focused() => {
+                if self.annotation.is_some() {
+                    self.close_annotation().into()
                 } else {
-                    // we don't have enough data to establish the last page.
-                    // either there are not enough rows or the given row-count
-                    // was off. make a guess.
-                    state.vscroll.set_max_offset(
-                        state.rows.saturating_sub(state.table_area.height as usize),
-                    );
+                    self.show_selected_annotation().into()
                 }
-            } else if self.no_row_count {
-                algorithm = 1;
+            }
+            ct_event!(keycode press Esc) if self.is_focused() && self.annotation.is_some() => {
+                self.close_annotation().into()
+            }
+            ct_event!(mouse moved for column, row) => {
+                let hover = self
+                    .cell_at_clicked((*column, *row))
+                    .filter(|v| self.annotations.contains_key(v));
+                if hover != self.annotation {
+                    self.annotation = hover;
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            _ => Outcome::Continue,
+        }
+    }
 
-                // We need to feel out a bit beyond the page, otherwise
-                // we can't really stabilize the row count and the
-                // display starts flickering.
-                if row.is_some() {
-                    if data.nth(0) {
-                        // try one past page
-                        row = Some(row.expect("row").saturating_add(1));
-                        if data.nth(0) {
-                            // have an unknown number of rows left.
-                            row = Some(usize::MAX - 1);
-                        }
+    /// Handles the peek popup.
+    ///
+    /// F3 shows the selected cell, a right-click any cell.
+    /// While the popup is open Up/Down/PageUp/PageDown and the
+    /// mouse-wheel scroll its content, Esc and F3 close it.
+    /// Any other key or click closes it too, but is processed
+    /// as usual.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_peek(&mut self, event: &crossterm::event::Event) -> Outcome {
+        if self.peek.is_none() {
+            return match event {
+                ct_event!(keycode press F(3)) if self.is_focused() => self.peek_selected().into(),
+                ct_event!(mouse down Right for column, row) => {
+                    match self.cell_at_clicked((*column, *row)) {
+                        Some((col, row)) => self.peek_cell(col, row).into(),
+                        None => Outcome::Continue,
                     }
                 }
+                _ => Outcome::Continue,
+            };
+        }
 
-                state.rows = row.map_or(0, |v| v + 1);
-                state._counted_rows = row.map_or(0, |v| v + 1);
-                // rough estimate
-                state.vscroll.set_max_offset(usize::MAX - 1);
-                if state.vscroll.page_len() == 0 {
-                    state.vscroll.set_page_len(state.table_area.height as usize);
+        let page = max(1, self.peek_area.height.saturating_sub(3)) as isize;
+        match event {
+            ct_event!(keycode press Esc) | ct_event!(keycode press F(3)) if self.is_focused() => {
+                self.close_peek().into()
+            }
+            ct_event!(keycode press Up) if self.is_focused() => self.scroll_peek(-1).into(),
+            ct_event!(keycode press Down) if self.is_focused() => self.scroll_peek(1).into(),
+            ct_event!(keycode press PageUp) if self.is_focused() => self.scroll_peek(-page).into(),
+            ct_event!(keycode press PageDown) if self.is_focused() => self.scroll_peek(page).into(),
+            ct_event!(scroll up for column, row)
+                if self.peek_area.contains((*column, *row).into()) =>
+            {
+                self.scroll_peek(-1).into()
+            }
+            ct_event!(scroll down for column, row)
+                if self.peek_area.contains((*column, *row).into()) =>
+            {
+                self.scroll_peek(1).into()
+            }
+            ct_event!(mouse down Right for column, row)
+                if self.table_area.contains((*column, *row).into()) =>
+            {
+                match self.cell_at_clicked((*column, *row)) {
+                    Some((col, row)) => self.peek_cell(col, row).into(),
+                    None => self.close_peek().into(),
                 }
-            } else {
-                algorithm = 2;
+            }
+            ct_event!(mouse any for m) if self.peek_area.contains((m.column, m.row).into()) => {
+                Outcome::Unchanged
+            }
+            crossterm::event::Event::Key(_) if self.is_focused() => {
+                self.close_peek();
+                Outcome::Continue
+            }
+            ct_event!(mouse down Left for _column, _row) => {
+                self.close_peek();
+                Outcome::Continue
+            }
+            _ => Outcome::Continue,
+        }
+    }
 
-                // Read all the rest to establish the exact row-count.
-                let mut sum_height = row_heights.iter().sum::<u16>();
-                while data.nth(0) {
-                    let row_height = data.row_height();
-                    row_heights.push(row_height);
+    /// Collects the scrolling if
+    /// [scroll_coalesce](TableState::scroll_coalesce) is set.
+    ///
+    /// Returns Changed for the first scroll since the last render,
+    /// and Unchanged after that.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_coalesce(&mut self, scroll: ScrollOutcome) -> Outcome {
+        if !self.scroll_coalesce {
+            return Outcome::Continue;
+        }
+        let pending = self.pending_scroll != (0, 0);
+        match scroll {
+            ScrollOutcome::Up(v) => self.pending_scroll.1 -= v as isize,
+            ScrollOutcome::Down(v) => self.pending_scroll.1 += v as isize,
+            ScrollOutcome::Left(v) => self.pending_scroll.0 -= v as isize,
+            ScrollOutcome::Right(v) => self.pending_scroll.0 += v as isize,
+            _ => return Outcome::Continue,
+        }
+        if pending {
+            Outcome::Unchanged
+        } else {
+            Outcome::Changed
+        }
+    }
 
-                    // Keep a rolling sum of the heights and drop unnecessary info.
-                    // We don't need more info, and there will be a lot more otherwise.
-                    sum_height += row_height;
-                    if sum_height.saturating_sub(row_heights.first().copied().unwrap_or_default())
-                        > state.table_area.height
-                    {
-                        let lost_height = row_heights.remove(0);
-                        sum_height -= lost_height;
-                    }
-                    row = Some(row.map_or(0, |v| v + 1));
-                }
+    /// Horizontal scrolling with Shift+wheel, and with the
+    /// horizontal wheel if the terminal reports it.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_hwheel(&self, event: &crossterm::event::Event) -> ScrollOutcome {
+        use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
+        let Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers,
+        }) = event
+        else {
+            return ScrollOutcome::Continue;
+        };
+        if !self.inner.contains((*column, *row).into()) {
+            return ScrollOutcome::Continue;
+        }
+        let by = if self.scroll_columns {
+            1
+        } else {
+            self.hscroll.scroll_by()
+        };
+        match kind {
+            MouseEventKind::ScrollUp if *modifiers == KeyModifiers::SHIFT => {
+                ScrollOutcome::Left(by)
+            }
+            MouseEventKind::ScrollDown if *modifiers == KeyModifiers::SHIFT => {
+                ScrollOutcome::Right(by)
+            }
+            MouseEventKind::ScrollLeft => ScrollOutcome::Left(by),
+            MouseEventKind::ScrollRight => ScrollOutcome::Right(by),
+            _ => ScrollOutcome::Continue,
+        }
+    }
 
-                state.rows = row.map_or(0, |v| v + 1);
-                state._counted_rows = row.map_or(0, |v| v + 1);
+    /// Moves clicks next to a scrollbar onto the scrollbar
+    /// in touch mode.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn touch_event(
+        &self,
+        event: &crossterm::event::Event,
+    ) -> Option<crossterm::event::Event> {
+        use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+        use ratatui::layout::Position;
 
-                // have we got a page worth of data?
-                if let Some(last_page) = state.calc_last_page(row_heights) {
-                    state.vscroll.set_max_offset(state.rows - last_page);
+        if !self.touch {
+            return None;
+        }
+        let Event::Mouse(
+            m @ MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            },
+        ) = event
+        else {
+            return None;
+        };
+        let pos = Position::new(*column, *row);
+        for area in [self.vscroll.area, self.hscroll.area] {
+            if area.is_empty() || area.contains(pos) {
+                continue;
+            }
+            let reach = Rect::new(
+                area.x.saturating_sub(1),
+                area.y.saturating_sub(1),
+                area.width + 2,
+                area.height + 2,
+            );
+            if reach.contains(pos) {
+                return Some(Event::Mouse(MouseEvent {
+                    column: pos.x.clamp(area.left(), area.right() - 1),
+                    row: pos.y.clamp(area.top(), area.bottom() - 1),
+                    ..*m
+                }));
+            }
+        }
+        None
+    }
+
+    /// Scrolls with a vertical drag in the body in touch mode.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_swipe(&mut self, event: &crossterm::event::Event) -> Outcome {
+        if !self.touch {
+            return Outcome::Continue;
+        }
+        match event {
+            ct_event!(mouse down Left for column, row) => {
+                if self.table_area.contains((*column, *row).into()) {
+                    self.touch_swipe = Some(*row);
+                }
+                Outcome::Continue
+            }
+            ct_event!(mouse drag Left for _column, row) => {
+                if let Some(last) = self.touch_swipe {
+                    self.touch_swipe = Some(*row);
+                    let r = if *row > last {
+                        self.scroll_up((*row - last) as usize)
+                    } else {
+                        self.scroll_down((last - *row) as usize)
+                    };
+                    r.into()
                 } else {
-                    state.vscroll.set_max_offset(0);
+                    Outcome::Continue
                 }
             }
+            ct_event!(mouse up Left for _column, _row) => {
+                self.touch_swipe = None;
+                Outcome::Continue
+            }
+            _ => Outcome::Continue,
         }
-        {
-            state
-                .hscroll
-                .set_max_offset(width.saturating_sub(state.table_area.width) as usize);
+    }
+
+    /// PageUp/PageDown with [TableState::set_page_scroll_offset].
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_page_scroll(&mut self, event: &crossterm::event::Event) -> Outcome {
+        if !self.page_scroll_offset || !self.is_focused() {
+            return Outcome::Continue;
+        }
+        match event {
+            ct_event!(keycode press PageUp) => self.scroll_up(self.page_up_len()).into(),
+            ct_event!(keycode press PageDown) => self.scroll_down(self.page_down_len()).into(),
+            _ => Outcome::Continue,
         }
+    }
 
-        #[cfg(debug_assertions)]
-        {
-            use std::fmt::Write;
-            let mut msg = String::new();
-            if insane_offset {
-                _= write!(msg,
-                          "Table::render:\n        offset {}\n        rows {}\n        iter-rows {}max\n    don't match up\nCode X{}X\n",
-                          state.vscroll.offset(), state.rows, state._counted_rows, algorithm
-                );
+    /// Remembers the mouse position while dragging a selection.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn track_drag(&mut self, event: &crossterm::event::Event) {
+        match event {
+            ct_event!(mouse drag Left for column, row)
+            | ct_event!(mouse drag CONTROL-Left for column, row)
+                if self.mouse.drag.get() =>
+            {
+                self.drag_pos = Some((*column, *row));
             }
-            if state.rows != state._counted_rows {
-                _ = write!(
-                    msg,
-                    "Table::render:\n    rows {} don't match\n    iterated rows {}\nCode X{}X\n",
-                    state.rows, state._counted_rows, algorithm
-                );
+            ct_event!(mouse down Left for _column, _row)
+            | ct_event!(mouse up Left for _column, _row) => {
+                self.drag_pos = None;
             }
-            if !msg.is_empty() {
-                use log::warn;
-                use ratatui::style::Stylize;
-                use ratatui::text::Text;
+            _ => {}
+        }
+    }
 
-                warn!("{}", &msg);
-                Text::from(msg)
-                    .white()
-                    .on_red()
-                    .render(state.table_area, buf);
-            }
+    /// Position of a drag outside the table area.
+    #[cfg(feature = "crossterm")]
+    fn drag_outside(&mut self) -> Option<(u16, u16)> {
+        if !self.mouse.drag.get() {
+            self.drag_pos = None;
         }
+        self.drag_pos
+            .filter(|pos| !self.table_area.contains((*pos).into()))
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn render_footer(
-        &self,
-        columns: usize,
-        width: u16,
-        l_columns: &[Rect],
-        l_spacers: &[Rect],
-        area: Rect,
-        buf: &mut Buffer,
-        state: &mut TableState<Selection>,
-    ) {
-        if let Some(footer) = &self.footer {
-            let render_row_area = Rect::new(0, 0, width, footer.height);
-            let mut row_buf = Buffer::empty(render_row_area);
+    /// Limits the offset from dragging the scrollbar to the
+    /// rows found so far, if the row count is unknown.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn remap_vpos(&self, offset: usize) -> usize {
+        match self.known_rows {
+            Some(known) => min(offset, known.saturating_sub(1)),
+            None => offset,
+        }
+    }
 
-            row_buf.set_style(render_row_area, self.style);
-            if let Some(footer_style) = footer.style {
-                row_buf.set_style(render_row_area, footer_style);
-            } else if let Some(footer_style) = self.footer_style {
-                row_buf.set_style(render_row_area, footer_style);
+    /// Tracks the range covered by a mouse drag.
+    ///
+    /// This only records [drag_range](TableState::drag_range) for
+    /// rendering the preview. The selection is changed by the
+    /// regular mouse handling.
+    ///
+    /// rows: The drag covers full rows.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_drag_range(
+        &mut self,
+        event: &crossterm::event::Event,
+        rows: bool,
+    ) -> Outcome {
+        let full_row = |(col, row): (usize, usize), columns: usize| {
+            if rows {
+                ((0, row), (columns.saturating_sub(1), row))
+            } else {
+                ((col, row), (col, row))
             }
-
-            let mut col = 0;
-            loop {
-                if col >= columns {
-                    break;
+        };
+        match event {
+            ct_event!(mouse down Left for column, row)
+            | ct_event!(mouse down CONTROL-Left for column, row) => {
+                self.drag_anchor = self.cell_at_clicked((*column, *row));
+                self.drag_range = None;
+                Outcome::Continue
+            }
+            ct_event!(mouse drag Left for column, row)
+            | ct_event!(mouse drag CONTROL-Left for column, row) => {
+                if let Some(anchor) = self.drag_anchor {
+                    let current = self.cell_at_drag((*column, *row));
+                    let (start, _) = full_row(anchor, self.columns);
+                    let (_, end) = full_row(current, self.columns);
+                    self.drag_range = Some((start, end));
                 }
+                Outcome::Continue
+            }
+            ct_event!(mouse up Left for _column, _row)
+            | ct_event!(mouse up CONTROL-Left for _column, _row) => {
+                self.drag_anchor = None;
+                if self.drag_range.take().is_some() {
+                    // the event is consumed, end the drag here.
+                    self.mouse.drag.set(false);
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            _ => Outcome::Continue,
+        }
+    }
 
-                let render_cell_area =
-                    Rect::new(l_columns[col].x, 0, l_columns[col].width, area.height);
-                let render_space_area = Rect::new(
-                    l_spacers[col + 1].x,
-                    0,
-                    l_spacers[col + 1].width,
-                    area.height,
-                );
-
-                if state.selection.is_selected_column(col) {
-                    if let Some(selected_style) = self.patch_select(
-                        self.select_footer_style,
-                        state.focus.get(),
-                        self.show_footer_focus,
-                    ) {
-                        row_buf.set_style(render_cell_area, selected_style);
-                        row_buf.set_style(render_space_area, selected_style);
-                    }
+    /// Handles clicks on the minimap.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_minimap(&mut self, event: &crossterm::event::Event) -> Outcome {
+        match event {
+            ct_event!(mouse down Left for column, row)
+            | ct_event!(mouse drag Left for column, row)
+                if self.minimap_area.contains((*column, *row).into()) =>
+            {
+                let line = (*row - self.minimap_area.y) as usize;
+                let height = self.minimap_area.height as usize;
+                // scaled like render_minimap, but only to rows found so far.
+                let (rows, max_row) = match self.known_rows {
+                    Some(known) => (self.estimated_rows, known.saturating_sub(1)),
+                    None => (self.rows, self.rows.saturating_sub(1)),
                 };
+                let target = min(line.saturating_mul(rows) / height, max_row);
+                let offset = target.saturating_sub(self.page_len() / 2);
+                self.set_row_offset(offset).into()
+            }
+            _ => Outcome::Continue,
+        }
+    }
 
-                // partially visible?
-                if render_cell_area.right() > state.hscroll.offset as u16
-                    || render_cell_area.left() < state.hscroll.offset as u16 + area.width
-                {
-                    if let Some(cell) = footer.cells.get(col) {
-                        if let Some(cell_style) = cell.style {
-                            row_buf.set_style(render_cell_area, cell_style);
-                        }
-                        cell.content.clone().render(render_cell_area, &mut row_buf);
+    /// Handles hovering and dragging the resize handles.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn handle_resize(&mut self, event: &crossterm::event::Event) -> Outcome {
+        if !self.resize_handles {
+            return Outcome::Continue;
+        }
+        match event {
+            ct_event!(mouse moved for column, row) => {
+                let hover = self.resize_handle_at((*column, *row));
+                if self.hover_handle != hover {
+                    self.hover_handle = hover;
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            ct_event!(mouse down Left for column, row) => {
+                if let Some(col) = self.resize_handle_at((*column, *row)) {
+                    self.resize_drag = Some(col);
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            ct_event!(mouse drag Left for column, _row) => {
+                if let Some(col) = self.resize_drag {
+                    let Some(layout) = self.column_layout.get(col) else {
+                        return Outcome::Continue;
+                    };
+                    let (shift, _, _) = self.column_view(col);
+                    let x = (*column as usize + shift as usize)
+                        .saturating_sub(self.header_area.x as usize);
+                    let width = max(1, x.saturating_sub(layout.x as usize)) as u16;
+                    if self.width_overrides.get(&col) != Some(&width) {
+                        self.width_overrides.insert(col, width);
+                        Outcome::Changed
+                    } else {
+                        Outcome::Unchanged
                     }
+                } else {
+                    Outcome::Continue
+                }
+            }
+            ct_event!(mouse up Left for _column, _row) => {
+                if self.resize_drag.take().is_some() {
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
                 }
-
-                col += 1;
             }
+            _ => Outcome::Continue,
+        }
+    }
 
-            // render shifted and clipped row.
-            transfer_buffer(&mut row_buf, state.hscroll.offset() as u16, area, buf);
+    /// Finds the resize handle at the given screen position.
+    ///
+    /// The handle is one cell wide, but this accepts one cell
+    /// to each side too. If two handles are in range the nearer
+    /// one wins. In [touch](TableState::set_touch) mode this is
+    /// one more cell in each direction.
+    pub fn resize_handle_at(&self, pos: (u16, u16)) -> Option<usize> {
+        let (x, y) = pos;
+        let reach = if self.touch { 2 } else { 1 };
+        let top = self.header_area.top().saturating_sub(reach - 1);
+        let bottom = self.header_area.bottom().saturating_add(reach - 1);
+        if self.header_area.is_empty() || y < top || y >= bottom {
+            return None;
+        }
+        let mut found: Option<(usize, u16)> = None;
+        for (col, hx) in self.resize_handle_pos.iter().enumerate() {
+            let Some(hx) = *hx else {
+                continue;
+            };
+            let dist = hx.abs_diff(x);
+            if dist <= reach && found.map(|(_, d)| dist < d).unwrap_or(true) {
+                found = Some((col, dist));
+            }
         }
+        found.map(|(col, _)| col)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn render_header(
-        &self,
-        columns: usize,
-        width: u16,
-        l_columns: &[Rect],
-        l_spacers: &[Rect],
-        area: Rect,
-        buf: &mut Buffer,
-        state: &mut TableState<Selection>,
-    ) {
-        if let Some(header) = &self.header {
-            let render_row_area = Rect::new(0, 0, width, header.height);
-            let mut row_buf = Buffer::empty(render_row_area);
+    /// Rule that produced the width of the column in the last render.
+    ///
+    /// This is meant for debugging layouts. See [WidthSource].
+    pub fn width_source(&self, column: usize) -> Option<WidthSource> {
+        self.width_sources.get(column).copied()
+    }
 
-            row_buf.set_style(render_row_area, self.style);
-            if let Some(header_style) = header.style {
-                row_buf.set_style(render_row_area, header_style);
-            } else if let Some(header_style) = self.header_style {
-                row_buf.set_style(render_row_area, header_style);
-            }
+    /// Removes all widths set by resizing columns.
+    pub fn reset_column_widths(&mut self) {
+        self.width_overrides.clear();
+    }
 
-            let mut col = 0;
-            loop {
-                if col >= columns {
-                    break;
-                }
+    /// Counting the rows is not finished yet.
+    /// See [Table::row_count_budget].
+    pub fn is_counting_rows(&self) -> bool {
+        self.counted_rows.is_some()
+    }
 
-                let render_cell_area =
-                    Rect::new(l_columns[col].x, 0, l_columns[col].width, area.height);
-                let render_space_area = Rect::new(
-                    l_spacers[col + 1].x,
-                    0,
-                    l_spacers[col + 1].width,
-                    area.height,
-                );
+    /// Forget the latched layout width.
+    /// See [Table::latch_layout_width].
+    pub fn reset_latched_width(&mut self) {
+        self.latched_width = 0;
+    }
 
-                if state.selection.is_selected_column(col) {
-                    if let Some(selected_style) = self.patch_select(
-                        self.select_header_style,
-                        state.focus.get(),
-                        self.show_header_focus,
-                    ) {
-                        row_buf.set_style(render_cell_area, selected_style);
-                        row_buf.set_style(render_space_area, selected_style);
-                    }
-                };
+    /// Sets both offsets to 0.
+    pub fn clear_offset(&mut self) {
+        self.vscroll.set_offset(0);
+        self.hscroll.set_offset(0);
+    }
 
-                // partially visible?
-                if render_cell_area.right() > state.hscroll.offset as u16
-                    || render_cell_area.left() < state.hscroll.offset as u16 + area.width
-                {
-                    if let Some(cell) = header.cells.get(col) {
-                        if let Some(cell_style) = cell.style {
-                            row_buf.set_style(render_cell_area, cell_style);
-                        }
-                        cell.content.clone().render(render_cell_area, &mut row_buf);
-                    }
-                }
+    /// Maximum offset that is accessible with scrolling.
+    ///
+    /// This is shorter than the length by whatever fills the last page.
+    /// This is the base for the scrollbar content_length.
+    pub fn row_max_offset(&self) -> usize {
+        self.vscroll.max_offset()
+    }
 
-                col += 1;
-            }
+    /// Current vertical offset.
+    pub fn row_offset(&self) -> usize {
+        self.vscroll.offset()
+    }
 
-            // render shifted and clipped row.
-            transfer_buffer(&mut row_buf, state.hscroll.offset() as u16, area, buf);
-        }
+    /// Change the vertical offset.
+    ///
+    /// Due to overscroll it's possible that this is an invalid offset for the widget.
+    /// The widget must deal with this situation.
+    ///
+    /// The widget returns true if the offset changed at all.
+    pub fn set_row_offset(&mut self, offset: usize) -> bool {
+        self.vscroll.set_offset(offset)
     }
 
-    fn calculate_column_areas(
-        &self,
-        columns: usize,
-        l_columns: &[Rect],
-        l_spacers: &[Rect],
-        state: &mut TableState<Selection>,
-    ) {
-        state.column_areas.clear();
-        state.column_layout.clear();
+    /// Vertical page-size at the current offset.
+    pub fn page_len(&self) -> usize {
+        self.vscroll.page_len()
+    }
 
-        let mut col = 0;
-        let shift = state.hscroll.offset() as isize;
-        loop {
-            if col >= columns {
-                break;
-            }
+    /// The last render was split, and the rest will be rendered
+    /// with the next frame. See [Table::progressive].
+    #[inline]
+    pub fn is_render_pending(&self) -> bool {
+        self.render_continuation.as_ref().is_some_and(|v| v.pending)
+    }
 
-            state.column_layout.push(Rect::new(
-                l_columns[col].x,
-                0,
-                l_columns[col].width + l_spacers[col + 1].width,
-                0,
-            ));
+    /// Current page in [paged](Table::paged) presentation.
+    pub fn page(&self) -> usize {
+        self.align_page_offset(self.row_offset())
+            .checked_div(self.page_size)
+            .unwrap_or_default()
+    }
 
-            let cell_x1 = l_columns[col].x as isize;
-            let cell_x2 =
-                (l_columns[col].x + l_columns[col].width + l_spacers[col + 1].width) as isize;
+    /// Show the given page in [paged](Table::paged) presentation.
+    pub fn set_page(&mut self, page: usize) -> bool {
+        let page = min(page, self.page_count().saturating_sub(1));
+        self.set_row_offset(page * self.page_size)
+    }
 
-            let squish_x1 = cell_x1.saturating_sub(shift);
-            let squish_x2 = cell_x2.saturating_sub(shift);
+    /// Number of pages in [paged](Table::paged) presentation.
+    /// As of the last render.
+    pub fn page_count(&self) -> usize {
+        if self.page_size > 0 {
+            self.rows.div_ceil(self.page_size)
+        } else {
+            0
+        }
+    }
 
-            let abs_x1 = max(0, squish_x1) as u16;
-            let abs_x2 = max(0, squish_x2) as u16;
+    /// Snaps the offset to the start of a page. Scrolling down
+    /// goes to the next page, scrolling up to the previous one.
+    fn align_page_offset(&self, offset: usize) -> usize {
+        let page = self.page_size;
+        if !self.paged || page == 0 {
+            offset
+        } else if offset > self.paged_offset {
+            offset.div_ceil(page) * page
+        } else {
+            offset / page * page
+        }
+    }
 
-            let v_area = Rect::new(
-                state.table_area.x + abs_x1,
-                state.table_area.y,
-                abs_x2 - abs_x1,
-                state.table_area.height,
-            );
-            state
-                .column_areas
-                .push(v_area.intersection(state.table_area));
+    /// Number of rows to move the selection for PageUp.
+    ///
+    /// With different row heights this counts the rows that fit
+    /// in the table together with the selected row. Heights of rows
+    /// that are not visible are estimated with the average of the
+    /// visible rows. At least 1.
+    ///
+    /// See [TableState::set_page_scroll].
+    pub fn page_up_len(&self) -> usize {
+        self.page_scroll_len(self.full_page_up_len())
+    }
 
-            col += 1;
+    /// Number of rows to move the selection for PageDown.
+    ///
+    /// See [TableState::set_page_scroll].
+    pub fn page_down_len(&self) -> usize {
+        self.page_scroll_len(self.full_page_down_len())
+    }
+
+    fn page_scroll_len(&self, page: usize) -> usize {
+        if self.paged && self.page_size > 0 {
+            return self.page_size;
+        }
+        match self.page_scroll {
+            PageScroll::Page => page,
+            PageScroll::HalfPage => max(1, page / 2),
+            PageScroll::Rows(n) => max(1, n),
         }
     }
 
-    #[expect(clippy::collapsible_else_if)]
-    fn patch_select(&self, style: Option<Style>, focus: bool, show: bool) -> Option<Style> {
-        if let Some(style) = style {
-            if let Some(focus_style) = self.focus_style {
-                if focus && show {
-                    Some(style.patch(focus_style))
-                } else {
-                    Some(fallback_select_style(style))
-                }
-            } else {
-                if focus && show {
-                    Some(revert_style(style))
-                } else {
-                    Some(fallback_select_style(style))
-                }
+    // Rows above the selection that fit in the table.
+    fn full_page_up_len(&self) -> usize {
+        let lead = self.selection.lead_selection();
+        let Some((_, row)) = lead.filter(|_| !self.page_scroll_offset) else {
+            return max(1, self.page_len().saturating_sub(1));
+        };
+        let mut height = self.known_row_height(row) as usize;
+        let mut n = 0;
+        for r in (0..min(row, self.rows)).rev() {
+            height += self.known_row_height(r) as usize;
+            if height > self.table_area.height as usize {
+                break;
             }
-        } else {
-            None
+            n += 1;
         }
+        max(1, n)
     }
-}
 
-impl Default for TableStyle {
-    fn default() -> Self {
-        Self {
-            style: Default::default(),
-            header: None,
-            footer: None,
-            select_row: None,
-            select_column: None,
-            select_cell: None,
-            select_header: None,
-            select_footer: None,
-            show_row_focus: true, // non standard
-            show_column_focus: false,
-            show_cell_focus: false,
-            show_header_focus: false,
-            show_footer_focus: false,
-            focus_style: None,
-            block: None,
-            border_style: None,
-            scroll: None,
-            non_exhaustive: NonExhaustive,
+    // With different row heights this counts the rows below the
+    // selection that fit in the table together with the selected
+    // row. Heights of rows that are not visible are estimated
+    // with the average of the visible rows. At least 1.
+    fn full_page_down_len(&self) -> usize {
+        let lead = self.selection.lead_selection();
+        let Some((_, row)) = lead.filter(|_| !self.page_scroll_offset) else {
+            return max(1, self.page_len().saturating_sub(1));
+        };
+        let mut height = self.known_row_height(row) as usize;
+        let mut n = 0;
+        for r in row.saturating_add(1)..self.rows {
+            height += self.known_row_height(r) as usize;
+            if height > self.table_area.height as usize {
+                break;
+            }
+            n += 1;
         }
+        max(1, n)
     }
-}
 
-impl<Selection: Clone> Clone for TableState<Selection> {
-    fn clone(&self) -> Self {
-        Self {
-            focus: FocusFlag::named(self.focus.name()),
-            area: self.area,
-            inner: self.inner,
-            header_area: self.header_area,
-            table_area: self.table_area,
-            row_areas: self.row_areas.clone(),
-            column_areas: self.column_areas.clone(),
-            column_layout: self.column_layout.clone(),
-            footer_area: self.footer_area,
-            rows: self.rows,
-            _counted_rows: self._counted_rows,
-            columns: self.columns,
-            vscroll: self.vscroll.clone(),
-            hscroll: self.hscroll.clone(),
-            selection: self.selection.clone(),
-            mouse: Default::default(),
-            non_exhaustive: NonExhaustive,
+    // Height of the row from the last render, or the average
+    // height of the visible rows.
+    fn known_row_height(&self, row: usize) -> u16 {
+        let offset = self.vscroll.offset();
+        if let Some(h) = row
+            .checked_sub(offset)
+            .and_then(|v| self.row_heights.get(v))
+        {
+            return max(1, *h);
+        }
+        if self.row_heights.is_empty() {
+            1
+        } else {
+            let sum = self.row_heights.iter().map(|v| *v as usize).sum::<usize>();
+            max(1, sum.div_ceil(self.row_heights.len())) as u16
         }
     }
-}
 
-impl<Selection: Default> Default for TableState<Selection> {
-    fn default() -> Self {
-        Self {
-            focus: Default::default(),
-            area: Default::default(),
-            inner: Default::default(),
-            header_area: Default::default(),
-            table_area: Default::default(),
-            row_areas: Default::default(),
-            column_areas: Default::default(),
-            column_layout: Default::default(),
-            footer_area: Default::default(),
-            rows: Default::default(),
-            _counted_rows: Default::default(),
-            columns: Default::default(),
-            vscroll: Default::default(),
-            hscroll: Default::default(),
-            selection: Default::default(),
-            mouse: Default::default(),
-            non_exhaustive: NonExhaustive,
-        }
+    /// Suggested scroll per scroll-event.
+    ///
+    /// This is the [Table::wheel_scroll_by] override if any,
+    /// otherwise the scroll_by of the vertical scrollbar.
+    pub fn row_scroll_by(&self) -> usize {
+        self.wheel_scroll_by
+            .unwrap_or_else(|| self.vscroll.scroll_by())
     }
-}
 
-impl<Selection> HasFocus for TableState<Selection> {
-    #[inline]
-    fn focus(&self) -> FocusFlag {
-        self.focus.clone()
+    /// Maximum offset that is accessible with scrolling.
+    ///
+    /// This is shorter than the length of the content by whatever fills the last page.
+    /// This is the base for the scrollbar content_length.
+    pub fn x_max_offset(&self) -> usize {
+        self.hscroll.max_offset()
     }
 
-    #[inline]
-    fn area(&self) -> Rect {
-        self.area
+    /// Current horizontal offset.
+    pub fn x_offset(&self) -> usize {
+        self.hscroll.offset()
     }
-}
 
-impl<Selection> RelocatableState for TableState<Selection> {
-    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
-        self.area = relocate_area(self.area, shift, clip);
-        self.inner = relocate_area(self.inner, shift, clip);
-        self.table_area = relocate_area(self.table_area, shift, clip);
-        self.footer_area = relocate_area(self.footer_area, shift, clip);
-        self.header_area = relocate_area(self.header_area, shift, clip);
+    /// Change the horizontal offset.
+    ///
+    /// Due to overscroll it's possible that this is an invalid offset for the widget.
+    /// The widget must deal with this situation.
+    ///
+    /// The widget returns true if the offset changed at all.
+    pub fn set_x_offset(&mut self, offset: usize) -> bool {
+        self.hscroll.set_offset(offset)
+    }
 
-        relocate_areas(self.row_areas.as_mut_slice(), shift, clip);
-        relocate_areas(self.column_areas.as_mut_slice(), shift, clip);
-        relocate_areas(self.column_layout.as_mut_slice(), shift, clip);
+    /// Horizontal page-size at the current offset.
+    pub fn page_width(&self) -> usize {
+        self.hscroll.page_len()
+    }
 
-        self.hscroll.relocate(shift, clip);
-        self.vscroll.relocate(shift, clip);
+    /// Suggested scroll per scroll-event.
+    pub fn x_scroll_by(&self) -> usize {
+        self.hscroll.scroll_by()
     }
-}
 
-impl<Selection> TableState<Selection> {
-    fn calc_last_page(&self, mut row_heights: Vec<u16>) -> Option<usize> {
-        let mut sum_heights = 0;
-        let mut n_rows = 0;
-        while let Some(h) = row_heights.pop() {
-            sum_heights += h;
-            n_rows += 1;
-            if sum_heights >= self.table_area.height {
-                break;
-            }
+    /// Checks if the visible rows changed since the last call.
+    ///
+    /// Call this after event handling to find the rows that
+    /// just scrolled into view, e.g. to fetch remote data for them.
+    /// Returns the new (first, last) visible row, or None if
+    /// nothing changed. Uses the number of rows shown by the
+    /// last render, which is exact for varying row-heights too.
+    pub fn visible_rows_changed(&mut self) -> Option<(usize, usize)> {
+        let visible = if self.rows > 0 && !self.row_areas.is_empty() {
+            let first = min(self.row_offset(), self.rows - 1);
+            let last = min(first + self.row_areas.len() - 1, self.rows - 1);
+            Some((first, last))
+        } else {
+            None
+        };
+        if visible != self.reported_rows {
+            self.reported_rows = visible;
+            visible
+        } else {
+            None
         }
+    }
 
-        if sum_heights < self.table_area.height {
-            None
+    /// Ensures that the selected item is visible.
+    /// Caveat: This doesn't work nicely if you have varying row-heights.
+    pub fn scroll_to_selected(&mut self) -> bool {
+        if let Some(selected) = self.selection.lead_selection() {
+            let c = self.scroll_to_x(selected.0);
+            let r = self.scroll_to_row(selected.1);
+            r || c
         } else {
-            Some(n_rows)
+            false
         }
     }
-}
 
-// Baseline
-impl<Selection> TableState<Selection>
-where
-    Selection: Default,
-{
-    pub fn new() -> Self {
-        Self::default()
+    /// Ensures that the given row is visible.
+    /// Caveat: This doesn't work nicely if you have varying row-heights.
+    pub fn scroll_to_row(&mut self, pos: usize) -> bool {
+        if pos >= self.rows {
+            false
+        } else if pos == self.row_offset().saturating_add(self.page_len()) {
+            // the page might not fill the full area.
+            let heights = self.row_areas.iter().map(|v| v.height).sum::<u16>();
+            if heights < self.table_area.height {
+                false
+            } else {
+                self.set_row_offset(pos.saturating_sub(self.page_len()).saturating_add(1))
+            }
+        } else if pos >= self.row_offset().saturating_add(self.page_len()) {
+            self.set_row_offset(pos.saturating_sub(self.page_len()).saturating_add(1))
+        } else if pos < self.row_offset() {
+            self.set_row_offset(pos)
+        } else {
+            false
+        }
     }
 
-    pub fn named(name: &str) -> Self {
-        Self {
-            focus: FocusFlag::named(name),
-            ..TableState::default()
+    /// Set the indicator row. This is a second current row
+    /// independent of the selection, e.g. the playback position
+    /// of a player or the running step of a runner.
+    ///
+    /// With [set_follow_indicator](TableState::set_follow_indicator)
+    /// this scrolls to the row too.
+    ///
+    /// Returns true if the indicator changed or the table scrolled.
+    pub fn set_indicator_row(&mut self, row: Option<usize>) -> bool {
+        let old = self.indicator_row;
+        self.indicator_row = row;
+        let mut r = old != row;
+        if self.follow_indicator {
+            if let Some(row) = row {
+                r |= self.scroll_to_row(row);
+            }
         }
+        r
     }
-}
 
-// Baseline
-impl<Selection> TableState<Selection> {
-    /// Number of rows.
+    /// Keep n rows visible above and below the selected row,
+    /// when moving the selection up or down. Like vim's scrolloff.
+    ///
+    /// The margin is limited to half a page.
     #[inline]
-    pub fn rows(&self) -> usize {
-        self.rows
+    pub fn set_scrolloff(&mut self, n: usize) {
+        self.scrolloff = n;
     }
 
-    /// Number of columns.
-    #[inline]
-    pub fn columns(&self) -> usize {
-        self.columns
+    /// Ensures that the given row is visible together with
+    /// the [scrolloff](TableState::set_scrolloff) margin.
+    /// Caveat: This doesn't work nicely if you have varying row-heights.
+    pub fn scroll_to_row_scrolloff(&mut self, pos: usize) -> bool {
+        if pos >= self.rows {
+            return false;
+        }
+        let margin = min(self.scrolloff, self.page_len().saturating_sub(1) / 2);
+        let mut r = self.scroll_to_row(pos);
+        if margin > 0 {
+            r |= self.scroll_to_row(min(pos + margin, self.rows - 1));
+            r |= self.scroll_to_row(pos.saturating_sub(margin));
+        }
+        r
     }
-}
 
-// Table areas
-impl<Selection> TableState<Selection> {
-    /// Returns the whole row-area and the cell-areas for the
-    /// given row, if it is visible.
-    ///
-    /// Attention: These areas might be 0-length if the column is scrolled
-    /// beyond the table-area.
-    pub fn row_cells(&self, row: usize) -> Option<(Rect, Vec<Rect>)> {
-        if row < self.vscroll.offset() || row >= self.vscroll.offset() + self.vscroll.page_len() {
-            return None;
+    /// Ensures that the given column is completely visible.
+    pub fn scroll_to_col(&mut self, pos: usize) -> bool {
+        if let Some(col) = self.column_layout.get(pos) {
+            if (col.left() as usize) < self.x_offset() {
+                self.set_x_offset(col.x as usize)
+            } else if (col.right() as usize) >= self.x_offset().saturating_add(self.page_width()) {
+                self.set_x_offset((col.right() as usize).saturating_sub(self.page_width()))
+            } else {
+                false
+            }
+        } else {
+            false
         }
+    }
 
-        let mut areas = Vec::new();
+    /// Ensures that the given position is visible.
+    pub fn scroll_to_x(&mut self, pos: usize) -> bool {
+        if pos >= self.x_offset().saturating_add(self.page_width()) {
+            self.set_x_offset(pos.saturating_sub(self.page_width()).saturating_add(1))
+        } else if pos < self.x_offset() {
+            self.set_x_offset(pos)
+        } else {
+            false
+        }
+    }
 
-        let r = self.row_areas[row - self.vscroll.offset()];
-        for c in &self.column_areas {
-            areas.push(Rect::new(c.x, r.y, c.width, r.height));
+    /// Reduce the row-offset by n.
+    ///
+    /// With [TableState::set_scroll_lines] this scrolls lines.
+    pub fn scroll_up(&mut self, n: usize) -> bool {
+        if self.scroll_lines {
+            self.scroll_up_lines(n)
+        } else {
+            self.vscroll.scroll_up(n)
         }
+    }
 
-        Some((r, areas))
+    /// Increase the row-offset by n.
+    ///
+    /// With [TableState::set_scroll_lines] this scrolls lines.
+    pub fn scroll_down(&mut self, n: usize) -> bool {
+        if self.scroll_lines {
+            self.scroll_down_lines(n)
+        } else {
+            self.vscroll.scroll_down(n)
+        }
     }
 
-    /// Cell at given position.
-    pub fn cell_at_clicked(&self, pos: (u16, u16)) -> Option<(usize, usize)> {
-        let col = self.column_at_clicked(pos);
-        let row = self.row_at_clicked(pos);
+    /// Scroll by lines instead of rows.
+    ///
+    /// This way rows that are taller than the table can be
+    /// scrolled into. Changes scroll_up() and scroll_down(),
+    /// which are used for the mouse wheel.
+    #[inline]
+    pub fn set_scroll_lines(&mut self, lines: bool) {
+        self.scroll_lines = lines;
+    }
 
-        match (col, row) {
-            (Some(col), Some(row)) => Some((col, row)),
-            _ => None,
+    /// Lines of the first visible row that are scrolled out
+    /// at the top.
+    pub fn line_offset(&self) -> u16 {
+        if self.line_offset.0 == self.vscroll.offset() {
+            self.line_offset.1
+        } else {
+            0
         }
     }
 
-    /// Column at given position.
-    pub fn column_at_clicked(&self, pos: (u16, u16)) -> Option<usize> {
-        self.mouse.column_at(&self.column_areas, pos.0)
+    /// Scroll up n lines.
+    ///
+    /// The height of the rows above the first visible row is
+    /// not known. Moving into the previous row starts with its
+    /// last line, any further rows count as one line.
+    pub fn scroll_up_lines(&mut self, n: usize) -> bool {
+        let old = (self.vscroll.offset(), self.line_offset());
+        let line = self.line_offset() as usize;
+        if n <= line {
+            self.line_offset = (old.0, (line - n) as u16);
+        } else {
+            let rest = n - line;
+            if old.0 >= rest {
+                self.vscroll.set_offset(old.0 - rest);
+                self.line_offset = (old.0 - rest, u16::MAX);
+            } else {
+                self.vscroll.set_offset(0);
+                self.line_offset = (0, 0);
+            }
+        }
+        old != (self.vscroll.offset(), self.line_offset())
     }
 
-    /// Row at given position.
-    pub fn row_at_clicked(&self, pos: (u16, u16)) -> Option<usize> {
-        self.mouse
-            .row_at(&self.row_areas, pos.1)
-            .map(|v| self.vscroll.offset() + v)
+    /// Scroll down n lines.
+    ///
+    /// Uses the row heights of the last render, rows below
+    /// count as one line.
+    pub fn scroll_down_lines(&mut self, n: usize) -> bool {
+        let old = (self.vscroll.offset(), self.line_offset());
+        let mut offset = old.0;
+        let mut line = old.1 as usize + n;
+        let mut heights = self.row_heights.iter();
+        loop {
+            let height = heights.next().map(|v| *v as usize).unwrap_or(1);
+            if line < height {
+                break;
+            }
+            line -= height;
+            offset += 1;
+        }
+        if offset > self.vscroll.max_offset() {
+            offset = self.vscroll.max_offset();
+            line = if offset == old.0 { old.1 as usize } else { 0 };
+        }
+        self.vscroll.set_offset(offset);
+        self.line_offset = (offset, line as u16);
+        old != (self.vscroll.offset(), self.line_offset())
     }
 
-    /// Cell when dragging. Position can be outside the table area.
-    /// See [row_at_drag](TableState::row_at_drag), [col_at_drag](TableState::column_at_drag)
-    pub fn cell_at_drag(&self, pos: (u16, u16)) -> (usize, usize) {
-        let col = self.column_at_drag(pos);
-        let row = self.row_at_drag(pos);
+    /// Animate the height of a row from one height to another.
+    ///
+    /// Use this when the row height changes, e.g. when showing
+    /// more details for a row. The row is rendered with the
+    /// intermediate heights, [animation_tick](TableState::animation_tick)
+    /// moves towards the target height.
+    pub fn animate_row_height(&mut self, row: usize, from: u16, to: u16) {
+        if from != to {
+            self.animated_rows.insert(row, (from, to));
+        } else {
+            self.animated_rows.remove(&row);
+        }
+    }
 
-        (col, row)
+    /// Are there any running height animations.
+    pub fn is_animating(&self) -> bool {
+        !self.animated_rows.is_empty()
     }
 
-    /// Row when dragging. Position can be outside the table area.
-    /// If the position is above the table-area this returns offset - #rows.
-    /// If the position is below the table-area this returns offset + page_len + #rows.
+    /// Advance the height animations by one step.
     ///
-    /// This doesn't account for the row-height of the actual rows outside
-    /// the table area, just assumes '1'.
-    pub fn row_at_drag(&self, pos: (u16, u16)) -> usize {
-        match self
-            .mouse
-            .row_at_drag(self.table_area, &self.row_areas, pos.1)
-        {
-            Ok(v) => self.vscroll.offset() + v,
-            Err(v) if v <= 0 => self.vscroll.offset().saturating_sub((-v) as usize),
-            Err(v) => self.vscroll.offset() + self.row_areas.len() + v as usize,
+    /// Call this with a timer tick and render if it returns true.
+    /// Each step covers half the remaining distance.
+    ///
+    /// If rows above the selected row change height, the table
+    /// scrolls by lines to keep the selected row in place.
+    pub fn animation_tick(&mut self) -> bool {
+        if self.animated_rows.is_empty() {
+            return false;
+        }
+
+        let offset = self.vscroll.offset();
+        let lead = self
+            .selection
+            .lead_selection()
+            .map(|(_, row)| row)
+            .filter(|row| *row >= offset && *row < offset + self.page_len());
+
+        let mut shift = 0isize;
+        self.animated_rows.retain(|row, (height, target)| {
+            let step = max(1, height.abs_diff(*target) / 2);
+            let next = if *height < *target {
+                *height + step
+            } else {
+                *height - step
+            };
+            if lead.map(|lead| *row >= offset && *row < lead) == Some(true) {
+                shift += next as isize - *height as isize;
+            }
+            // scrolling uses the new height.
+            if let Some(h) = row
+                .checked_sub(offset)
+                .and_then(|v| self.row_heights.get_mut(v))
+            {
+                *h = next;
+            }
+            *height = next;
+            next != *target
+        });
+
+        if shift > 0 {
+            self.scroll_down_lines(shift as usize);
+        } else if shift < 0 {
+            self.scroll_up_lines((-shift) as usize);
         }
+        true
     }
 
-    /// Column when dragging. Position can be outside the table area.
-    /// If the position is left of the table area this returns offset - 1.
-    /// If the position is right of the table area this returns offset + page_width + 1.
-    pub fn column_at_drag(&self, pos: (u16, u16)) -> usize {
-        match self
-            .mouse
-            .column_at_drag(self.table_area, &self.column_areas, pos.0)
-        {
-            Ok(v) => v,
-            Err(v) if v <= 0 => self.hscroll.offset().saturating_sub((-v) as usize),
-            Err(v) => self.hscroll.offset() + self.hscroll.page_len() + v as usize,
+    /// Reduce the col-offset by n.
+    ///
+    /// With [TableState::set_scroll_columns] this scrolls columns.
+    pub fn scroll_left(&mut self, n: usize) -> bool {
+        if self.scroll_columns {
+            self.scroll_columns_left(n)
+        } else {
+            self.hscroll.scroll_left(n)
         }
     }
-}
 
-// Offset related.
-impl<Selection: TableSelection> TableState<Selection> {
-    /// Sets both offsets to 0.
-    pub fn clear_offset(&mut self) {
-        self.vscroll.set_offset(0);
-        self.hscroll.set_offset(0);
+    /// Increase the col-offset by n.
+    ///
+    /// With [TableState::set_scroll_columns] this scrolls columns.
+    pub fn scroll_right(&mut self, n: usize) -> bool {
+        if self.scroll_columns {
+            self.scroll_columns_right(n)
+        } else {
+            self.hscroll.scroll_right(n)
+        }
     }
 
-    /// Maximum offset that is accessible with scrolling.
+    /// Scroll horizontally by whole columns instead of cells.
     ///
-    /// This is shorter than the length by whatever fills the last page.
-    /// This is the base for the scrollbar content_length.
-    pub fn row_max_offset(&self) -> usize {
-        self.vscroll.max_offset()
+    /// Changes scroll_left() and scroll_right(), which are used
+    /// for the Left/Right keys and the mouse wheel. The first
+    /// visible column is always aligned with the left border.
+    #[inline]
+    pub fn set_scroll_columns(&mut self, columns: bool) {
+        self.scroll_columns = columns;
     }
 
-    /// Current vertical offset.
-    pub fn row_offset(&self) -> usize {
-        self.vscroll.offset()
+    /// Apply the requests made with [requests](TableState::requests).
+    ///
+    /// This is called by render.
+    pub fn apply_requests(&mut self) -> bool {
+        let pending = self.requests.take();
+        let mut r = false;
+        if let Some(row) = pending.select {
+            if self.rows > 0 {
+                let col = self.selection.lead_selection().map(|v| v.0).unwrap_or(0);
+                r |= self.selection.move_lead(col, min(row, self.rows - 1));
+                r |= self.scroll_to_selected();
+            }
+        }
+        if let Some(row) = pending.scroll_to_row {
+            if self.rows > 0 {
+                r |= self.scroll_to_row(min(row, self.rows - 1));
+            }
+        }
+        r
     }
 
-    /// Change the vertical offset.
+    /// Apply the scrolling collected with
+    /// [scroll_coalesce](TableState::scroll_coalesce).
     ///
-    /// Due to overscroll it's possible that this is an invalid offset for the widget.
-    /// The widget must deal with this situation.
+    /// This is called by render.
+    pub fn apply_pending_scroll(&mut self) -> bool {
+        let (h, v) = mem::take(&mut self.pending_scroll);
+        let mut r = false;
+        if v < 0 {
+            r |= self.scroll_up(v.unsigned_abs());
+        } else if v > 0 {
+            r |= self.scroll_down(v.unsigned_abs());
+        }
+        if h < 0 {
+            r |= self.scroll_left(h.unsigned_abs());
+        } else if h > 0 {
+            r |= self.scroll_right(h.unsigned_abs());
+        }
+        r
+    }
+}
+
+// Focus
+impl<Selection: TableSelection> TableState<Selection> {
+    /// Applies [on_focus_gained](TableState::on_focus_gained) once
+    /// after the table gained the focus.
     ///
-    /// The widget returns true if the offset changed at all.
-    pub fn set_row_offset(&mut self, offset: usize) -> bool {
-        self.vscroll.set_offset(offset)
+    /// This is called by the event-handlers. If you use your own,
+    /// call it before handling the event.
+    pub fn handle_focus_gained(&mut self) -> bool {
+        if !self.focus.gained() {
+            self.focus_gained_done = false;
+            return false;
+        }
+        if self.focus_gained_done {
+            return false;
+        }
+        self.focus_gained_done = true;
+        self.apply_focus_gained()
     }
 
-    /// Vertical page-size at the current offset.
-    pub fn page_len(&self) -> usize {
-        self.vscroll.page_len()
+    /// Applies [on_focus_gained](TableState::on_focus_gained) now.
+    pub fn apply_focus_gained(&mut self) -> bool {
+        match self.on_focus_gained {
+            FocusGained::Nothing => false,
+            FocusGained::ScrollToSelection => self.scroll_to_selected(),
+            FocusGained::SelectFirstVisible => {
+                let mut r = false;
+                if self.selection.lead_selection().is_none() && self.rows > 0 {
+                    let row = min(self.row_offset(), self.rows - 1);
+                    r = self.selection.move_lead(0, row);
+                }
+                let s = self.scroll_to_selected();
+                r || s
+            }
+        }
     }
+}
 
-    /// Suggested scroll per scroll-event.
-    pub fn row_scroll_by(&self) -> usize {
-        self.vscroll.scroll_by()
+// Peek
+impl<Selection: TableSelection> TableState<Selection> {
+    /// Show the full content of the cell in a popup.
+    ///
+    /// The content is taken from [TableData::cell_text].
+    /// Cells without text show no popup.
+    pub fn peek_cell(&mut self, column: usize, row: usize) -> bool {
+        let old = self.peek;
+        self.peek = Some((column, row));
+        self.peek_offset = 0;
+        old != self.peek
     }
 
-    /// Maximum offset that is accessible with scrolling.
+    /// Show the full content of the selected cell in a popup.
     ///
-    /// This is shorter than the length of the content by whatever fills the last page.
-    /// This is the base for the scrollbar content_length.
-    pub fn x_max_offset(&self) -> usize {
-        self.hscroll.max_offset()
+    /// For row selections this uses the first visible column.
+    pub fn peek_selected(&mut self) -> bool {
+        let Some((column, row)) = self.selection.lead_selection() else {
+            return false;
+        };
+        let column = max(column, self.first_visible_column().unwrap_or(0));
+        self.peek_cell(column, row)
     }
 
-    /// Current horizontal offset.
-    pub fn x_offset(&self) -> usize {
-        self.hscroll.offset()
+    /// Close the peek popup.
+    pub fn close_peek(&mut self) -> bool {
+        self.peek_offset = 0;
+        self.peek.take().is_some()
     }
 
-    /// Change the horizontal offset.
-    ///
-    /// Due to overscroll it's possible that this is an invalid offset for the widget.
-    /// The widget must deal with this situation.
-    ///
-    /// The widget returns true if the offset changed at all.
-    pub fn set_x_offset(&mut self, offset: usize) -> bool {
-        self.hscroll.set_offset(offset)
+    /// Is the peek popup open.
+    pub fn is_peeking(&self) -> bool {
+        self.peek.is_some()
     }
 
-    /// Horizontal page-size at the current offset.
-    pub fn page_width(&self) -> usize {
-        self.hscroll.page_len()
+    /// Set the notes for cells (column, row).
+    ///
+    /// Cells with a note get a marker in the top-left corner.
+    /// The note is shown when hovering the cell with the mouse,
+    /// or with F4 for the selected cell.
+    pub fn set_annotations(&mut self, annotations: HashMap<(usize, usize), Text<'static>>) {
+        self.annotations = annotations;
+        if let Some(cell) = self.annotation {
+            if !self.annotations.contains_key(&cell) {
+                self.annotation = None;
+            }
+        }
     }
 
-    /// Suggested scroll per scroll-event.
-    pub fn x_scroll_by(&self) -> usize {
-        self.hscroll.scroll_by()
+    /// Note for the cell.
+    pub fn annotation(&self, column: usize, row: usize) -> Option<&Text<'static>> {
+        self.annotations.get(&(column, row))
     }
 
-    /// Ensures that the selected item is visible.
-    /// Caveat: This doesn't work nicely if you have varying row-heights.
-    pub fn scroll_to_selected(&mut self) -> bool {
-        if let Some(selected) = self.selection.lead_selection() {
-            let c = self.scroll_to_x(selected.0);
-            let r = self.scroll_to_row(selected.1);
-            r || c
-        } else {
-            false
+    /// Show the note of the cell. Does nothing if the
+    /// cell has no note.
+    pub fn show_annotation(&mut self, column: usize, row: usize) -> bool {
+        if !self.annotations.contains_key(&(column, row)) {
+            return false;
         }
+        let old = self.annotation;
+        self.annotation = Some((column, row));
+        old != self.annotation
     }
 
-    /// Ensures that the given row is visible.
-    /// Caveat: This doesn't work nicely if you have varying row-heights.
-    pub fn scroll_to_row(&mut self, pos: usize) -> bool {
-        if pos >= self.rows {
-            false
-        } else if pos == self.row_offset().saturating_add(self.page_len()) {
-            // the page might not fill the full area.
-            let heights = self.row_areas.iter().map(|v| v.height).sum::<u16>();
-            if heights < self.table_area.height {
-                false
-            } else {
-                self.set_row_offset(pos.saturating_sub(self.page_len()).saturating_add(1))
-            }
-        } else if pos >= self.row_offset().saturating_add(self.page_len()) {
-            self.set_row_offset(pos.saturating_sub(self.page_len()).saturating_add(1))
-        } else if pos < self.row_offset() {
-            self.set_row_offset(pos)
+    /// Show the note of the selected cell.
+    ///
+    /// For row selections this uses the first column with a note.
+    pub fn show_selected_annotation(&mut self) -> bool {
+        let Some((column, row)) = self.selection.lead_selection() else {
+            return false;
+        };
+        if Selection::selects_cells() {
+            self.show_annotation(column, row)
+        } else if let Some(column) =
+            (0..self.columns).find(|c| self.annotations.contains_key(&(*c, row)))
+        {
+            self.show_annotation(column, row)
         } else {
             false
         }
     }
 
-    /// Ensures that the given column is completely visible.
-    pub fn scroll_to_col(&mut self, pos: usize) -> bool {
-        if let Some(col) = self.column_layout.get(pos) {
-            if (col.left() as usize) < self.x_offset() {
-                self.set_x_offset(col.x as usize)
-            } else if (col.right() as usize) >= self.x_offset().saturating_add(self.page_width()) {
-                self.set_x_offset((col.right() as usize).saturating_sub(self.page_width()))
-            } else {
-                false
-            }
-        } else {
-            false
-        }
+    /// Hide the note.
+    pub fn close_annotation(&mut self) -> bool {
+        self.annotation.take().is_some()
     }
 
-    /// Ensures that the given position is visible.
-    pub fn scroll_to_x(&mut self, pos: usize) -> bool {
-        if pos >= self.x_offset().saturating_add(self.page_width()) {
-            self.set_x_offset(pos.saturating_sub(self.page_width()).saturating_add(1))
-        } else if pos < self.x_offset() {
-            self.set_x_offset(pos)
+    /// Scroll the content of the peek popup.
+    pub fn scroll_peek(&mut self, n: isize) -> bool {
+        let page = self.peek_area.height.saturating_sub(2) as usize;
+        let max_offset = self.peek_lines.saturating_sub(page);
+        let old = self.peek_offset;
+        self.peek_offset = min(self.peek_offset.saturating_add_signed(n), max_offset);
+        old != self.peek_offset
+    }
+}
+
+// Column units
+impl<Selection: TableSelection> TableState<Selection> {
+    /// Column is hidden.
+    /// See [collapsed_columns](TableState::collapsed_columns).
+    pub fn is_column_collapsed(&self, column: usize) -> bool {
+        self.collapsed_columns.contains(&column)
+    }
+
+    /// Hide or show a column.
+    /// See [collapsed_columns](TableState::collapsed_columns).
+    pub fn set_column_collapsed(&mut self, column: usize, collapsed: bool) -> bool {
+        if collapsed {
+            self.collapsed_columns.insert(column)
         } else {
-            false
+            self.collapsed_columns.remove(&column)
         }
     }
 
-    /// Reduce the row-offset by n.
-    pub fn scroll_up(&mut self, n: usize) -> bool {
-        self.vscroll.scroll_up(n)
+    /// First column that is at least partially visible.
+    pub fn first_visible_column(&self) -> Option<usize> {
+        let x_offset = self.x_offset();
+        self.column_layout
+            .iter()
+            .position(|v| v.right() as usize > x_offset)
     }
 
-    /// Increase the row-offset by n.
-    pub fn scroll_down(&mut self, n: usize) -> bool {
-        self.vscroll.scroll_down(n)
+    /// Number of fully visible columns.
+    pub fn visible_column_count(&self) -> usize {
+        let x_offset = self.x_offset();
+        let x_end = x_offset.saturating_add(self.page_width());
+        self.column_layout
+            .iter()
+            .filter(|v| v.left() as usize >= x_offset && v.right() as usize <= x_end)
+            .count()
     }
 
-    /// Reduce the col-offset by n.
-    pub fn scroll_left(&mut self, n: usize) -> bool {
-        self.hscroll.scroll_left(n)
+    /// Horizontal page in columns. At least 1.
+    pub fn column_page(&self) -> usize {
+        max(1, self.visible_column_count())
     }
 
-    /// Increase the col-offset by n.
-    pub fn scroll_right(&mut self, n: usize) -> bool {
-        self.hscroll.scroll_right(n)
+    /// Maximum first column for scrolling in column units.
+    /// The columns from there to the end fit in the page.
+    pub fn column_max_offset(&self) -> usize {
+        let Some(last) = self.column_layout.last() else {
+            return 0;
+        };
+        let page_width = self.page_width();
+        self.column_layout
+            .iter()
+            .position(|v| (last.right() - v.left()) as usize <= page_width)
+            .unwrap_or(self.column_layout.len().saturating_sub(1))
+    }
+
+    /// Scroll left by n columns.
+    /// The first visible column is aligned with the left border.
+    pub fn scroll_columns_left(&mut self, n: usize) -> bool {
+        let first = self.first_visible_column().unwrap_or_default();
+        // partially visible first column counts as one step.
+        let first = match self.column_layout.get(first) {
+            Some(v) if (v.x as usize) < self.x_offset() => first + 1,
+            _ => first,
+        };
+        let col = first.saturating_sub(n);
+        match self.column_layout.get(col) {
+            Some(v) => self.set_x_offset(v.x as usize),
+            None => false,
+        }
+    }
+
+    /// Scroll right by n columns.
+    /// The first visible column is aligned with the left border.
+    pub fn scroll_columns_right(&mut self, n: usize) -> bool {
+        let first = self.first_visible_column().unwrap_or_default();
+        let col = min(first.saturating_add(n), self.column_max_offset());
+        match self.column_layout.get(col) {
+            Some(v) if v.x as usize > self.x_offset() => self.set_x_offset(v.x as usize),
+            _ => false,
+        }
     }
 }
 
 impl TableState<RowSelection> {
     /// Update the state to match adding items.
     /// This corrects the number of rows, offset and selection.
+    ///
+    /// See [TableState::set_show_inserted_at_top].
     pub fn items_added(&mut self, pos: usize, n: usize) {
-        self.vscroll.items_added(pos, n);
+        self.offset_items_added(pos, n);
+        self.animated_items_added(pos, n);
         self.selection.items_added(pos, n);
         self.rows += n;
     }
@@ -1942,6 +7169,7 @@ impl TableState<RowSelection> {
     /// This corrects the number of rows, offset and selection.
     pub fn items_removed(&mut self, pos: usize, n: usize) {
         self.vscroll.items_removed(pos, n);
+        self.animated_items_removed(pos, n);
         self.selection
             .items_removed(pos, n, self.rows.saturating_sub(1));
         self.rows -= n;
@@ -1953,6 +7181,49 @@ impl TableState<RowSelection> {
         self.selection.set_scroll_selected(scroll);
     }
 
+    /// PageUp/PageDown scroll the offset and keep the selection
+    /// at the same screen position, like less or vim.
+    ///
+    /// This is independent of [TableState::set_scroll_selection].
+    #[inline]
+    pub fn set_page_keeps_position(&mut self, keep: bool) {
+        self.selection.set_page_keeps_position(keep);
+    }
+
+    /// Move the selection n rows up as a page.
+    ///
+    /// With [TableState::set_page_keeps_position] the offset is
+    /// scrolled by the same amount as long as possible.
+    pub fn page_up(&mut self, n: usize) -> bool {
+        if self.selection.page_keeps_position {
+            let o = self.set_row_offset(self.row_offset().saturating_sub(n));
+            let r = self.selection.move_up(n, self.rows.saturating_sub(1));
+            let s = self.scroll_to_row(self.selection.selected().expect("row"));
+            o || r || s
+        } else {
+            self.move_up(n)
+        }
+    }
+
+    /// Move the selection n rows down as a page.
+    ///
+    /// With [TableState::set_page_keeps_position] the offset is
+    /// scrolled by the same amount as long as possible.
+    pub fn page_down(&mut self, n: usize) -> bool {
+        if self.selection.page_keeps_position {
+            let offset = min(
+                self.row_offset().saturating_add(n),
+                self.vscroll.max_offset(),
+            );
+            let o = self.set_row_offset(max(offset, self.row_offset()));
+            let r = self.selection.move_down(n, self.rows.saturating_sub(1));
+            let s = self.scroll_to_row(self.selection.selected().expect("row"));
+            o || r || s
+        } else {
+            self.move_down(n)
+        }
+    }
+
     /// Clear the selection.
     #[inline]
     pub fn clear_selection(&mut self) {
@@ -1980,9 +7251,14 @@ impl TableState<RowSelection> {
     /// Scroll delivers a value between 0 and max_offset as offset.
     /// This remaps the ratio to the selection with a range 0..row_len.
     ///
+    #[cfg(feature = "crossterm")]
     pub(crate) fn remap_offset_selection(&self, offset: usize) -> usize {
+        let rows = self.known_rows.unwrap_or(self.rows);
         if self.vscroll.max_offset() > 0 {
-            (self.rows * offset) / self.vscroll.max_offset()
+            min(
+                rows.saturating_sub(1),
+                (rows * offset) / self.vscroll.max_offset(),
+            )
         } else {
             0 // ???
         }
@@ -1997,12 +7273,28 @@ impl TableState<RowSelection> {
         r || s
     }
 
+    /// Auto-scroll while dragging the selection.
+    ///
+    /// Call this with a timer tick. If the mouse has left the
+    /// table area during a drag, this moves the selection further
+    /// in that direction and scrolls the table. The step grows
+    /// with the distance to the table area.
+    ///
+    /// Returns Continue if there is no drag outside the table area.
+    #[cfg(feature = "crossterm")]
+    pub fn drag_tick(&mut self) -> Outcome {
+        match self.drag_outside() {
+            Some(pos) => self.move_to(self.row_at_drag(pos)).into(),
+            None => Outcome::Continue,
+        }
+    }
+
     /// Move the selection up n rows.
     /// Ensures the row is visible afterwards.
     #[inline]
     pub fn move_up(&mut self, n: usize) -> bool {
         let r = self.selection.move_up(n, self.rows.saturating_sub(1));
-        let s = self.scroll_to_row(self.selection.selected().expect("row"));
+        let s = self.scroll_to_row_scrolloff(self.selection.selected().expect("row"));
         r || s
     }
 
@@ -2011,9 +7303,38 @@ impl TableState<RowSelection> {
     #[inline]
     pub fn move_down(&mut self, n: usize) -> bool {
         let r = self.selection.move_down(n, self.rows.saturating_sub(1));
-        let s = self.scroll_to_row(self.selection.selected().expect("row"));
+        let s = self.scroll_to_row_scrolloff(self.selection.selected().expect("row"));
         r || s
     }
+
+    /// Move the selection to the given row and record the
+    /// jump in the jump list. Use this for go-to-row, search hits
+    /// and the like.
+    pub fn jump_to(&mut self, row: usize) -> bool {
+        if let Some(current) = self.selection.selected() {
+            self.jumps.record(current);
+        }
+        self.move_to(row)
+    }
+
+    /// Go back to the previous jump position.
+    pub fn jump_back(&mut self) -> bool {
+        let current = self.selection.selected().unwrap_or_default();
+        if let Some(row) = self.jumps.back(current) {
+            self.move_to(row)
+        } else {
+            false
+        }
+    }
+
+    /// Go forward to the next jump position.
+    pub fn jump_forward(&mut self) -> bool {
+        if let Some(row) = self.jumps.forward() {
+            self.move_to(row)
+        } else {
+            false
+        }
+    }
 }
 
 impl TableState<RowSetSelection> {
@@ -2090,6 +7411,22 @@ impl TableState<RowSetSelection> {
         r || s
     }
 
+    /// Auto-scroll while dragging the selection.
+    ///
+    /// Call this with a timer tick. If the mouse has left the
+    /// table area during a drag, this moves the selection further
+    /// in that direction and scrolls the table. The step grows
+    /// with the distance to the table area.
+    ///
+    /// Returns Continue if there is no drag outside the table area.
+    #[cfg(feature = "crossterm")]
+    pub fn drag_tick(&mut self) -> Outcome {
+        match self.drag_outside() {
+            Some(pos) => self.move_to(self.row_at_drag(pos), true).into(),
+            None => Outcome::Continue,
+        }
+    }
+
     /// Move the selection up n rows.
     /// Ensures the row is visible afterwards.
     #[inline]
@@ -2097,7 +7434,7 @@ impl TableState<RowSetSelection> {
         let r = self
             .selection
             .move_up(n, self.rows.saturating_sub(1), extend);
-        let s = self.scroll_to_row(self.selection.lead().expect("row"));
+        let s = self.scroll_to_row_scrolloff(self.selection.lead().expect("row"));
         r || s
     }
 
@@ -2108,9 +7445,38 @@ impl TableState<RowSetSelection> {
         let r = self
             .selection
             .move_down(n, self.rows.saturating_sub(1), extend);
-        let s = self.scroll_to_row(self.selection.lead().expect("row"));
+        let s = self.scroll_to_row_scrolloff(self.selection.lead().expect("row"));
         r || s
     }
+
+    /// Move the selection to the given row and record the
+    /// jump in the jump list. Use this for go-to-row, search hits
+    /// and the like.
+    pub fn jump_to(&mut self, row: usize) -> bool {
+        if let Some(current) = self.selection.lead() {
+            self.jumps.record(current);
+        }
+        self.move_to(row, false)
+    }
+
+    /// Go back to the previous jump position.
+    pub fn jump_back(&mut self) -> bool {
+        let current = self.selection.lead().unwrap_or_default();
+        if let Some(row) = self.jumps.back(current) {
+            self.move_to(row, false)
+        } else {
+            false
+        }
+    }
+
+    /// Go forward to the next jump position.
+    pub fn jump_forward(&mut self) -> bool {
+        if let Some(row) = self.jumps.forward() {
+            self.move_to(row, false)
+        } else {
+            false
+        }
+    }
 }
 
 impl TableState<CellSelection> {
@@ -2169,6 +7535,22 @@ impl TableState<CellSelection> {
         r || s
     }
 
+    /// Auto-scroll while dragging the selection.
+    ///
+    /// Call this with a timer tick. If the mouse has left the
+    /// table area during a drag, this moves the selection further
+    /// in that direction and scrolls the table. The step grows
+    /// with the distance to the table area.
+    ///
+    /// Returns Continue if there is no drag outside the table area.
+    #[cfg(feature = "crossterm")]
+    pub fn drag_tick(&mut self) -> Outcome {
+        match self.drag_outside() {
+            Some(pos) => self.move_to(self.cell_at_drag(pos)).into(),
+            None => Outcome::Continue,
+        }
+    }
+
     /// Select a row, limit to maximum.
     #[inline]
     pub fn move_to_row(&mut self, row: usize) -> bool {
@@ -2192,7 +7574,10 @@ impl TableState<CellSelection> {
     #[inline]
     pub fn move_up(&mut self, n: usize) -> bool {
         let r = self.selection.move_up(n, self.rows.saturating_sub(1));
-        let s = self.scroll_to_selected();
+        let mut s = self.scroll_to_selected();
+        if let Some((_, row)) = self.selection.lead_selection() {
+            s |= self.scroll_to_row_scrolloff(row);
+        }
         r || s
     }
 
@@ -2201,7 +7586,10 @@ impl TableState<CellSelection> {
     #[inline]
     pub fn move_down(&mut self, n: usize) -> bool {
         let r = self.selection.move_down(n, self.rows.saturating_sub(1));
-        let s = self.scroll_to_selected();
+        let mut s = self.scroll_to_selected();
+        if let Some((_, row)) = self.selection.lead_selection() {
+            s |= self.scroll_to_row_scrolloff(row);
+        }
         r || s
     }
 
@@ -2222,8 +7610,38 @@ impl TableState<CellSelection> {
         let s = self.scroll_to_selected();
         r || s
     }
+
+    /// Move the selection to the given row and record the
+    /// jump in the jump list. Use this for go-to-row, search hits
+    /// and the like.
+    pub fn jump_to(&mut self, row: usize) -> bool {
+        if let Some((_, current)) = self.selection.lead_selection() {
+            self.jumps.record(current);
+        }
+        self.move_to_row(row)
+    }
+
+    /// Go back to the previous jump position.
+    pub fn jump_back(&mut self) -> bool {
+        let current = self.selection.lead_selection().map(|v| v.1);
+        if let Some(row) = self.jumps.back(current.unwrap_or_default()) {
+            self.move_to_row(row)
+        } else {
+            false
+        }
+    }
+
+    /// Go forward to the next jump position.
+    pub fn jump_forward(&mut self) -> bool {
+        if let Some(row) = self.jumps.forward() {
+            self.move_to_row(row)
+        } else {
+            false
+        }
+    }
 }
 
+#[cfg(feature = "crossterm")]
 impl<Selection> HandleEvent<crossterm::event::Event, DoubleClick, DoubleClickOutcome>
     for TableState<Selection>
 {
@@ -2233,20 +7651,115 @@ impl<Selection> HandleEvent<crossterm::event::Event, DoubleClick, DoubleClickOut
         event: &crossterm::event::Event,
         _keymap: DoubleClick,
     ) -> DoubleClickOutcome {
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let crossterm::event::Event::Mouse(m) = event else {
+            return DoubleClickOutcome::Continue;
+        };
+        if m.kind != MouseEventKind::Down(MouseButton::Left) {
+            return DoubleClickOutcome::Continue;
+        }
+        let Some((column, row)) = self.cell_at_clicked((m.column, m.row)) else {
+            self.click_count = 0;
+            return DoubleClickOutcome::Continue;
+        };
+
+        let timeout = Duration::from_millis(double_click_timeout() as u64);
+        let repeat = match self.last_click {
+            Some((time, cell)) => cell == (column, row) && time.elapsed() <= timeout,
+            None => false,
+        };
+        self.click_count = if repeat { self.click_count + 1 } else { 1 };
+        self.last_click = Some((Instant::now(), (column, row)));
+
+        let click = Click {
+            column,
+            row,
+            position: (m.column, m.row),
+            shift: m.modifiers.contains(KeyModifiers::SHIFT),
+            ctrl: m.modifiers.contains(KeyModifiers::CONTROL),
+            alt: m.modifiers.contains(KeyModifiers::ALT),
+        };
+        match self.click_count {
+            2 => {
+                if !self.triple_click {
+                    self.click_count = 0;
+                }
+                DoubleClickOutcome::ClickClick(click)
+            }
+            3 => {
+                self.click_count = 0;
+                DoubleClickOutcome::ClickClickClick(click)
+            }
+            _ => DoubleClickOutcome::Continue,
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<Selection> HandleEvent<crossterm::event::Event, BadgeClick, BadgeOutcome>
+    for TableState<Selection>
+{
+    /// Handles clicks on cell badges.
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: BadgeClick) -> BadgeOutcome {
         match event {
-            ct_event!(mouse any for m) if self.mouse.doubleclick(self.table_area, m) => {
-                if let Some((col, row)) = self.cell_at_clicked((m.column, m.row)) {
-                    DoubleClickOutcome::ClickClick(col, row)
+            ct_event!(mouse down Left for column, row) => {
+                if let Some((col, row)) = self.badge_at((*column, *row)) {
+                    BadgeOutcome::Clicked(col, row)
                 } else {
-                    DoubleClickOutcome::Continue
+                    BadgeOutcome::Continue
                 }
             }
-            _ => DoubleClickOutcome::Continue,
+            _ => BadgeOutcome::Continue,
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl<Selection: TableSelection>
+    HandleEvent<crossterm::event::Event, ColumnGroup, ColumnGroupOutcome>
+    for TableState<Selection>
+{
+    /// Handles dragging one header onto another.
+    fn handle(
+        &mut self,
+        event: &crossterm::event::Event,
+        _keymap: ColumnGroup,
+    ) -> ColumnGroupOutcome {
+        use ratatui::layout::Position;
+
+        match event {
+            ct_event!(mouse down Left for column, row) => {
+                let pos = Position::new(*column, *row);
+                if self.header_area.contains(pos)
+                    && self.resize_handle_at((*column, *row)).is_none()
+                {
+                    self.group_drag = self.column_at_clicked((*column, *row));
+                }
+                ColumnGroupOutcome::Continue
+            }
+            ct_event!(mouse up Left for column, row) => {
+                let Some(start) = self.group_drag.take() else {
+                    return ColumnGroupOutcome::Continue;
+                };
+                if !self.header_area.contains(Position::new(*column, *row)) {
+                    return ColumnGroupOutcome::Continue;
+                }
+                match self.column_at_clicked((*column, *row)) {
+                    Some(end) if end != start => {
+                        let (first, last) = self.group_columns(start, end);
+                        ColumnGroupOutcome::Grouped(first, last)
+                    }
+                    _ => ColumnGroupOutcome::Continue,
+                }
+            }
+            _ => ColumnGroupOutcome::Continue,
         }
     }
 }
 
 /// Handle all events for recognizing double-clicks.
+#[cfg(feature = "crossterm")]
 pub fn handle_doubleclick_events<Selection: TableSelection>(
     state: &mut TableState<Selection>,
     event: &crossterm::event::Event,
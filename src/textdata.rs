@@ -2,14 +2,23 @@
 //! Implements a Row and a Cell struct that are compatible to ratatui.
 //! You only need these if you use preformatted data.
 //!
+//! The content is a ratatui Text, which can borrow its strings.
+//! `Cell::from(&str)` doesn't allocate the string, and
+//! [Row::to_borrowed] creates a row that borrows from an existing one.
+//!
 
 use crate::_private::NonExhaustive;
+use crate::util::borrow_text;
 use crate::{TableContext, TableData};
 use ratatui::buffer::Buffer;
-use ratatui::layout::Rect;
+use ratatui::layout::{Alignment, Rect};
 use ratatui::prelude::{Style, Text};
 use ratatui::style::Styled;
 use ratatui::widgets::Widget;
+use std::borrow::Cow;
+use std::cmp::{max, min};
+use std::fmt;
+use std::fmt::{Display, Formatter};
 
 /// Internal impl for TableData using pre-rendered Cells.
 #[derive(Debug, Default, Clone)]
@@ -23,6 +32,9 @@ pub struct Row<'a> {
     pub cells: Vec<Cell<'a>>,
     pub top_margin: u16,
     pub height: u16,
+    /// The height has been set explicitly. Otherwise header
+    /// and footer grow to fit their content.
+    pub fixed_height: bool,
     pub bottom_margin: u16,
     pub style: Option<Style>,
 
@@ -34,6 +46,9 @@ pub struct Row<'a> {
 pub struct Cell<'a> {
     pub content: Text<'a>,
     pub style: Option<Style>,
+    /// Number of columns covered by this cell.
+    /// Only used for header and footer.
+    pub span: usize,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -59,11 +74,28 @@ impl<'a> TableData<'a> for TextTableData<'a> {
         }
     }
 
-    fn render_cell(&self, _ctx: &TableContext, c: usize, r: usize, area: Rect, buf: &mut Buffer) {
+    fn cell_text(&self, c: usize, r: usize) -> Option<Cow<'_, str>> {
+        let cell = self.rows.get(r)?.cells.get(c)?;
+        let text = cell
+            .content
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(Cow::Owned(text))
+    }
+
+    fn render_cell(&self, ctx: &TableContext, c: usize, r: usize, area: Rect, buf: &mut Buffer) {
         if let Some(row) = self.rows.get(r) {
             if let Some(cell) = row.cell(c) {
-                if let Some(style) = cell.style {
-                    buf.set_style(area, style);
+                if cell.style.is_some() {
+                    ctx.apply_cell_style(buf, area, cell.style);
                 }
                 cell.content.clone().render(area, buf);
             }
@@ -77,6 +109,7 @@ impl Default for Row<'_> {
             cells: Default::default(),
             top_margin: 0,
             height: 0,
+            fixed_height: false,
             bottom_margin: 0,
             style: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -136,12 +169,29 @@ impl<'a> Row<'a> {
     }
 
     /// Set the row-height.
+    ///
+    /// For header and footer this overrides the height
+    /// calculated from the content.
     #[inline]
     pub fn height(mut self, height: u16) -> Self {
         self.height = height;
+        self.fixed_height = true;
         self
     }
 
+    /// Height of the row. Unless the height has been set
+    /// explicitly this is at least the height of the tallest cell.
+    pub fn content_height(&self) -> u16 {
+        if self.fixed_height {
+            self.height
+        } else {
+            self.cells
+                .iter()
+                .map(|v| v.content.height() as u16)
+                .fold(self.height, max)
+        }
+    }
+
     /// Add some margin.
     pub fn top_margin(mut self, margin: u16) -> Self {
         self.top_margin = margin;
@@ -160,6 +210,60 @@ impl<'a> Row<'a> {
         self
     }
 
+    /// New row from the formatted values.
+    pub fn from_display<T>(cells: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Display,
+    {
+        Self::new(
+            cells
+                .into_iter()
+                .map(|v| Cell::display(v, <T::Item as Display>::fmt)),
+        )
+    }
+
+    /// New row from (value, style) pairs.
+    pub fn from_styled<T, D>(cells: T) -> Self
+    where
+        T: IntoIterator<Item = (D, Style)>,
+        D: Display,
+    {
+        Self::new(cells.into_iter().map(|(v, style)| Cell::styled(v, style)))
+    }
+
+    /// Copy of the row that borrows all the text.
+    ///
+    /// Keep the rows somewhere and use this to pass them to the
+    /// table, this avoids cloning all the strings for each render.
+    pub fn to_borrowed(&self) -> Row<'_> {
+        Row {
+            cells: self.cells.iter().map(|v| v.to_borrowed()).collect(),
+            top_margin: self.top_margin,
+            height: self.height,
+            fixed_height: self.fixed_height,
+            bottom_margin: self.bottom_margin,
+            style: self.style,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+
+    /// Maps each column to the cell starting there and its span.
+    /// Columns covered by a spanning cell map to None.
+    pub(crate) fn column_spans(&self, columns: usize) -> Vec<Option<(usize, usize)>> {
+        let mut spans = vec![None; columns];
+        let mut col = 0;
+        for (idx, cell) in self.cells.iter().enumerate() {
+            if col >= columns {
+                break;
+            }
+            let span = min(max(1, cell.span), columns - col);
+            spans[col] = Some((idx, span));
+            col += span;
+        }
+        spans
+    }
+
     /// Access to the cell.
     pub fn cell<'b: 'a>(&'b self, c: usize) -> Option<&'a Cell<'a>> {
         if let Some(t) = self.cells.get(c) {
@@ -175,6 +279,7 @@ impl Default for Cell<'_> {
         Self {
             content: Default::default(),
             style: Default::default(),
+            span: 1,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -188,6 +293,7 @@ where
         Self {
             content: value.into(),
             style: Default::default(),
+            span: 1,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -215,6 +321,57 @@ impl<'a> Cell<'a> {
         Self {
             content: content.into(),
             style: Default::default(),
+            span: 1,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+
+    /// New Cell with the value formatted by the given function.
+    ///
+    /// ```
+    /// use rat_ftable::textdata::Cell;
+    ///
+    /// let cell = Cell::display(1.5, |v, f| write!(f, "{:>6.2}", v));
+    /// ```
+    pub fn display<T>(value: T, format: impl Fn(&T, &mut Formatter<'_>) -> fmt::Result) -> Self {
+        struct Format<T, F>(T, F);
+        impl<T, F> Display for Format<T, F>
+        where
+            F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (self.1)(&self.0, f)
+            }
+        }
+        Self::new(Format(value, format).to_string())
+    }
+
+    /// New Cell with the number formatted with the NumberFormat.
+    /// The content is right aligned.
+    #[cfg(feature = "number-format")]
+    pub fn number<N>(value: N, format: &format_num_pattern::NumberFormat) -> Self
+    where
+        N: std::fmt::LowerExp + Display,
+    {
+        Self::new(format.fmt_u(value)).alignment(Alignment::Right)
+    }
+
+    /// New Cell with the formatted value and a style.
+    pub fn styled(value: impl Display, style: Style) -> Self {
+        Self {
+            content: Text::from(value.to_string()),
+            style: Some(style),
+            span: 1,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+
+    /// Copy of the cell that borrows the text.
+    pub fn to_borrowed(&self) -> Cell<'_> {
+        Cell {
+            content: borrow_text(&self.content),
+            style: self.style,
+            span: self.span,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -233,4 +390,19 @@ impl<'a> Cell<'a> {
         self.style = style;
         self
     }
+
+    /// Let the cell cover the given number of columns.
+    ///
+    /// This works for the header and footer row. The following
+    /// cell starts after the covered columns.
+    pub fn span(mut self, span: usize) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Alignment of the cell content.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.content.alignment = Some(alignment);
+        self
+    }
 }
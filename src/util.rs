@@ -1,8 +1,13 @@
-use ratatui::buffer::Buffer;
+use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::Rect;
 use ratatui::prelude::Style;
-use ratatui::style::Stylize;
+use ratatui::style::{Color, Stylize};
+use ratatui::text::{Line, Span, Text};
+use std::borrow::Cow;
+use std::cmp::{max, min};
 use std::mem;
+use std::ops::Range;
+use unicode_width::UnicodeWidthChar;
 
 pub(crate) fn revert_style(mut style: Style) -> Style {
     if style.fg.is_some() && style.bg.is_some() {
@@ -13,6 +18,21 @@ pub(crate) fn revert_style(mut style: Style) -> Style {
     }
 }
 
+/// High-contrast variant of a style.
+/// Removes the dim modifier and replaces the gray colors.
+pub(crate) fn high_contrast_style(style: Style) -> Style {
+    let mut style = style.not_dim();
+    style.fg = match style.fg {
+        Some(Color::DarkGray) | Some(Color::Gray) => Some(Color::White),
+        fg => fg,
+    };
+    style.bg = match style.bg {
+        Some(Color::DarkGray) | Some(Color::Gray) => Some(Color::Black),
+        bg => bg,
+    };
+    style
+}
+
 /// Fallback for select style.
 pub(crate) fn fallback_select_style(style: Style) -> Style {
     if style.fg.is_some() || style.bg.is_some() {
@@ -28,14 +48,25 @@ pub(crate) fn fallback_select_style(style: Style) -> Style {
 /// * tmp: Temporary buffer
 /// * h_offset: Left shift of the tmp-buffer.
 /// * view_area: clipped area in the target buffer.
+/// * blend: Blank cells without any styling are skipped,
+///   and the target cell is kept.
 /// * buf: Target buffer
-pub(crate) fn transfer_buffer(tmp: &mut Buffer, h_offset: u16, view_area: Rect, buf: &mut Buffer) {
+pub(crate) fn transfer_buffer(
+    tmp: &mut Buffer,
+    h_offset: u16,
+    view_area: Rect,
+    blend: bool,
+    buf: &mut Buffer,
+) {
     // copy buffer
     for (cell_offset, cell) in tmp.content.iter_mut().enumerate() {
         let tmp_row = cell_offset as u16 / tmp.area.width;
         let tmp_col = cell_offset as u16 % tmp.area.width;
 
         let cell = mem::take(cell);
+        if blend && is_blank(&cell) {
+            continue;
+        }
 
         // ensure tmp_col-h_offset doesn't underflow.
         if tmp_col >= h_offset {
@@ -50,3 +81,281 @@ pub(crate) fn transfer_buffer(tmp: &mut Buffer, h_offset: u16, view_area: Rect,
         }
     }
 }
+
+/// Move the columns of a tmp-buffer starting at `start + h_offset`
+/// to a target. Only those cells are reset, the rest of the
+/// tmp-buffer is left as is.
+///
+/// * tmp: Temporary buffer
+/// * h_offset: Left shift of the tmp-buffer.
+/// * view_area: clipped area in the target buffer.
+/// * start: x-position of view_area relative to the
+///   area used for h_offset.
+/// * blend: see [transfer_buffer].
+/// * buf: Target buffer
+pub(crate) fn transfer_columns(
+    tmp: &mut Buffer,
+    h_offset: u16,
+    view_area: Rect,
+    start: u16,
+    blend: bool,
+    buf: &mut Buffer,
+) {
+    let x1 = h_offset.saturating_add(start);
+    let x2 = min(x1.saturating_add(view_area.width), tmp.area.width);
+    for tmp_row in 0..tmp.area.height {
+        for tmp_col in x1..x2 {
+            let idx = (tmp_row * tmp.area.width + tmp_col) as usize;
+            let cell = mem::take(&mut tmp.content[idx]);
+            if blend && is_blank(&cell) {
+                continue;
+            }
+            let buf_row = view_area.y + tmp_row;
+            let buf_col = view_area.x + tmp_col - x1;
+            if view_area.contains((buf_col, buf_row).into()) {
+                if let Some(buf_cell) = buf.cell_mut((buf_col, buf_row)) {
+                    *buf_cell = cell
+                }
+            }
+        }
+    }
+}
+
+/// Blank cell without any styling.
+fn is_blank(cell: &Cell) -> bool {
+    cell.symbol() == " "
+        && cell.bg == Color::Reset
+        && cell.fg == Color::Reset
+        && cell.modifier.is_empty()
+}
+
+/// Copy of the text that borrows the content of all spans.
+pub(crate) fn borrow_text<'a>(text: &'a Text<'_>) -> Text<'a> {
+    Text {
+        lines: text
+            .lines
+            .iter()
+            .map(|line| Line {
+                spans: line
+                    .spans
+                    .iter()
+                    .map(|span| Span {
+                        content: Cow::Borrowed(span.content.as_ref()),
+                        style: span.style,
+                    })
+                    .collect(),
+                style: line.style,
+                alignment: line.alignment,
+            })
+            .collect(),
+        style: text.style,
+        alignment: text.alignment,
+    }
+}
+
+/// Move the content of the area down by gap rows.
+/// The freed rows at the top are reset and get the style.
+pub(crate) fn shift_down(buf: &mut Buffer, area: Rect, gap: u16, style: Style) {
+    let gap = min(gap, area.height);
+    for y in (area.top()..area.bottom() - gap).rev() {
+        for x in area.left()..area.right() {
+            if let Some(cell) = buf.cell((x, y)).cloned() {
+                if let Some(dst_cell) = buf.cell_mut((x, y + gap)) {
+                    *dst_cell = cell;
+                }
+            }
+        }
+    }
+    let gap_area = Rect::new(area.x, area.y, area.width, gap).intersection(buf.area);
+    for y in gap_area.top()..gap_area.bottom() {
+        for x in gap_area.left()..gap_area.right() {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.reset();
+            }
+        }
+    }
+    buf.set_style(gap_area, style);
+}
+
+/// Copy the area from one buffer to another.
+/// Cells outside of either buffer are ignored.
+pub(crate) fn copy_area(src: &Buffer, area: Rect, dst: &mut Buffer) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if let (Some(src_cell), Some(dst_cell)) = (src.cell((x, y)), dst.cell_mut((x, y))) {
+                *dst_cell = src_cell.clone();
+            }
+        }
+    }
+}
+
+/// Patch a style onto byte-ranges of a text that has already
+/// been rendered to the area.
+///
+/// Each line of the text is searched in its row of the area,
+/// so the alignment doesn't matter and the existing styles are
+/// kept. Lines that can't be found are left alone.
+pub(crate) fn patch_ranges(
+    buf: &mut Buffer,
+    area: Rect,
+    text: &str,
+    ranges: &[Range<usize>],
+    style: Style,
+) {
+    let mut line_start = 0;
+    for (y, line) in (area.top()..area.bottom()).zip(text.split('\n')) {
+        // byte-range and width for each symbol. zero-width chars
+        // belong to the preceding one.
+        let mut symbols: Vec<(Range<usize>, u16)> = Vec::new();
+        for (idx, c) in line.char_indices() {
+            let range = line_start + idx..line_start + idx + c.len_utf8();
+            match c.width().unwrap_or_default() {
+                0 => {
+                    if let Some(last) = symbols.last_mut() {
+                        last.0.end = range.end;
+                    }
+                }
+                width => symbols.push((range, width as u16)),
+            }
+        }
+        line_start += line.len() + 1;
+
+        let found_at = |mut x: u16| {
+            for (range, width) in &symbols {
+                if x >= area.right() {
+                    break;
+                }
+                if buf.cell((x, y)).map(|v| v.symbol()) != Some(&text[range.clone()]) {
+                    return false;
+                }
+                x += width;
+            }
+            true
+        };
+        let Some(mut x) = (area.left()..area.right()).find(|x| found_at(*x)) else {
+            continue;
+        };
+        for (range, width) in &symbols {
+            if x >= area.right() {
+                break;
+            }
+            if ranges
+                .iter()
+                .any(|v| v.start < range.end && range.start < v.end)
+            {
+                buf.set_style(Rect::new(x, y, min(*width, area.right() - x), 1), style);
+            }
+            x += width;
+        }
+    }
+}
+
+/// Cut or pad the text to exactly the given display width.
+/// Only the first line is used.
+pub(crate) fn fit_text(text: &str, width: usize) -> String {
+    let mut result = String::new();
+    let mut used = 0;
+    for c in text.lines().next().unwrap_or_default().chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > width {
+            break;
+        }
+        result.push(c);
+        used += w;
+    }
+    for _ in used..width {
+        result.push(' ');
+    }
+    result
+}
+
+/// Word-wrap the text to the given width.
+///
+/// Breaks at whitespace where possible, and hard-breaks words
+/// that are longer than the width. Span and line styles are kept.
+/// Explicit line-breaks in the text are kept too.
+pub(crate) fn wrap_text<'a>(text: &Text<'a>, width: u16) -> Text<'a> {
+    let width = max(1, width) as usize;
+
+    let mut wrapped = Text::default().style(text.style);
+    wrapped.alignment = text.alignment;
+
+    for line in &text.lines {
+        // split into words with their trailing whitespace.
+        let mut words: Vec<Vec<(char, Style)>> = Vec::new();
+        let mut word = Vec::new();
+        let mut in_space = false;
+        for span in &line.spans {
+            for c in span.content.chars() {
+                if c.is_whitespace() {
+                    in_space = true;
+                } else if in_space {
+                    words.push(mem::take(&mut word));
+                    in_space = false;
+                }
+                word.push((c, span.style));
+            }
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+
+        let mut lines: Vec<Vec<(char, Style)>> = vec![Vec::new()];
+        let mut line_width = 0;
+        for word in words {
+            let text_width = word
+                .iter()
+                .filter(|(c, _)| !c.is_whitespace())
+                .map(|(c, _)| c.width().unwrap_or(0))
+                .sum::<usize>();
+            if line_width > 0 && line_width + text_width > width {
+                lines.push(Vec::new());
+                line_width = 0;
+            }
+            for (c, style) in word {
+                let w = c.width().unwrap_or(0);
+                if line_width + w > width {
+                    if c.is_whitespace() {
+                        continue;
+                    }
+                    lines.push(Vec::new());
+                    line_width = 0;
+                }
+                if let Some(current) = lines.last_mut() {
+                    current.push((c, style));
+                }
+                line_width += w;
+            }
+        }
+
+        for chars in lines {
+            let mut spans: Vec<Span<'a>> = Vec::new();
+            let mut buf = String::new();
+            let mut buf_style = None;
+            // trailing whitespace is not needed.
+            let end = chars
+                .iter()
+                .rposition(|(c, _)| !c.is_whitespace())
+                .map(|v| v + 1)
+                .unwrap_or(0);
+            for (c, style) in chars.into_iter().take(end) {
+                if buf_style.is_some() && buf_style != Some(style) {
+                    spans.push(Span::styled(
+                        mem::take(&mut buf),
+                        buf_style.unwrap_or_default(),
+                    ));
+                }
+                buf_style = Some(style);
+                buf.push(c);
+            }
+            if !buf.is_empty() {
+                spans.push(Span::styled(buf, buf_style.unwrap_or_default()));
+            }
+            let mut wrapped_line = Line::from(spans).style(line.style);
+            wrapped_line.alignment = line.alignment;
+            wrapped.lines.push(wrapped_line);
+        }
+    }
+
+    wrapped
+}
@@ -0,0 +1,72 @@
+//!
+//! Generation counter for the table data.
+//!
+//! The owner of the data keeps a [DataVersion] and bumps it with
+//! every change. [TableData::version](crate::TableData::version)
+//! returns the current generation, and the table compares it with
+//! the one seen with the last render. Everything the table derives
+//! from the data is renewed if they differ, and
+//! [TableState::data_changed](crate::TableState::data_changed) is
+//! set for this render.
+//!
+//! ```rust
+//! use rat_ftable::version::DataVersion;
+//!
+//! let mut version = DataVersion::new();
+//! let seen = version.generation();
+//!
+//! // data changes
+//! version.bump();
+//!
+//! assert!(version.changed_since(seen));
+//! assert!(version.take_changed());
+//! assert!(!version.take_changed());
+//! ```
+//!
+
+/// Generation counter plus a change flag.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DataVersion {
+    generation: u64,
+    changed: bool,
+}
+
+impl DataVersion {
+    /// New counter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark a change of the data.
+    #[inline]
+    pub fn bump(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.changed = true;
+    }
+
+    /// Current generation.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Data changed since the given generation.
+    #[inline]
+    pub fn changed_since(&self, generation: u64) -> bool {
+        self.generation != generation
+    }
+
+    /// Data changed since the last call to take_changed.
+    #[inline]
+    pub fn is_changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Returns the change flag and resets it.
+    #[inline]
+    pub fn take_changed(&mut self) -> bool {
+        let changed = self.changed;
+        self.changed = false;
+        changed
+    }
+}
@@ -0,0 +1,48 @@
+//!
+//! Column widths from sample content.
+//!
+//! Instead of counting characters for formatted columns, give
+//! a sample of the widest content and let the width be calculated.
+//! The width is the display width, so wide characters count
+//! twice.
+//!
+//! ```rust
+//! use ratatui::layout::Constraint;
+//! use rat_ftable::widths::{width_chars, width_of, width_of_any};
+//!
+//! let widths = [
+//!     width_of("2024-12-31 23:59:59"),
+//!     width_chars(12),
+//!     width_of_any(["Open", "Closed", "Pending"]),
+//! ];
+//!
+//! assert_eq!(widths[0], Constraint::Length(19));
+//! assert_eq!(widths[1], Constraint::Length(12));
+//! assert_eq!(widths[2], Constraint::Length(7));
+//! assert_eq!(width_of("日付"), Constraint::Length(4));
+//! ```
+//!
+
+use ratatui::layout::Constraint;
+use unicode_width::UnicodeWidthStr;
+
+/// Width of the sample text. For multi-line samples
+/// this is the widest line.
+pub fn width_of(sample: &str) -> Constraint {
+    Constraint::Length(text_width(sample))
+}
+
+/// Width of the widest of the samples.
+pub fn width_of_any<'a>(samples: impl IntoIterator<Item = &'a str>) -> Constraint {
+    let width = samples.into_iter().map(text_width).max().unwrap_or(0);
+    Constraint::Length(width)
+}
+
+/// Width of n characters.
+pub fn width_chars(n: u16) -> Constraint {
+    Constraint::Length(n)
+}
+
+fn text_width(text: &str) -> u16 {
+    text.lines().map(|v| v.width()).max().unwrap_or(0) as u16
+}